@@ -0,0 +1,62 @@
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs, U128, U256};
+use uniswap_erc20::Address;
+
+/// Returns token0 and token1 of `pair`
+pub fn tokens(pair: Key) -> (Key, Key) {
+    let pair_package_hash: ContractPackageHash = pair.into_hash().unwrap_or_revert().into();
+    let token0: Key =
+        runtime::call_versioned_contract(pair_package_hash, None, "token0", runtime_args! {});
+    let token1: Key =
+        runtime::call_versioned_contract(pair_package_hash, None, "token1", runtime_args! {});
+    (token0, token1)
+}
+
+/// Returns the reserves of `pair` ordered as (input, output)
+pub fn reserves(pair: Key, zero_for_one: bool) -> (U256, U256) {
+    let (reserve0, reserve1, _): (U128, U128, u64) = runtime::call_versioned_contract(
+        pair.into_hash().unwrap_or_revert().into(),
+        None,
+        "get_reserves",
+        runtime_args! {},
+    );
+    let (reserve0, reserve1) = (
+        U256::from(reserve0.as_u128()),
+        U256::from(reserve1.as_u128()),
+    );
+    if zero_for_one {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    }
+}
+
+/// Sends `amount` of `token` to `recipient`, nothing for a zero amount
+pub fn transfer(token: Key, recipient: Key, amount: U256) {
+    if amount.is_zero() {
+        return;
+    }
+    let () = runtime::call_versioned_contract(
+        token.into_hash().unwrap_or_revert().into(),
+        None,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::from(recipient),
+            "amount" => amount
+        },
+    );
+}
+
+/// Pulls `amount` of `token` from `owner` to `recipient`, out of the caller's allowance
+pub fn transfer_from(token: Key, owner: Key, recipient: Key, amount: U256) {
+    let () = runtime::call_versioned_contract(
+        token.into_hash().unwrap_or_revert().into(),
+        None,
+        "transfer_from",
+        runtime_args! {
+            "owner" => Address::from(owner),
+            "recipient" => Address::from(recipient),
+            "amount" => amount
+        },
+    );
+}
@@ -1,16 +1,21 @@
+//! Contracts revert with `ApiError::User(code)`, which is reported as 65,536 + code. Every error
+//! enum owns its own range of codes so that they never collide:
+//!
+//! * 0 - 17: reserved for the erc20 errors of `uniswap_erc20`
+//! * 18 - 999: [`Errors`], from [`ERRORS_BASE`], new variants take the next free code
+//! * 1,000 - 1,999: [`WcsprError`], from [`WCSPR_ERRORS_BASE`]
+
 use casper_types::ApiError;
 
+/// First code of [`Errors`]
+pub const ERRORS_BASE: u16 = 18;
+/// First code of [`WcsprError`]
+pub const WCSPR_ERRORS_BASE: u16 = 1000;
+
 #[repr(u16)]
 pub enum Errors {
-    /// 65,551 for (UniswapV2 Core WCSPR OverFlow1)
-    UniswapV2CoreWCSPROverFlow1 = 15,
-    /// 65,552 for (UniswapV2 Core WCSPR OverFlow2)
-    UniswapV2CoreWCSPROverFlow2 = 16,
-    /// 65,553 for (UniswapV2 Core WCSPR OverFlow3)
-    UniswapV2CoreWCSPROverFlow3 = 17,
-
     /// 65,556 for (UniswapV2 Factory No Pair Exists1)
-    UniswapV2FactoryNoPairExists1 = 18,
+    UniswapV2FactoryNoPairExists1 = ERRORS_BASE,
     /// 65,557 for (UniswapV2 Factory No Pair Exists2)
     UniswapV2FactoryNoPairExists2 = 19,
     /// 65,558 for (UniswapV2 Factory WhiteList Pair Mismatch)
@@ -169,6 +174,222 @@ pub enum Errors {
     UniswapV2CoreErc20SecureOnlyAdmin1 = 95,
     /// 65,655 for (UniswapV2 Core Erc20 Secure Only Admin)
     UniswapV2CoreErc20SecureOnlyAdmin2 = 96,
+    /// 65,633 for (UniswapV2 Core FlashSwapper Not Owner)
+    UniswapV2CoreFlashSwapperNotOwner = 97,
+    /// 65,634 for (UniswapV2 Core FlashSwapper Invalid Pair)
+    UniswapV2CoreFlashSwapperInvalidPair = 98,
+    /// 65,635 for (UniswapV2 Core FlashSwapper Invalid Path)
+    UniswapV2CoreFlashSwapperInvalidPath = 99,
+    /// 65,636 for (UniswapV2 Core FlashSwapper Insufficient Repayment)
+    UniswapV2CoreFlashSwapperInsufficientRepayment = 100,
+
+    /// 65,637 for (UniswapV2 Library Insufficient Input Amount)
+    UniswapV2LibraryInsufficientInputAmount = 101,
+    /// 65,638 for (UniswapV2 Library Insufficient Output Amount)
+    UniswapV2LibraryInsufficientOutputAmount = 102,
+    /// 65,639 for (UniswapV2 Library Insufficient Liquidity)
+    UniswapV2LibraryInsufficientLiquidity = 103,
+    /// 65,640 for (UniswapV2 Library OverFlow)
+    UniswapV2LibraryOverFlow = 104,
+    /// 65,641 for (UniswapV2 Library UnderFlow)
+    UniswapV2LibraryUnderFlow = 105,
+
+    /// 65,642 for (UniswapV2 Core FlashSwapper Protocol Fee Too High)
+    UniswapV2CoreFlashSwapperProtocolFeeTooHigh = 106,
+
+    /// 65,643 for (UniswapV2 Core Pair Locked4)
+    UniswapV2CorePairLocked4 = 107,
+    /// 65,644 for (UniswapV2 Core Pair Unsupported Token)
+    UniswapV2CorePairUnsupportedToken = 108,
+    /// 65,645 for (UniswapV2 Core Pair Flash Loan Not Repaid)
+    UniswapV2CorePairFlashLoanNotRepaid = 109,
+    /// 65,646 for (UniswapV2 Core Pair Multiplication OverFlow18)
+    UniswapV2CorePairMultiplicationOverFlow18 = 110,
+    /// 65,647 for (UniswapV2 Core Pair OverFlow7)
+    UniswapV2CorePairOverFlow7 = 111,
+    /// 65,648 for (UniswapV2 Core Pair OverFlow8)
+    UniswapV2CorePairOverFlow8 = 112,
+
+    /// 65,649 for (Multicall Invalid Calls)
+    MulticallInvalidCalls = 113,
+    /// 65,650 for (UniswapV2 Factory Forbidden3)
+    UniswapV2FactoryForbidden3 = 114,
+    /// 65,651 for (UniswapV2 Factory Forbidden4)
+    UniswapV2FactoryForbidden4 = 115,
+    /// 65,652 for (UniswapV2 Factory Protocol Fee Too High)
+    UniswapV2FactoryProtocolFeeTooHigh = 116,
+    /// 65,653 for (UniswapV2 Core Pair Multiplication OverFlow19)
+    UniswapV2CorePairMultiplicationOverFlow19 = 117,
+    /// 65,654 for (UniswapV2 Core Pair Multiplication OverFlow20)
+    UniswapV2CorePairMultiplicationOverFlow20 = 118,
+    /// 65,655 for (UniswapV2 Core Pair UnderFlow10)
+    UniswapV2CorePairUnderFlow10 = 119,
+    /// 65,656 for (Fee Collector Not Owner)
+    FeeCollectorNotOwner = 120,
+    /// 65,657 for (Fee Collector Invalid Route)
+    FeeCollectorInvalidRoute = 121,
+    /// 65,658 for (Fee Collector OverFlow)
+    FeeCollectorOverFlow = 122,
+    /// 65,659 for (Zap Invalid Token)
+    ZapInvalidToken = 123,
+    /// 65,660 for (Zap Insufficient Liquidity Minted)
+    ZapInsufficientLiquidityMinted = 124,
+    /// 65,661 for (Zap Insufficient Output Amount)
+    ZapInsufficientOutputAmount = 125,
+    /// 65,662 for (Zap OverFlow)
+    ZapOverFlow = 126,
+    /// 65,663 for (Zap UnderFlow)
+    ZapUnderFlow = 127,
+    /// 65,664 for (Router Expired)
+    RouterExpired = 128,
+    /// 65,665 for (Router Invalid Path)
+    RouterInvalidPath = 129,
+    /// 65,666 for (Router Pair Not Found)
+    RouterPairNotFound = 130,
+    /// 65,667 for (Router Insufficient Output Amount)
+    RouterInsufficientOutputAmount = 131,
+    /// 65,668 for (Router UnderFlow)
+    RouterUnderFlow = 132,
+    /// 65,669 for (UniswapV2 Library Stable Invariant Not Converged)
+    UniswapV2LibraryStableNotConverged = 133,
+    /// 65,670 for (UniswapV2 Core Pair Invalid Pair Type)
+    UniswapV2CorePairInvalidPairType = 134,
+    /// 65,671 for (UniswapV2 Core Pair Invalid Amplification)
+    UniswapV2CorePairInvalidAmplification = 135,
+    /// 65,672 for (UniswapV2 Core Pair Stable Decimals Mismatch)
+    UniswapV2CorePairStableDecimalsMismatch = 136,
+    /// 65,673 for (UniswapV2 Library Math Out Of Range)
+    UniswapV2LibraryMathOutOfRange = 137,
+    /// 65,674 for (UniswapV2 Core Pair Invalid Weight)
+    UniswapV2CorePairInvalidWeight = 138,
+    /// 65,675 for (Limit Order Invalid Token)
+    LimitOrderInvalidToken = 139,
+    /// 65,676 for (Limit Order Invalid Amount)
+    LimitOrderInvalidAmount = 140,
+    /// 65,677 for (Limit Order Expired)
+    LimitOrderExpired = 141,
+    /// 65,678 for (Limit Order Not Found)
+    LimitOrderNotFound = 142,
+    /// 65,679 for (Limit Order Not Open)
+    LimitOrderNotOpen = 143,
+    /// 65,680 for (Limit Order Forbidden)
+    LimitOrderForbidden = 144,
+    /// 65,681 for (Limit Order Price Not Reached)
+    LimitOrderPriceNotReached = 145,
+    /// 65,682 for (Limit Order OverFlow)
+    LimitOrderOverFlow = 146,
+    /// 65,683 for (Limit Order UnderFlow)
+    LimitOrderUnderFlow = 147,
+    /// 65,684 for (Staking Rewards Not Owner)
+    StakingRewardsNotOwner = 148,
+    /// 65,685 for (Staking Rewards Pool Not Found)
+    StakingRewardsPoolNotFound = 149,
+    /// 65,686 for (Staking Rewards Invalid Amount)
+    StakingRewardsInvalidAmount = 150,
+    /// 65,687 for (Staking Rewards Insufficient Balance)
+    StakingRewardsInsufficientBalance = 151,
+    /// 65,688 for (Staking Rewards Period Not Finished)
+    StakingRewardsPeriodNotFinished = 152,
+    /// 65,689 for (Staking Rewards Invalid Duration)
+    StakingRewardsInvalidDuration = 153,
+    /// 65,690 for (Staking Rewards OverFlow)
+    StakingRewardsOverFlow = 154,
+    /// 65,691 for (Staking Rewards UnderFlow)
+    StakingRewardsUnderFlow = 155,
+    /// 65,692 for (UniswapV2 Core Pair Forbidden3)
+    UniswapV2CorePairForbidden3 = 156,
+    /// 65,693 for (UniswapV2 Core Pair Checkpoints Enabled)
+    UniswapV2CorePairCheckpointsEnabled = 157,
+    /// 65,694 for (UniswapV2 Core Pair Checkpoints Disabled)
+    UniswapV2CorePairCheckpointsDisabled = 158,
+    /// 65,695 for (UniswapV2 Core Pair Checkpoint Invalid Timestamp)
+    UniswapV2CorePairCheckpointInvalidTimestamp = 159,
+    /// 65,696 for (Governance Token Invalid Signature)
+    GovernanceTokenInvalidSignature = 160,
+    /// 65,697 for (Governance Token Invalid Nonce)
+    GovernanceTokenInvalidNonce = 161,
+    /// 65,698 for (Governance Token Signature Expired)
+    GovernanceTokenSignatureExpired = 162,
+    /// 65,699 for (Governance Token Not Yet Determined)
+    GovernanceTokenNotYetDetermined = 163,
+    /// 65,700 for (Governance Token OverFlow)
+    GovernanceTokenOverFlow = 164,
+    /// 65,701 for (Governance Token UnderFlow)
+    GovernanceTokenUnderFlow = 165,
+    /// 65,702 for (Timelock Not Admin)
+    TimelockNotAdmin = 166,
+    /// 65,703 for (Timelock Not Pending Admin)
+    TimelockNotPendingAdmin = 167,
+    /// 65,704 for (Timelock Not Timelock)
+    TimelockNotTimelock = 168,
+    /// 65,705 for (Timelock Invalid Delay)
+    TimelockInvalidDelay = 169,
+    /// 65,706 for (Timelock Eta Too Early)
+    TimelockEtaTooEarly = 170,
+    /// 65,707 for (Timelock Already Queued)
+    TimelockAlreadyQueued = 171,
+    /// 65,708 for (Timelock Not Queued)
+    TimelockNotQueued = 172,
+    /// 65,709 for (Timelock Not Ready)
+    TimelockNotReady = 173,
+    /// 65,710 for (Timelock Stale)
+    TimelockStale = 174,
+    /// 65,711 for (Timelock Invalid Args)
+    TimelockInvalidArgs = 175,
+    /// 65,712 for (Governor Below Threshold)
+    GovernorBelowThreshold = 176,
+    /// 65,713 for (Governor Invalid Actions)
+    GovernorInvalidActions = 177,
+    /// 65,714 for (Governor Live Proposal)
+    GovernorLiveProposal = 178,
+    /// 65,715 for (Governor Proposal Not Found)
+    GovernorProposalNotFound = 179,
+    /// 65,716 for (Governor Invalid State)
+    GovernorInvalidState = 180,
+    /// 65,717 for (Governor Already Voted)
+    GovernorAlreadyVoted = 181,
+    /// 65,718 for (Governor Not Guardian)
+    GovernorNotGuardian = 182,
+    /// 65,719 for (Governor OverFlow)
+    GovernorOverFlow = 183,
+    /// 65,720 for (Upgrade Not Package Owner)
+    UpgradeNotPackageOwner = 184,
+    /// 65,721 for (Upgrade Invalid Version)
+    UpgradeInvalidVersion = 185,
+    /// 65,722 for (UniswapV2 Core Pair Invalid Minimum Liquidity)
+    UniswapV2CorePairInvalidMinimumLiquidity = 186,
+    /// 65,723 for (UniswapV2 Core Pair Invalid Owner)
+    UniswapV2CorePairInvalidOwner = 187,
+    /// 65,724 for (UniswapV2 Core Pair Locked5)
+    UniswapV2CorePairLocked5 = 188,
+    /// 65,725 for (UniswapV2 Core Pair Locked6)
+    UniswapV2CorePairLocked6 = 189,
+    /// 65,726 for (UniswapV2 Core Pair Invalid Callee)
+    UniswapV2CorePairInvalidCallee = 190,
+    /// 65,727 for (UniswapV2 Library Unsupported Pair Type)
+    UniswapV2LibraryUnsupportedPairType = 191,
+    /// 65,728 for (Staking Rewards Invalid Lp Token)
+    StakingRewardsInvalidLpToken = 192,
+    /// 65,729 for (Staking Rewards Pool Exists)
+    StakingRewardsPoolExists = 193,
+    /// 65,730 for (Governor Proposer Above Threshold)
+    GovernorProposerAboveThreshold = 194,
+    /// 65,731 for (Fee Collector Insufficient Output Amount)
+    FeeCollectorInsufficientOutputAmount = 195,
+    /// 65,732 for (Fee Collector Invalid Amounts Out Min)
+    FeeCollectorInvalidAmountsOutMin = 196,
+    /// 65,733 for (UniswapV2 Core Pair Stable OverFlow1)
+    UniswapV2CorePairStableOverFlow1 = 197,
+    /// 65,734 for (UniswapV2 Core Pair Stable OverFlow2)
+    UniswapV2CorePairStableOverFlow2 = 198,
+    /// 65,735 for (UniswapV2 Core Pair Weighted OverFlow1)
+    UniswapV2CorePairWeightedOverFlow1 = 199,
+    /// 65,736 for (UniswapV2 Core Pair Weighted OverFlow2)
+    UniswapV2CorePairWeightedOverFlow2 = 200,
+    /// 65,737 for (UniswapV2 Library Stable OverFlow)
+    UniswapV2LibraryStableOverFlow = 201,
+    /// 65,738 for (UniswapV2 Library Weighted OverFlow)
+    UniswapV2LibraryWeightedOverFlow = 202,
 }
 
 impl From<Errors> for ApiError {
@@ -176,3 +397,39 @@ impl From<Errors> for ApiError {
         ApiError::User(error as u16)
     }
 }
+
+#[repr(u16)]
+pub enum WcsprError {
+    /// 66,536 for (WCSPR Zero Amount)
+    ZeroAmount = WCSPR_ERRORS_BASE,
+    /// 66,537 for (WCSPR Amount OverFlow)
+    AmountOverFlow = WCSPR_ERRORS_BASE + 1,
+    /// 66,538 for (WCSPR Purse OverFlow)
+    PurseOverFlow = WCSPR_ERRORS_BASE + 2,
+    /// 66,539 for (WCSPR Insufficient Balance)
+    InsufficientBalance = WCSPR_ERRORS_BASE + 3,
+    /// 66,540 for (WCSPR Insufficient Allowance)
+    InsufficientAllowance = WCSPR_ERRORS_BASE + 4,
+    /// 66,541 for (WCSPR Purse Transfer Failed)
+    PurseTransferFailed = WCSPR_ERRORS_BASE + 5,
+    /// 66,542 for (WCSPR Migrate Failed)
+    MigrateFailed = WCSPR_ERRORS_BASE + 6,
+    /// 66,543 for (WCSPR Not Owner)
+    NotOwner = WCSPR_ERRORS_BASE + 7,
+    /// 66,544 for (WCSPR Backing Deficit)
+    BackingDeficit = WCSPR_ERRORS_BASE + 8,
+    /// 66,545 for (WCSPR Invalid Recipient)
+    InvalidRecipient = WCSPR_ERRORS_BASE + 9,
+    /// 66,546 for (WCSPR Invalid Entry Point)
+    InvalidEntryPoint = WCSPR_ERRORS_BASE + 10,
+    /// 66,547 for (WCSPR Insufficient Liquidity Minted)
+    InsufficientLiquidityMinted = WCSPR_ERRORS_BASE + 11,
+    /// 66,548 for (WCSPR Insufficient Output Amount)
+    InsufficientOutputAmount = WCSPR_ERRORS_BASE + 12,
+}
+
+impl From<WcsprError> for ApiError {
+    fn from(error: WcsprError) -> ApiError {
+        ApiError::User(error as u16)
+    }
+}
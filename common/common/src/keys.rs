@@ -1,15 +1,31 @@
 // common keys
 pub const PURSE: &str = "purse";
+pub const LAST_EVENT: &str = "last_event";
+
+// upgrades
+pub const CONTRACT_VERSION: &str = "contract_version";
+pub const PACKAGE_OWNER: &str = "package_owner";
 
 // session code
 pub const ENTRYPOINT: &str = "entrypoint";
 pub const PACKAGE_HASH: &str = "package_hash";
 pub const DEPOSIT: &str = "deposit";
 pub const WITHDRAW: &str = "withdraw";
+pub const DEPOSIT_TO: &str = "deposit_to";
+pub const WITHDRAW_TO: &str = "withdraw_to";
+pub const WITHDRAW_FROM: &str = "withdraw_from";
+pub const WRAP_AND_ADD_LIQUIDITY: &str = "wrap_and_add_liquidity";
+pub const WRAP_AND_SWAP: &str = "wrap_and_swap";
+pub const CALLS: &str = "calls";
+
+// wcspr
+pub const EXCESS_RECIPIENT: &str = "excess_recipient";
 
 // factory
 pub const WHITELISTS_DICT: &str = "white_lists";
 pub const PAIRS_DICT: &str = "pairs";
+pub const PAIR_IDS_DICT: &str = "pair_ids";
+pub const PROTOCOL_FEE_OVERRIDES_DICT: &str = "protocol_fee_overrides";
 pub const FEE_TO: &str = "fee_to";
 pub const FEE_TO_SETTER: &str = "fee_to_setter";
 pub const ALL_PAIRS: &str = "all_pairs";
@@ -17,22 +33,25 @@ pub const OWNER: &str = "owner";
 
 // flashswapper
 pub const WCSPR: &str = "wcspr";
-pub const DAI: &str = "dai";
+pub const BASE_TOKENS: &str = "base_tokens";
 pub const BTC: &str = "btc";
 pub const CSPR: &str = "cspr";
 pub const PERMISSIONED_PAIR_ADDRESS: &str = "permissioned_pair_address";
 pub const UNISWAP_V2_FACTROY: &str = "uniswap_v2_factory";
 pub const UNISWAP_V2_PAIR: &str = "uniswap_v2_pair";
+pub const BORROWER: &str = "borrower";
+pub const PROTOCOL_FEE: &str = "protocol_fee";
+pub const TREASURY: &str = "treasury";
 
 // pair
 pub const FACTORY: &str = "factory_hash";
 pub const RESERVE0: &str = "reserve0";
 pub const RESERVE1: &str = "reserve1";
+pub const LAST_FLASH_LOAN: &str = "last_flash_loan";
 pub const BLOCK_TIMESTAMP_LAST: &str = "block_timestamp_last";
 pub const PRICE0_CUMULATIVE_LAST: &str = "price0_cumulative_last";
 pub const PRICE1_CUMULATIVE_LAST: &str = "price1_cumulative_last";
 pub const K_LAST: &str = "k_last";
-pub const TREASURY_FEE: &str = "treasury_fee";
 pub const MINIMUM_LIQUIDITY: &str = "minimum_liquidity";
 pub const TOKEN0: &str = "token0";
 pub const TOKEN1: &str = "token1";
@@ -42,3 +61,60 @@ pub const AMOUNT1: &str = "amount1";
 pub const CALLEE_PACKAGE_HASH: &str = "callee_package_hash";
 pub const LOCK: &str = "lock";
 pub const PAUSE: &str = "pause";
+pub const ERC20_NAME: &str = "name";
+pub const ERC20_SYMBOL: &str = "symbol";
+pub const ERC20_DECIMALS: &str = "decimals";
+pub const PAIR_TYPE: &str = "pair_type";
+pub const AMPLIFICATION: &str = "amplification";
+pub const WEIGHT0: &str = "weight0";
+pub const CHECKPOINTS_ENABLED_AT: &str = "checkpoints_enabled_at";
+pub const BALANCE_CHECKPOINTS_DICT: &str = "balance_checkpoints";
+pub const BALANCE_CHECKPOINT_COUNTS_DICT: &str = "balance_checkpoint_counts";
+pub const TOTAL_SUPPLY_CHECKPOINTS_DICT: &str = "total_supply_checkpoints";
+pub const TOTAL_SUPPLY_CHECKPOINT_COUNT: &str = "total_supply_checkpoint_count";
+
+// fee collector
+pub const TARGET_TOKEN: &str = "target_token";
+pub const ROUTES_DICT: &str = "routes";
+
+// limit order
+pub const ORDERS_DICT: &str = "orders";
+pub const ORDERS_COUNT: &str = "orders_count";
+
+// staking rewards
+pub const REWARDS_TOKEN: &str = "rewards_token";
+pub const REWARDS_DURATION: &str = "rewards_duration";
+pub const REWARD_RATE: &str = "reward_rate";
+pub const PERIOD_FINISH: &str = "period_finish";
+pub const TOTAL_ALLOC_POINT: &str = "total_alloc_point";
+pub const POOLS_DICT: &str = "pools";
+pub const POOLS_COUNT: &str = "pools_count";
+pub const STAKES_DICT: &str = "stakes";
+pub const USER_REWARD_PER_TOKEN_PAID_DICT: &str = "user_reward_per_token_paid";
+pub const REWARDS_DICT: &str = "rewards";
+
+// governance token
+pub const DELEGATES_DICT: &str = "delegates";
+pub const VOTE_CHECKPOINTS_DICT: &str = "vote_checkpoints";
+pub const VOTE_CHECKPOINT_COUNTS_DICT: &str = "vote_checkpoint_counts";
+pub const NONCES_DICT: &str = "nonces";
+
+// timelock
+pub const ADMIN: &str = "admin";
+pub const PENDING_ADMIN: &str = "pending_admin";
+pub const ADMIN_INITIALIZED: &str = "admin_initialized";
+pub const DELAY: &str = "delay";
+pub const QUEUED_TRANSACTIONS_DICT: &str = "queued_transactions";
+
+// governor
+pub const TIMELOCK: &str = "timelock";
+pub const GOVERNANCE_TOKEN: &str = "governance_token";
+pub const GUARDIAN: &str = "guardian";
+pub const VOTING_DELAY: &str = "voting_delay";
+pub const VOTING_PERIOD: &str = "voting_period";
+pub const PROPOSAL_THRESHOLD: &str = "proposal_threshold";
+pub const QUORUM_VOTES: &str = "quorum_votes";
+pub const PROPOSALS_DICT: &str = "proposals";
+pub const PROPOSAL_COUNT: &str = "proposal_count";
+pub const LATEST_PROPOSAL_IDS_DICT: &str = "latest_proposal_ids";
+pub const RECEIPTS_DICT: &str = "receipts";
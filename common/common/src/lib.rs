@@ -1,6 +1,16 @@
+pub mod calls;
 pub mod errors;
 pub mod functions;
 pub mod keys;
+pub mod math;
+pub mod multicall;
+pub mod order;
+pub mod pair_info;
+pub mod proposal;
+pub mod quote;
+pub mod signature;
+pub mod staking_pool;
+pub mod upgrade;
 
 pub use casper_contract::*;
 pub use casper_types::*;
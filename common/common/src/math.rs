@@ -0,0 +1,393 @@
+use crate::errors::Errors;
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::U256;
+
+/// Given an input amount of an asset and pair reserves, returns the maximum output amount of the
+/// other asset, after the 0.3% pair fee
+pub fn get_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    if amount_in.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientInputAmount);
+    }
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let amount_in_with_fee: U256 = amount_in
+        .checked_mul(997.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow);
+    let numerator: U256 = amount_in_with_fee
+        .checked_mul(reserve_out)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow);
+    let denominator: U256 = reserve_in
+        .checked_mul(1000.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow)
+        .checked_add(amount_in_with_fee)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow);
+    numerator / denominator
+}
+
+/// Given an output amount of an asset and pair reserves, returns the required input amount of the
+/// other asset, after the 0.3% pair fee
+pub fn get_amount_in(amount_out: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    if amount_out.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientOutputAmount);
+    }
+    if reserve_in.is_zero() || amount_out >= reserve_out {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let numerator: U256 = reserve_in
+        .checked_mul(amount_out)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow)
+        .checked_mul(1000.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow);
+    let denominator: U256 = reserve_out
+        .checked_sub(amount_out)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryUnderFlow)
+        .checked_mul(997.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow);
+    (numerator / denominator)
+        .checked_add(1.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow)
+}
+
+/// Babylonian integer square root
+pub fn sqrt(y: U256) -> U256 {
+    let mut z: U256 = 0.into();
+    if y > 3.into() {
+        z = y;
+        let mut x: U256 = y / 2 + 1;
+        while x < z {
+            z = x;
+            x = (y / x + x) / 2;
+        }
+    } else if !y.is_zero() {
+        z = 1.into();
+    }
+    z
+}
+
+/// Given an input amount of one asset of a pair and its reserve, returns how much of it to swap
+/// so the swap output and the remainder can be added as liquidity in the new ratio, after the 0.3%
+/// pair fee. Closed form of the quadratic `s^2 * 997 + s * r * 1997 - a * r * 1000 = 0`
+pub fn get_zap_swap_amount(amount_in: U256, reserve_in: U256) -> U256 {
+    if amount_in.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientInputAmount);
+    }
+    if reserve_in.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let root: U256 = sqrt(
+        reserve_in
+            .checked_mul(
+                reserve_in
+                    .checked_mul(3_988_009.into())
+                    .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow)
+                    .checked_add(
+                        amount_in
+                            .checked_mul(3_988_000.into())
+                            .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow),
+                    )
+                    .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow),
+            )
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow),
+    );
+    root.checked_sub(
+        reserve_in
+            .checked_mul(1_997.into())
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryOverFlow),
+    )
+    .unwrap_or_revert_with(Errors::UniswapV2LibraryUnderFlow)
+        / 1_994
+}
+
+/// Iterations of Newton's method before the StableSwap math gives up
+const STABLE_MAX_ITERATIONS: u32 = 255;
+
+/// Returns the StableSwap invariant `D` of a two token pool holding `x` and `y`, with amplification
+/// coefficient `amplification`, found with Newton's method on
+/// `4A(x + y) + D = 4AD + D^3 / 4xy`
+pub fn get_stable_d(x: U256, y: U256, amplification: U256) -> U256 {
+    let s: U256 = x
+        .checked_add(y)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+    if s.is_zero() {
+        return 0.into();
+    }
+    if x.is_zero() || y.is_zero() || amplification.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let ann: U256 = amplification
+        .checked_mul(4.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+    let mut d: U256 = s;
+    for _ in 0..STABLE_MAX_ITERATIONS {
+        // D^3 / 4xy, divided as it goes to stay in range
+        let d_p: U256 = (d
+            .checked_mul(d)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            / (x * 2))
+            .checked_mul(d)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            / (y * 2);
+        let d_prev: U256 = d;
+        let numerator: U256 = ann
+            .checked_mul(s)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            .checked_add(
+                d_p.checked_mul(2.into())
+                    .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+            )
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            .checked_mul(d)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+        let denominator: U256 = (ann - 1)
+            .checked_mul(d)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            .checked_add(
+                d_p.checked_mul(3.into())
+                    .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+            )
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+        d = numerator / denominator;
+        if d.max(d_prev) - d.min(d_prev) <= 1.into() {
+            return d;
+        }
+    }
+    runtime::revert(Errors::UniswapV2LibraryStableNotConverged);
+}
+
+/// Returns how much of the other token a StableSwap pool with invariant `d` holds when it holds
+/// `x` of one token, found with Newton's method on `y^2 + (x + D/4A - D)y = D^3 / 16Ax`
+pub fn get_stable_y(x: U256, d: U256, amplification: U256) -> U256 {
+    if x.is_zero() || amplification.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let ann: U256 = amplification
+        .checked_mul(4.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+    let c: U256 = (d
+        .checked_mul(d)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+        / (x * 2))
+        .checked_mul(d)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+        / (ann * 2);
+    let b: U256 = x
+        .checked_add(d / ann)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow);
+    let mut y: U256 = d;
+    for _ in 0..STABLE_MAX_ITERATIONS {
+        let y_prev: U256 = y;
+        y = y
+            .checked_mul(y)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            .checked_add(c)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            / (y * 2 + b)
+                .checked_sub(d)
+                .unwrap_or_revert_with(Errors::UniswapV2LibraryUnderFlow);
+        if y.max(y_prev) - y.min(y_prev) <= 1.into() {
+            return y;
+        }
+    }
+    runtime::revert(Errors::UniswapV2LibraryStableNotConverged);
+}
+
+/// Given an input amount of an asset and the reserves of a stable pair, returns the maximum output
+/// amount of the other asset, after the 0.3% pair fee
+pub fn get_stable_amount_out(
+    amount_in: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    amplification: U256,
+) -> U256 {
+    if amount_in.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientInputAmount);
+    }
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    // scaled by 1000 like the balances the pair checks its invariant on, so the fee is exact
+    let thousand: U256 = 1000.into();
+    let d: U256 = get_stable_d(
+        reserve_in
+            .checked_mul(thousand)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+        reserve_out
+            .checked_mul(thousand)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+        amplification,
+    );
+    let y: U256 = get_stable_y(
+        reserve_in
+            .checked_mul(thousand)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow)
+            .checked_add(
+                amount_in
+                    .checked_mul(997.into())
+                    .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+            )
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryStableOverFlow),
+        d,
+        amplification,
+    );
+    // rounds the reserve left up, so rounding in `D` never fails the pair's invariant check
+    reserve_out.saturating_sub(y / thousand + 1)
+}
+
+/// One in the 18 decimals fixed point `ln`, `exp` and `pow` work in
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+/// ln(2) in 18 decimals fixed point
+const LN_2: i128 = 693_147_180_559_945_309;
+/// Largest exponent `exp` accepts, e^130 still fits a U256 in fixed point
+const EXP_MAX: i128 = 130 * WAD as i128;
+/// Exponent below which `exp` rounds to zero in fixed point
+const EXP_MIN: i128 = -42 * WAD as i128;
+/// Weights of a weighted pair are given in basis points of this
+pub const WEIGHT_ONE: u64 = 10_000;
+
+/// Natural logarithm of `x`, both in 18 decimals fixed point. `x` is brought into [1, 2) by powers
+/// of two, and the rest comes from the series ln(x) = 2 * atanh((x - 1) / (x + 1))
+pub fn ln(x: U256) -> i128 {
+    if x.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryMathOutOfRange);
+    }
+    let one: U256 = WAD.into();
+    let mut x: U256 = x;
+    let mut k: i128 = 0;
+    while x >= one * 2 {
+        x /= 2;
+        k += 1;
+    }
+    while x < one {
+        x *= 2;
+        k -= 1;
+    }
+    let z: U256 = (x - one) * one / (x + one);
+    let z_squared: U256 = z * z / one;
+    let mut term: U256 = z;
+    let mut sum: U256 = 0.into();
+    let mut n: u64 = 1;
+    while !term.is_zero() {
+        sum += term / n;
+        term = term * z_squared / one;
+        n += 2;
+    }
+    k * LN_2 + 2 * sum.as_u128() as i128
+}
+
+/// e to the power of `x`, both in 18 decimals fixed point. `x` is split into k * ln(2) + r with
+/// |r| <= ln(2) / 2, so e^x = 2^k * e^r with e^r from its Taylor series
+pub fn exp(x: i128) -> U256 {
+    if x > EXP_MAX {
+        runtime::revert(Errors::UniswapV2LibraryMathOutOfRange);
+    }
+    if x < EXP_MIN {
+        return 0.into();
+    }
+    let one: i128 = WAD as i128;
+    let k: i128 = if x >= 0 {
+        (2 * x + LN_2) / (2 * LN_2)
+    } else {
+        -((-2 * x + LN_2) / (2 * LN_2))
+    };
+    let r: i128 = x - k * LN_2;
+    let mut term: i128 = one;
+    let mut sum: i128 = one;
+    let mut n: i128 = 1;
+    while term != 0 {
+        term = term * r / (one * n);
+        sum += term;
+        n += 1;
+    }
+    let sum: U256 = U256::from(sum as u128);
+    if k >= 0 {
+        sum << k as usize
+    } else {
+        sum >> (-k) as usize
+    }
+}
+
+/// `base` to the power of `exponent`, all in 18 decimals fixed point, as e^(exponent * ln(base))
+pub fn pow(base: U256, exponent: U256) -> U256 {
+    if exponent.is_zero() {
+        return WAD.into();
+    }
+    if base.is_zero() {
+        return 0.into();
+    }
+    let ln_base: i128 = ln(base);
+    let magnitude: U256 = U256::from(ln_base.unsigned_abs())
+        .checked_mul(exponent)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow)
+        / WAD;
+    if magnitude > U256::from(EXP_MAX as u128) {
+        if ln_base < 0 {
+            return 0.into();
+        }
+        runtime::revert(Errors::UniswapV2LibraryMathOutOfRange);
+    }
+    let magnitude: i128 = magnitude.as_u128() as i128;
+    exp(if ln_base < 0 { -magnitude } else { magnitude })
+}
+
+/// Weighted geometric mean `x^w0 * y^(1 - w0)`, with `weight0` in basis points of `WEIGHT_ONE`
+pub fn get_weighted_mean(x: U256, y: U256, weight0: U256) -> U256 {
+    if x.is_zero() || y.is_zero() {
+        return 0.into();
+    }
+    exp(get_weighted_log(x, y, weight0) / i128::from(WEIGHT_ONE)) / WAD
+}
+
+/// `w0 * ln(x) + (1 - w0) * ln(y)` in fixed point, scaled by `WEIGHT_ONE`. The invariant a
+/// weighted pair holds is the exponential of this
+pub fn get_weighted_log(x: U256, y: U256, weight0: U256) -> i128 {
+    let one: U256 = WAD.into();
+    let weight0: i128 = weight0.as_u128() as i128;
+    weight0
+        * ln(x
+            .checked_mul(one)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow))
+        + (i128::from(WEIGHT_ONE) - weight0)
+            * ln(y
+                .checked_mul(one)
+                .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow))
+}
+
+/// Given an input amount of an asset and the reserves and weights of a weighted pair, returns the
+/// maximum output amount of the other asset, after the 0.3% pair fee:
+/// `reserve_out * (1 - (reserve_in / (reserve_in + amount_in)) ^ (weight_in / weight_out))`
+pub fn get_weighted_amount_out(
+    amount_in: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    weight_in: U256,
+    weight_out: U256,
+) -> U256 {
+    if amount_in.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientInputAmount);
+    }
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        runtime::revert(Errors::UniswapV2LibraryInsufficientLiquidity);
+    }
+    let one: U256 = WAD.into();
+    let amount_in_with_fee: U256 = amount_in
+        .checked_mul(997.into())
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow)
+        / 1000;
+    let base: U256 = reserve_in
+        .checked_mul(one)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow)
+        / reserve_in
+            .checked_add(amount_in_with_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow);
+    let power: U256 = pow(base, weight_in * one / weight_out);
+    // rounds the power up by more than `ln` and `exp` can be off, so the pair's invariant holds
+    let power: U256 = power + power / 1_000_000_000_000u64 + 1;
+    if power >= one {
+        return 0.into();
+    }
+    reserve_out
+        .checked_mul(one - power)
+        .unwrap_or_revert_with(Errors::UniswapV2LibraryWeightedOverFlow)
+        / one
+}
@@ -0,0 +1,76 @@
+use casper_types::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLType, CLTyped, Key, RuntimeArgs,
+};
+
+/// Return value of a call taken as is, so any entry point can be called without knowing its type
+pub struct RawBytes(pub Vec<u8>);
+
+impl CLTyped for RawBytes {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for RawBytes {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.clone())
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FromBytes for RawBytes {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        Ok((RawBytes(bytes.to_vec()), &[]))
+    }
+}
+
+/// A single contract call of a multicall batch, the batch is passed to the session code as the
+/// serialized bytes of a `Vec<Call>`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    pub package_hash: Key,
+    pub entry_point: String,
+    pub args: RuntimeArgs,
+    /// Name of the account named key that the raw result bytes of the call are stored under
+    pub capture: Option<String>,
+}
+
+impl ToBytes for Call {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.package_hash.to_bytes()?);
+        result.append(&mut self.entry_point.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.capture.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.package_hash.serialized_length()
+            + self.entry_point.serialized_length()
+            + self.args.serialized_length()
+            + self.capture.serialized_length()
+    }
+}
+
+impl FromBytes for Call {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (package_hash, bytes) = Key::from_bytes(bytes)?;
+        let (entry_point, bytes) = String::from_bytes(bytes)?;
+        let (args, bytes) = RuntimeArgs::from_bytes(bytes)?;
+        let (capture, bytes) = Option::<String>::from_bytes(bytes)?;
+        Ok((
+            Call {
+                package_hash,
+                entry_point,
+                args,
+                capture,
+            },
+            bytes,
+        ))
+    }
+}
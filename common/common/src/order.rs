@@ -0,0 +1,100 @@
+use casper_types::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+/// Order waiting for the pair price to reach its limit, or partly filled
+pub const ORDER_STATUS_OPEN: u8 = 0;
+/// Order whose whole `amount_in` was swapped
+pub const ORDER_STATUS_FILLED: u8 = 1;
+/// Order cancelled by its owner, the rest of the deposit refunded
+pub const ORDER_STATUS_CANCELLED: u8 = 2;
+/// Open order past its deadline, only returned by the `order_status` view and never stored
+pub const ORDER_STATUS_EXPIRED: u8 = 3;
+
+/// Limit order kept by the limit-order contract, returned by its `get_order`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Order {
+    pub owner: Key,
+    pub pair: Key,
+    pub token_in: Key,
+    pub token_out: Key,
+    /// Amount of `token_in` the order sells in total
+    pub amount_in: U256,
+    /// Least amount of `token_out` for the whole `amount_in`, which sets the limit price
+    pub amount_out_min: U256,
+    /// Amount of `token_in` paid to keepers on top of `amount_in`, pro rata to what they fill
+    pub tip: U256,
+    /// Block time in milliseconds after which the order can't be executed
+    pub deadline: U256,
+    /// Amount of `amount_in` already swapped
+    pub filled: U256,
+    /// One of the `ORDER_STATUS_*` constants, never `ORDER_STATUS_EXPIRED`
+    pub status: u8,
+}
+
+impl CLTyped for Order {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Order {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.owner.to_bytes()?);
+        result.append(&mut self.pair.to_bytes()?);
+        result.append(&mut self.token_in.to_bytes()?);
+        result.append(&mut self.token_out.to_bytes()?);
+        result.append(&mut self.amount_in.to_bytes()?);
+        result.append(&mut self.amount_out_min.to_bytes()?);
+        result.append(&mut self.tip.to_bytes()?);
+        result.append(&mut self.deadline.to_bytes()?);
+        result.append(&mut self.filled.to_bytes()?);
+        result.append(&mut self.status.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.pair.serialized_length()
+            + self.token_in.serialized_length()
+            + self.token_out.serialized_length()
+            + self.amount_in.serialized_length()
+            + self.amount_out_min.serialized_length()
+            + self.tip.serialized_length()
+            + self.deadline.serialized_length()
+            + self.filled.serialized_length()
+            + self.status.serialized_length()
+    }
+}
+
+impl FromBytes for Order {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (owner, bytes) = Key::from_bytes(bytes)?;
+        let (pair, bytes) = Key::from_bytes(bytes)?;
+        let (token_in, bytes) = Key::from_bytes(bytes)?;
+        let (token_out, bytes) = Key::from_bytes(bytes)?;
+        let (amount_in, bytes) = U256::from_bytes(bytes)?;
+        let (amount_out_min, bytes) = U256::from_bytes(bytes)?;
+        let (tip, bytes) = U256::from_bytes(bytes)?;
+        let (deadline, bytes) = U256::from_bytes(bytes)?;
+        let (filled, bytes) = U256::from_bytes(bytes)?;
+        let (status, bytes) = u8::from_bytes(bytes)?;
+        Ok((
+            Order {
+                owner,
+                pair,
+                token_in,
+                token_out,
+                amount_in,
+                amount_out_min,
+                tip,
+                deadline,
+                filled,
+                status,
+            },
+            bytes,
+        ))
+    }
+}
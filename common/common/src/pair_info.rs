@@ -0,0 +1,122 @@
+use casper_types::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U128, U256,
+};
+
+/// Pair priced on the constant product `x * y = k`
+pub const PAIR_TYPE_CONSTANT_PRODUCT: u8 = 0;
+/// Pair priced on the StableSwap invariant with an amplification coefficient, for correlated assets
+pub const PAIR_TYPE_STABLE: u8 = 1;
+/// Pair priced on the weighted product `x^w0 * y^(1 - w0) = k`, for non 50/50 pools
+pub const PAIR_TYPE_WEIGHTED: u8 = 2;
+
+/// Everything a client needs to know about a pair, returned by the pair's `pair_info`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PairInfo {
+    pub token0: Key,
+    pub token1: Key,
+    pub symbol0: String,
+    pub symbol1: String,
+    pub decimals0: u8,
+    pub decimals1: u8,
+    pub reserve0: U128,
+    pub reserve1: U128,
+    pub block_timestamp_last: u64,
+    /// Swap fee in basis points
+    pub swap_fee: U256,
+    /// Protocol share of the swap fee in basis points, as set on the factory
+    pub protocol_fee: U256,
+    pub fee_to: Key,
+    /// One of the `PAIR_TYPE_*` constants, tells which amount math applies
+    pub pair_type: u8,
+    /// Amplification coefficient of a stable pair, zero for any other pair type
+    pub amplification: U256,
+    /// Weight of token0 in basis points for a weighted pair, zero for any other pair type
+    pub weight0: U256,
+}
+
+impl CLTyped for PairInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for PairInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.token0.to_bytes()?);
+        result.append(&mut self.token1.to_bytes()?);
+        result.append(&mut self.symbol0.to_bytes()?);
+        result.append(&mut self.symbol1.to_bytes()?);
+        result.append(&mut self.decimals0.to_bytes()?);
+        result.append(&mut self.decimals1.to_bytes()?);
+        result.append(&mut self.reserve0.to_bytes()?);
+        result.append(&mut self.reserve1.to_bytes()?);
+        result.append(&mut self.block_timestamp_last.to_bytes()?);
+        result.append(&mut self.swap_fee.to_bytes()?);
+        result.append(&mut self.protocol_fee.to_bytes()?);
+        result.append(&mut self.fee_to.to_bytes()?);
+        result.append(&mut self.pair_type.to_bytes()?);
+        result.append(&mut self.amplification.to_bytes()?);
+        result.append(&mut self.weight0.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token0.serialized_length()
+            + self.token1.serialized_length()
+            + self.symbol0.serialized_length()
+            + self.symbol1.serialized_length()
+            + self.decimals0.serialized_length()
+            + self.decimals1.serialized_length()
+            + self.reserve0.serialized_length()
+            + self.reserve1.serialized_length()
+            + self.block_timestamp_last.serialized_length()
+            + self.swap_fee.serialized_length()
+            + self.protocol_fee.serialized_length()
+            + self.fee_to.serialized_length()
+            + self.pair_type.serialized_length()
+            + self.amplification.serialized_length()
+            + self.weight0.serialized_length()
+    }
+}
+
+impl FromBytes for PairInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (token0, bytes) = Key::from_bytes(bytes)?;
+        let (token1, bytes) = Key::from_bytes(bytes)?;
+        let (symbol0, bytes) = String::from_bytes(bytes)?;
+        let (symbol1, bytes) = String::from_bytes(bytes)?;
+        let (decimals0, bytes) = u8::from_bytes(bytes)?;
+        let (decimals1, bytes) = u8::from_bytes(bytes)?;
+        let (reserve0, bytes) = U128::from_bytes(bytes)?;
+        let (reserve1, bytes) = U128::from_bytes(bytes)?;
+        let (block_timestamp_last, bytes) = u64::from_bytes(bytes)?;
+        let (swap_fee, bytes) = U256::from_bytes(bytes)?;
+        let (protocol_fee, bytes) = U256::from_bytes(bytes)?;
+        let (fee_to, bytes) = Key::from_bytes(bytes)?;
+        let (pair_type, bytes) = u8::from_bytes(bytes)?;
+        let (amplification, bytes) = U256::from_bytes(bytes)?;
+        let (weight0, bytes) = U256::from_bytes(bytes)?;
+        Ok((
+            PairInfo {
+                token0,
+                token1,
+                symbol0,
+                symbol1,
+                decimals0,
+                decimals1,
+                reserve0,
+                reserve1,
+                block_timestamp_last,
+                swap_fee,
+                protocol_fee,
+                fee_to,
+                pair_type,
+                amplification,
+                weight0,
+            },
+            bytes,
+        ))
+    }
+}
@@ -0,0 +1,114 @@
+use casper_types::{
+    bytesrepr::{Bytes, Error, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+/// Proposal waiting for its voting to start
+pub const PROPOSAL_STATE_PENDING: u8 = 0;
+/// Proposal being voted on
+pub const PROPOSAL_STATE_ACTIVE: u8 = 1;
+/// Proposal cancelled by the guardian, or because its proposer fell below the threshold
+pub const PROPOSAL_STATE_CANCELED: u8 = 2;
+/// Proposal that lost the vote or missed the quorum
+pub const PROPOSAL_STATE_DEFEATED: u8 = 3;
+/// Proposal that won the vote, waiting to be queued in the timelock
+pub const PROPOSAL_STATE_SUCCEEDED: u8 = 4;
+/// Proposal queued in the timelock, waiting for its eta
+pub const PROPOSAL_STATE_QUEUED: u8 = 5;
+/// Queued proposal not executed within the timelock's grace period
+pub const PROPOSAL_STATE_EXPIRED: u8 = 6;
+/// Proposal whose calls were executed by the timelock
+pub const PROPOSAL_STATE_EXECUTED: u8 = 7;
+
+/// Proposal kept by the governor, returned by its `get_proposal`. Its state is derived from these
+/// fields and the block time by the governor's `state`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub proposer: Key,
+    /// Package hashes of the contracts the proposal calls, through the timelock
+    pub targets: Vec<Key>,
+    /// Entry point called on each target
+    pub entry_points: Vec<String>,
+    /// Serialized `RuntimeArgs` of each call
+    pub args: Vec<Bytes>,
+    /// Block time in milliseconds the timelock can execute the calls from, 0 until queued
+    pub eta: u64,
+    /// Block time in milliseconds the voting starts after, votes are weighted as of it
+    pub start_time: u64,
+    /// Block time in milliseconds the voting ends at
+    pub end_time: u64,
+    pub for_votes: U256,
+    pub against_votes: U256,
+    pub canceled: bool,
+    pub executed: bool,
+}
+
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.proposer.to_bytes()?);
+        result.append(&mut self.targets.to_bytes()?);
+        result.append(&mut self.entry_points.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.eta.to_bytes()?);
+        result.append(&mut self.start_time.to_bytes()?);
+        result.append(&mut self.end_time.to_bytes()?);
+        result.append(&mut self.for_votes.to_bytes()?);
+        result.append(&mut self.against_votes.to_bytes()?);
+        result.append(&mut self.canceled.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.proposer.serialized_length()
+            + self.targets.serialized_length()
+            + self.entry_points.serialized_length()
+            + self.args.serialized_length()
+            + self.eta.serialized_length()
+            + self.start_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.for_votes.serialized_length()
+            + self.against_votes.serialized_length()
+            + self.canceled.serialized_length()
+            + self.executed.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (proposer, bytes) = Key::from_bytes(bytes)?;
+        let (targets, bytes) = Vec::<Key>::from_bytes(bytes)?;
+        let (entry_points, bytes) = Vec::<String>::from_bytes(bytes)?;
+        let (args, bytes) = Vec::<Bytes>::from_bytes(bytes)?;
+        let (eta, bytes) = u64::from_bytes(bytes)?;
+        let (start_time, bytes) = u64::from_bytes(bytes)?;
+        let (end_time, bytes) = u64::from_bytes(bytes)?;
+        let (for_votes, bytes) = U256::from_bytes(bytes)?;
+        let (against_votes, bytes) = U256::from_bytes(bytes)?;
+        let (canceled, bytes) = bool::from_bytes(bytes)?;
+        let (executed, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            Proposal {
+                proposer,
+                targets,
+                entry_points,
+                args,
+                eta,
+                start_time,
+                end_time,
+                for_votes,
+                against_votes,
+                canceled,
+                executed,
+            },
+            bytes,
+        ))
+    }
+}
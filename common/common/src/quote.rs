@@ -0,0 +1,72 @@
+use crate::{
+    errors::Errors,
+    math::{
+        get_amount_in, get_amount_out, get_stable_amount_out, get_weighted_amount_out, WEIGHT_ONE,
+    },
+    pair_info::{PAIR_TYPE_CONSTANT_PRODUCT, PAIR_TYPE_STABLE, PAIR_TYPE_WEIGHTED},
+};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+/// Returns the `pair_type` of `pair`
+pub fn get_pair_type(pair: Key) -> u8 {
+    runtime::call_versioned_contract(
+        pair.into_hash().unwrap_or_revert().into(),
+        None,
+        "pair_type",
+        runtime_args! {},
+    )
+}
+
+/// Prices `amount_in` on the amount math of the type of `pair`, `zero_for_one` tells whether the
+/// input is token0
+pub fn get_pair_amount_out(
+    pair: Key,
+    zero_for_one: bool,
+    amount_in: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+) -> U256 {
+    let pair_package_hash: ContractPackageHash = pair.into_hash().unwrap_or_revert().into();
+    let pair_type: u8 = get_pair_type(pair);
+    if pair_type == PAIR_TYPE_STABLE {
+        let amplification: U256 = runtime::call_versioned_contract(
+            pair_package_hash,
+            None,
+            "amplification",
+            runtime_args! {},
+        );
+        get_stable_amount_out(amount_in, reserve_in, reserve_out, amplification)
+    } else if pair_type == PAIR_TYPE_WEIGHTED {
+        let weight0: U256 =
+            runtime::call_versioned_contract(pair_package_hash, None, "weight0", runtime_args! {});
+        let weight1: U256 = U256::from(WEIGHT_ONE) - weight0;
+        let (weight_in, weight_out) = if zero_for_one {
+            (weight0, weight1)
+        } else {
+            (weight1, weight0)
+        };
+        get_weighted_amount_out(amount_in, reserve_in, reserve_out, weight_in, weight_out)
+    } else {
+        get_amount_out(amount_in, reserve_in, reserve_out)
+    }
+}
+
+/// Returns the input `pair` needs for `amount_out`. Only constant product pairs can be priced
+/// backwards, any other pair type reverts
+pub fn get_pair_amount_in(
+    pair: Key,
+    amount_out: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+) -> U256 {
+    require_constant_product(pair);
+    get_amount_in(amount_out, reserve_in, reserve_out)
+}
+
+/// Reverts unless `pair` is a constant product pair, for callers whose math only holds on `x * y = k`
+pub fn require_constant_product(pair: Key) {
+    if get_pair_type(pair) != PAIR_TYPE_CONSTANT_PRODUCT {
+        runtime::revert(Errors::UniswapV2LibraryUnsupportedPairType);
+    }
+}
@@ -0,0 +1,31 @@
+use casper_types::{account::AccountHash, PublicKey};
+use cryptoxide::ed25519;
+
+/// Parses `count` bytes written as comma separated decimals, the way clients pass public keys
+/// and signatures of meta transactions
+fn parse_bytes(bytes: &str, count: usize) -> Option<Vec<u8>> {
+    let bytes_without_spaces: String = bytes.split_whitespace().collect();
+    let bytes: Vec<u8> = bytes_without_spaces
+        .split(',')
+        .map(|byte| byte.parse::<u8>().ok())
+        .collect::<Option<Vec<u8>>>()?;
+    if bytes.len() != count {
+        return None;
+    }
+    Some(bytes)
+}
+
+/// Verifies the ed25519 `signature` of `digest` against `public_key`, both comma separated
+/// decimal bytes. Malformed keys and signatures do not verify
+pub fn verify_signature(public_key: &str, signature: &str, digest: &[u8; 32]) -> bool {
+    match (parse_bytes(public_key, 32), parse_bytes(signature, 64)) {
+        (Some(public_key), Some(signature)) => ed25519::verify(digest, &public_key, &signature),
+        _ => false,
+    }
+}
+
+/// Account of the ed25519 `public_key`, comma separated decimal bytes
+pub fn signer_account_hash(public_key: &str) -> Option<AccountHash> {
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes(parse_bytes(public_key, 32)?).ok()?;
+    Some(AccountHash::from(&public_key))
+}
@@ -0,0 +1,65 @@
+use casper_types::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+/// Pool of the staking-rewards contract, returned by its `get_pool`
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakingPool {
+    /// Token staked in the pool, the LP token of a pair
+    pub lp_token: Key,
+    /// Share of the reward rate the pool gets, out of the sum over all pools
+    pub alloc_point: U256,
+    /// Amount of `lp_token` staked
+    pub total_supply: U256,
+    /// Reward per staked token accrued up to `last_update_time`, scaled by 10^18
+    pub reward_per_token_stored: U256,
+    /// Block time in seconds `reward_per_token_stored` was last brought up to
+    pub last_update_time: U256,
+}
+
+impl CLTyped for StakingPool {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for StakingPool {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.lp_token.to_bytes()?);
+        result.append(&mut self.alloc_point.to_bytes()?);
+        result.append(&mut self.total_supply.to_bytes()?);
+        result.append(&mut self.reward_per_token_stored.to_bytes()?);
+        result.append(&mut self.last_update_time.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.lp_token.serialized_length()
+            + self.alloc_point.serialized_length()
+            + self.total_supply.serialized_length()
+            + self.reward_per_token_stored.serialized_length()
+            + self.last_update_time.serialized_length()
+    }
+}
+
+impl FromBytes for StakingPool {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (lp_token, bytes) = Key::from_bytes(bytes)?;
+        let (alloc_point, bytes) = U256::from_bytes(bytes)?;
+        let (total_supply, bytes) = U256::from_bytes(bytes)?;
+        let (reward_per_token_stored, bytes) = U256::from_bytes(bytes)?;
+        let (last_update_time, bytes) = U256::from_bytes(bytes)?;
+        Ok((
+            StakingPool {
+                lp_token,
+                alloc_point,
+                total_supply,
+                reward_per_token_stored,
+                last_update_time,
+            },
+            bytes,
+        ))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{errors::Errors, functions::account_zero_address, keys::*};
+use casper_contract::contract_api::runtime;
+use casper_types::Key;
+use casperlabs_contract_utils::{get_key, set_key};
+
+/// Records the version of the code the state is written by, and the account installing the
+/// contract, which holds the access token of its package and alone can migrate it
+pub fn init_contract_version(contract_version: u32, package_owner: Key) {
+    set_contract_version(contract_version);
+    set_package_owner(package_owner);
+}
+
+pub fn set_contract_version(contract_version: u32) {
+    set_key(CONTRACT_VERSION, contract_version);
+}
+
+/// Version of the code the state is written by, 0 for contracts installed before versioning
+pub fn get_contract_version() -> u32 {
+    get_key(CONTRACT_VERSION).unwrap_or_default()
+}
+
+pub fn set_package_owner(package_owner: Key) {
+    set_key(PACKAGE_OWNER, package_owner);
+}
+
+pub fn get_package_owner() -> Key {
+    get_key(PACKAGE_OWNER).unwrap_or_else(account_zero_address)
+}
+
+/// Brings the state written by `from_version` up to `to_version`, the version of the running
+/// code, calling `migration` with every version in between, oldest first. Only the package
+/// owner can migrate, and only from the version the state is at, so a migration runs once
+pub fn migrate<F: Fn(u32)>(caller: Key, from_version: u32, to_version: u32, migration: F) {
+    if caller != get_package_owner() {
+        runtime::revert(Errors::UpgradeNotPackageOwner);
+    }
+    if from_version != get_contract_version() || from_version >= to_version {
+        runtime::revert(Errors::UpgradeInvalidVersion);
+    }
+    for version in from_version..to_version {
+        migration(version);
+    }
+    set_contract_version(to_version);
+}
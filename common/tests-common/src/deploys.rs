@@ -1,5 +1,10 @@
-use casperlabs_test_env::{TestContract, TestEnv};
-use common::{account::AccountHash, *};
+use crate::helpers::{AMOUNT, WRAPPED_CSPR};
+use casperlabs_test_env::{now, TestContract, TestEnv};
+use common::{
+    account::AccountHash,
+    pair_info::{PAIR_TYPE_STABLE, PAIR_TYPE_WEIGHTED},
+    *,
+};
 
 #[allow(clippy::too_many_arguments)]
 pub fn deploy_erc20(
@@ -27,6 +32,32 @@ pub fn deploy_erc20(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn deploy_erc20_fee_on_transfer(
+    env: &TestEnv,
+    contract_name: &str,
+    sender: AccountHash,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    supply: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "erc20-fee-on-transfer-token.wasm",
+        contract_name,
+        sender,
+        runtime_args! {
+            "initial_supply" => supply,
+            "name" => name,
+            "symbol" => symbol,
+            "decimals" => decimals
+        },
+        time,
+    )
+}
+
 pub fn deploy_factory(
     env: &TestEnv,
     owner: AccountHash,
@@ -75,7 +106,7 @@ pub fn deploy_flashswapper(
     env: &TestEnv,
     owner: AccountHash,
     wcspr: Key,
-    dai: Key,
+    base_tokens: Vec<Key>,
     factory: Key,
     time: u64,
 ) -> TestContract {
@@ -86,22 +117,17 @@ pub fn deploy_flashswapper(
         owner,
         runtime_args! {
             "wcspr" => wcspr,
-            "dai" => dai,
+            "base_tokens" => base_tokens,
             "uniswap_v2_factory" => factory
         },
         time,
     )
 }
 
-#[allow(clippy::too_many_arguments)]
 pub fn deploy_pair(
     env: &TestEnv,
     contract_name: &str,
     owner: AccountHash,
-    name: &str,
-    symbol: &str,
-    decimals: u8,
-    supply: U256,
     callee_package_hash: Key,
     factory_hash: Key,
     time: u64,
@@ -112,16 +138,145 @@ pub fn deploy_pair(
         contract_name,
         owner,
         runtime_args! {
-            "name" => name,
-            "symbol" => symbol,
-            "decimals" => decimals,
-            "initial_supply" => supply,
             "callee_package_hash" => callee_package_hash,
             "factory_hash" => factory_hash
         },
         time,
     )
 }
+pub fn deploy_stable_pair(
+    env: &TestEnv,
+    contract_name: &str,
+    owner: AccountHash,
+    callee_package_hash: Key,
+    factory_hash: Key,
+    amplification: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "pair-token.wasm",
+        contract_name,
+        owner,
+        runtime_args! {
+            "callee_package_hash" => callee_package_hash,
+            "factory_hash" => factory_hash,
+            "pair_type" => PAIR_TYPE_STABLE,
+            "amplification" => amplification,
+            "weight0" => U256::zero()
+        },
+        time,
+    )
+}
+pub fn deploy_weighted_pair(
+    env: &TestEnv,
+    contract_name: &str,
+    owner: AccountHash,
+    callee_package_hash: Key,
+    factory_hash: Key,
+    weight0: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "pair-token.wasm",
+        contract_name,
+        owner,
+        runtime_args! {
+            "callee_package_hash" => callee_package_hash,
+            "factory_hash" => factory_hash,
+            "pair_type" => PAIR_TYPE_WEIGHTED,
+            "amplification" => U256::zero(),
+            "weight0" => weight0
+        },
+        time,
+    )
+}
+
+/// Creates the pair `deploy_pair` deploys through a new factory and adds AMOUNT of both tokens as
+/// liquidity minted to the owner. Returns the factory, the pair and its token0 and token1
+pub fn deploy_pair_with_liquidity(
+    deploy_pair: impl FnOnce(&TestEnv, AccountHash, Key, Key) -> TestContract,
+) -> (
+    TestEnv,
+    AccountHash,
+    TestContract,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let wcspr = deploy_wcspr(
+        &env,
+        "WCSPR",
+        owner,
+        WRAPPED_CSPR.into(),
+        "WCSPR".into(),
+        9,
+        0.into(),
+        now(),
+    );
+    let token0 = deploy_erc20(&env, "ERC20-1", owner, "Token0", "TK-0", 9, 0.into(), now());
+    let token1 = deploy_erc20(&env, "ERC20-2", owner, "Token1", "TK-1", 9, 0.into(), now());
+    let callee_contract = deploy_flashswapper(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    let pair = deploy_pair(
+        &env,
+        owner,
+        Key::Hash(callee_contract.package_hash()),
+        Key::Hash(factory.package_hash()),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(token0.package_hash()),
+            "token_b" => Key::Hash(token1.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    for token in [&token0, &token1] {
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => AMOUNT
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner)
+        },
+        now(),
+    );
+    if pair.query_named_key::<Key>("token0".into()) == Key::Hash(token0.package_hash()) {
+        (env, owner, factory, pair, token0, token1)
+    } else {
+        (env, owner, factory, pair, token1, token0)
+    }
+}
 #[allow(clippy::too_many_arguments)]
 pub fn deploy_erc20_secure(
     env: &TestEnv,
@@ -147,3 +302,147 @@ pub fn deploy_erc20_secure(
         time,
     )
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn deploy_governance_token(
+    env: &TestEnv,
+    contract_name: &str,
+    sender: AccountHash,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    supply: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "governance-token.wasm",
+        contract_name,
+        sender,
+        runtime_args! {
+            "initial_supply" => supply,
+            "name" => name,
+            "symbol" => symbol,
+            "decimals" => decimals
+        },
+        time,
+    )
+}
+
+pub fn deploy_fee_collector(
+    env: &TestEnv,
+    owner: AccountHash,
+    target_token: Key,
+    treasury: Key,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "fee-collector.wasm",
+        "fee_collector",
+        owner,
+        runtime_args! {
+            "target_token" => target_token,
+            "treasury" => treasury
+        },
+        time,
+    )
+}
+
+pub fn deploy_zap(env: &TestEnv, owner: AccountHash, time: u64) -> TestContract {
+    TestContract::new(env, "zap.wasm", "zap", owner, runtime_args! {}, time)
+}
+
+pub fn deploy_limit_order(env: &TestEnv, owner: AccountHash, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "limit-order.wasm",
+        "limit_order",
+        owner,
+        runtime_args! {},
+        time,
+    )
+}
+
+pub fn deploy_staking_rewards(
+    env: &TestEnv,
+    owner: AccountHash,
+    rewards_token: Key,
+    rewards_duration: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "staking-rewards.wasm",
+        "staking_rewards",
+        owner,
+        runtime_args! {
+            "rewards_token" => rewards_token,
+            "rewards_duration" => rewards_duration
+        },
+        time,
+    )
+}
+
+pub fn deploy_timelock(
+    env: &TestEnv,
+    owner: AccountHash,
+    admin: Key,
+    delay: u64,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "timelock.wasm",
+        "timelock",
+        owner,
+        runtime_args! {
+            "admin" => admin,
+            "delay" => delay
+        },
+        time,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deploy_governor(
+    env: &TestEnv,
+    owner: AccountHash,
+    timelock: Key,
+    governance_token: Key,
+    voting_delay: u64,
+    voting_period: u64,
+    proposal_threshold: U256,
+    quorum_votes: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "governor.wasm",
+        "governor",
+        owner,
+        runtime_args! {
+            "timelock" => timelock,
+            "governance_token" => governance_token,
+            "guardian" => Key::from(owner),
+            "voting_delay" => voting_delay,
+            "voting_period" => voting_period,
+            "proposal_threshold" => proposal_threshold,
+            "quorum_votes" => quorum_votes
+        },
+        time,
+    )
+}
+
+pub fn deploy_router(env: &TestEnv, owner: AccountHash, factory: Key, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "router.wasm",
+        "router",
+        owner,
+        runtime_args! {
+            "factory_hash" => factory
+        },
+        time,
+    )
+}
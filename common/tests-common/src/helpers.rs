@@ -7,7 +7,6 @@ use hex::encode;
 
 pub const BALANCES: &str = "balances";
 pub const ALLOWANCES: &str = "allowances";
-pub const TREASURY_FEE: &str = "treasury_fee";
 
 pub const NAME: &str = "ERC20";
 pub const SYMBOL: &str = "ERC";
@@ -51,3 +50,34 @@ pub fn keys_to_str(key_a: &Key, key_b: &Key) -> String {
 
     encode(ret)
 }
+
+/// Key of an entry of a dictionary keyed by an account and an index, like the pair's
+/// `balance_checkpoints`
+pub fn key_and_index_to_str(key: &Key, index: u32) -> String {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(key.to_bytes().unwrap());
+    hasher.update(index.to_bytes().unwrap());
+
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+
+    encode(ret)
+}
+
+/// Key of a pair in the factory's `pair_ids` dictionary, computed the same way as the factory does
+pub fn pair_id(token_a: &Key, token_b: &Key, factory: ContractPackageHash) -> String {
+    let (token0, token1) = if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    };
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(token0.to_bytes().unwrap());
+    hasher.update(token1.to_bytes().unwrap());
+    hasher.update(factory.to_bytes().unwrap());
+
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+
+    encode(ret)
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+use tests_common::{account::AccountHash, *};
+
+/// Chain a manifest is applied to. Contracts are addressed by the name they were installed
+/// under, so that a backend for a node only needs to keep the hashes it deployed
+pub trait Backend {
+    /// Account making the deploys
+    fn deployer(&self) -> AccountHash;
+
+    /// Account the manifest calls `name`, created on first use where the backend can
+    fn account(&mut self, name: &str) -> AccountHash;
+
+    /// Installs `wasm` as contract `name` from the deployer, returning its package and contract
+    /// hashes
+    fn install(
+        &mut self,
+        name: &str,
+        wasm: &str,
+        args: RuntimeArgs,
+    ) -> (ContractPackageHash, ContractHash);
+
+    /// Calls `entry_point` of the contract installed as `name`, from the deployer
+    fn call(&mut self, name: &str, entry_point: &str, args: RuntimeArgs);
+}
+
+/// Backend deploying into an in-memory `TestEnv`, for local stacks and tests
+pub struct TestEnvBackend {
+    env: TestEnv,
+    deployer: AccountHash,
+    accounts: BTreeMap<String, AccountHash>,
+    contracts: BTreeMap<String, TestContract>,
+    time: u64,
+}
+
+impl TestEnvBackend {
+    /// Deploys from a new account of `env`, with every deploy at block time `time`
+    pub fn new(env: TestEnv, time: u64) -> TestEnvBackend {
+        let deployer = env.next_user();
+        TestEnvBackend {
+            env,
+            deployer,
+            accounts: BTreeMap::new(),
+            contracts: BTreeMap::new(),
+            time,
+        }
+    }
+
+    pub fn env(&self) -> &TestEnv {
+        &self.env
+    }
+
+    /// Contract installed as `name`, to query or call it after the deployment
+    pub fn contract(&self, name: &str) -> Option<&TestContract> {
+        self.contracts.get(name)
+    }
+}
+
+impl Backend for TestEnvBackend {
+    fn deployer(&self) -> AccountHash {
+        self.deployer
+    }
+
+    fn account(&mut self, name: &str) -> AccountHash {
+        let env = &self.env;
+        *self
+            .accounts
+            .entry(name.to_string())
+            .or_insert_with(|| env.next_user())
+    }
+
+    fn install(
+        &mut self,
+        name: &str,
+        wasm: &str,
+        args: RuntimeArgs,
+    ) -> (ContractPackageHash, ContractHash) {
+        let contract = TestContract::new(&self.env, wasm, name, self.deployer, args, self.time);
+        let hashes = (
+            ContractPackageHash::new(contract.package_hash()),
+            ContractHash::new(contract.contract_hash()),
+        );
+        self.contracts.insert(name.to_string(), contract);
+        hashes
+    }
+
+    fn call(&mut self, name: &str, entry_point: &str, args: RuntimeArgs) {
+        self.contracts
+            .get(name)
+            .unwrap_or_else(|| panic!("contract {} is not installed", name))
+            .call_contract(self.deployer, entry_point, args, self.time);
+    }
+}
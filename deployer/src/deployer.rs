@@ -0,0 +1,226 @@
+use crate::{
+    backend::Backend,
+    error::Error,
+    manifest::{parse_amount, DeployedContract, DeployedManifest, Manifest, PairConfig},
+};
+use std::collections::BTreeMap;
+use tests_common::*;
+
+const DEPLOYER: &str = "deployer";
+const WCSPR: &str = "wcspr";
+const FACTORY: &str = "factory";
+const FLASH_SWAPPER: &str = "flash_swapper";
+
+/// Applies `manifest` to `backend` in order: accounts, WCSPR and tokens, factory, flash swapper,
+/// white list, pairs and fee settings. Returns the hashes of everything deployed
+pub fn deploy<B: Backend>(backend: &mut B, manifest: &Manifest) -> Result<DeployedManifest, Error> {
+    let mut deployment = Deployment {
+        backend,
+        keys: BTreeMap::new(),
+        output: DeployedManifest::default(),
+    };
+    deployment.deploy(manifest)?;
+    Ok(deployment.output)
+}
+
+struct Deployment<'a, B: Backend> {
+    backend: &'a mut B,
+    /// Keys of the accounts and contract packages deployed so far, by name
+    keys: BTreeMap<String, Key>,
+    output: DeployedManifest,
+}
+
+impl<'a, B: Backend> Deployment<'a, B> {
+    fn deploy(&mut self, manifest: &Manifest) -> Result<(), Error> {
+        let deployer = Key::Account(self.backend.deployer());
+        self.output.deployer = deployer.to_formatted_string();
+        self.add_key(DEPLOYER, deployer)?;
+        for name in &manifest.accounts {
+            let account = Key::Account(self.backend.account(name));
+            self.add_key(name, account)?;
+            self.output
+                .accounts
+                .insert(name.clone(), account.to_formatted_string());
+        }
+
+        self.install(
+            WCSPR,
+            "wcspr-token.wasm",
+            runtime_args! {
+                "name" => manifest.wcspr.name.clone(),
+                "symbol" => manifest.wcspr.symbol.clone(),
+                "decimals" => manifest.wcspr.decimals
+            },
+        )?;
+        for token in &manifest.tokens {
+            let initial_supply: U256 = match &token.initial_supply {
+                Some(amount) => parse_amount(amount)?,
+                None => U256::zero(),
+            };
+            self.install(
+                &token.name,
+                "erc20-token.wasm",
+                runtime_args! {
+                    "initial_supply" => initial_supply,
+                    "name" => token.token_name.clone(),
+                    "symbol" => token.symbol.clone(),
+                    "decimals" => token.decimals
+                },
+            )?;
+        }
+
+        // the deployer sets the fees until the manifest hands them over
+        self.install(
+            FACTORY,
+            "factory.wasm",
+            runtime_args! {
+                "fee_to_setter" => deployer
+            },
+        )?;
+        let base_tokens: Vec<Key> = manifest
+            .base_tokens
+            .iter()
+            .map(|name| self.resolve(name))
+            .collect::<Result<_, _>>()?;
+        self.install(
+            FLASH_SWAPPER,
+            "flashswapper-token.wasm",
+            runtime_args! {
+                "wcspr" => self.resolve(WCSPR)?,
+                "base_tokens" => base_tokens,
+                "uniswap_v2_factory" => self.resolve(FACTORY)?
+            },
+        )?;
+
+        // the deployer creates the pairs
+        let mut white_list: Vec<Key> = vec![deployer];
+        for name in &manifest.white_list {
+            white_list.push(self.resolve(name)?);
+        }
+        for entry in white_list {
+            self.backend.call(
+                FACTORY,
+                "set_white_list",
+                runtime_args! {
+                    "white_list" => entry
+                },
+            );
+        }
+
+        for pair in &manifest.pairs {
+            self.deploy_pair(pair)?;
+        }
+
+        let fees = &manifest.fees;
+        if let Some(fee_to) = &fees.fee_to {
+            let fee_to: Key = self.resolve(fee_to)?;
+            self.backend.call(
+                FACTORY,
+                "set_fee_to",
+                runtime_args! {
+                    "fee_to" => fee_to
+                },
+            );
+        }
+        if let Some(protocol_fee) = fees.protocol_fee {
+            self.backend.call(
+                FACTORY,
+                "set_protocol_fee",
+                runtime_args! {
+                    "protocol_fee" => U256::from(protocol_fee)
+                },
+            );
+        }
+        for (pair, protocol_fee) in &fees.pair_protocol_fees {
+            let pair: Key = self.resolve(pair)?;
+            self.backend.call(
+                FACTORY,
+                "set_pair_protocol_fee",
+                runtime_args! {
+                    "pair" => pair,
+                    "protocol_fee" => Some(U256::from(*protocol_fee))
+                },
+            );
+        }
+        if let Some(fee_to_setter) = &fees.fee_to_setter {
+            let fee_to_setter: Key = self.resolve(fee_to_setter)?;
+            self.backend.call(
+                FACTORY,
+                "set_fee_to_setter",
+                runtime_args! {
+                    "fee_to_setter" => fee_to_setter
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Installs a pair with the settings the manifest gives and registers it with the factory
+    fn deploy_pair(&mut self, pair: &PairConfig) -> Result<(), Error> {
+        let token_a: Key = self.resolve(&pair.token_a)?;
+        let token_b: Key = self.resolve(&pair.token_b)?;
+        // the pair takes its LP name and symbol from its tokens once the factory creates it
+        let mut args = runtime_args! {
+            "callee_package_hash" => self.resolve(FLASH_SWAPPER)?,
+            "factory_hash" => self.resolve(FACTORY)?
+        };
+        if let Some(pair_type) = pair.pair_type {
+            args.insert("pair_type", pair_type).unwrap();
+        }
+        for (arg, amount) in [
+            ("amplification", &pair.amplification),
+            ("weight0", &pair.weight0),
+            ("minimum_liquidity", &pair.minimum_liquidity),
+        ] {
+            if let Some(amount) = amount {
+                args.insert(arg, parse_amount(amount)?).unwrap();
+            }
+        }
+        let pair_hash: Key = self.install(&pair.name, "pair-token.wasm", args)?;
+        self.backend.call(
+            FACTORY,
+            "create_pair",
+            runtime_args! {
+                "token_a" => token_a,
+                "token_b" => token_b,
+                "pair_hash" => pair_hash
+            },
+        );
+        Ok(())
+    }
+
+    /// Installs contract `name` and records its hashes, returning its package hash as a key
+    fn install(&mut self, name: &str, wasm: &str, args: RuntimeArgs) -> Result<Key, Error> {
+        if self.keys.contains_key(name) {
+            return Err(Error::DuplicateName(name.to_string()));
+        }
+        let (package_hash, contract_hash) = self.backend.install(name, wasm, args);
+        let package: Key = Key::from(package_hash);
+        self.add_key(name, package)?;
+        self.output.contracts.insert(
+            name.to_string(),
+            DeployedContract {
+                package_hash: package.to_formatted_string(),
+                contract_hash: Key::from(contract_hash).to_formatted_string(),
+            },
+        );
+        Ok(package)
+    }
+
+    fn add_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
+        if self.keys.insert(name.to_string(), key).is_some() {
+            return Err(Error::DuplicateName(name.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Key of an account or contract of the manifest, or of a formatted key
+    fn resolve(&self, name: &str) -> Result<Key, Error> {
+        match self.keys.get(name) {
+            Some(key) => Ok(*key),
+            None => {
+                Key::from_formatted_str(name).map_err(|_| Error::UnknownReference(name.to_string()))
+            }
+        }
+    }
+}
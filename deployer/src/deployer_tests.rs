@@ -0,0 +1,122 @@
+use crate::{
+    backend::{Backend, TestEnvBackend},
+    deployer::deploy,
+    error::Error,
+    manifest::{DeployedManifest, Manifest},
+};
+use tests_common::{helpers::*, *};
+
+const MANIFEST: &str = r#"
+accounts = ["treasury"]
+base_tokens = ["usdc"]
+
+[[tokens]]
+name = "usdc"
+token_name = "USD Coin"
+symbol = "USDC"
+decimals = 9
+initial_supply = "1000000000000000000"
+
+[[tokens]]
+name = "usdt"
+token_name = "Tether USD"
+symbol = "USDT"
+decimals = 9
+
+[[pairs]]
+name = "usdc_usdt"
+token_a = "usdc"
+token_b = "usdt"
+pair_type = 1
+amplification = "100"
+
+[fees]
+fee_to = "treasury"
+protocol_fee = 1666
+fee_to_setter = "treasury"
+
+[fees.pair_protocol_fees]
+usdc_usdt = 2500
+"#;
+
+fn package_key(deployed: &DeployedManifest, name: &str) -> Key {
+    Key::from_formatted_str(&deployed.contracts[name].package_hash).unwrap()
+}
+
+#[test]
+fn test_deploy_manifest() {
+    let manifest: Manifest = toml::from_str(MANIFEST).unwrap();
+    let mut backend = TestEnvBackend::new(TestEnv::new(), now());
+    let deployed: DeployedManifest = deploy(&mut backend, &manifest).unwrap();
+
+    let treasury: Key = Key::Account(backend.account("treasury"));
+    assert_eq!(
+        deployed.deployer,
+        Key::Account(backend.deployer()).to_formatted_string()
+    );
+    assert_eq!(
+        deployed.accounts["treasury"],
+        treasury.to_formatted_string()
+    );
+    for name in [
+        "wcspr",
+        "usdc",
+        "usdt",
+        "factory",
+        "flash_swapper",
+        "usdc_usdt",
+    ] {
+        let contract: &TestContract = backend.contract(name).unwrap();
+        assert_eq!(
+            package_key(&deployed, name),
+            Key::Hash(contract.package_hash())
+        );
+        assert_eq!(
+            deployed.contracts[name].contract_hash,
+            Key::Hash(contract.contract_hash()).to_formatted_string()
+        );
+    }
+
+    let pair: Key = package_key(&deployed, "usdc_usdt");
+    let factory: &TestContract = backend.contract("factory").unwrap();
+    let all_pairs: Vec<Key> = factory.query_named_key(String::from("all_pairs"));
+    assert_eq!(all_pairs, vec![pair]);
+    let fee_to: Key = factory.query_named_key(String::from("fee_to"));
+    assert_eq!(fee_to, treasury);
+    let fee_to_setter: Key = factory.query_named_key(String::from("fee_to_setter"));
+    assert_eq!(fee_to_setter, treasury);
+    let protocol_fee: U256 = factory.query_named_key(String::from("protocol_fee"));
+    assert_eq!(protocol_fee, 1666.into());
+    let pair_protocol_fee: Option<U256> = factory
+        .query_dictionary("protocol_fee_overrides", key_to_str(&pair))
+        .unwrap();
+    assert_eq!(pair_protocol_fee, Some(2500.into()));
+
+    let pair: &TestContract = backend.contract("usdc_usdt").unwrap();
+    let pair_type: u8 = pair.query_named_key(String::from("pair_type"));
+    assert_eq!(pair_type, 1);
+    let amplification: U256 = pair.query_named_key(String::from("amplification"));
+    assert_eq!(amplification, 100.into());
+}
+
+#[test]
+fn test_deploy_manifest_unknown_reference() {
+    let mut manifest: Manifest = toml::from_str(MANIFEST).unwrap();
+    manifest.pairs[0].token_b = "dai".to_string();
+    let mut backend = TestEnvBackend::new(TestEnv::new(), now());
+    match deploy(&mut backend, &manifest) {
+        Err(Error::UnknownReference(name)) => assert_eq!(name, "dai"),
+        result => panic!("expected an unknown reference, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_deploy_manifest_duplicate_name() {
+    let mut manifest: Manifest = toml::from_str(MANIFEST).unwrap();
+    manifest.accounts.push("usdc".to_string());
+    let mut backend = TestEnvBackend::new(TestEnv::new(), now());
+    match deploy(&mut backend, &manifest) {
+        Err(Error::DuplicateName(name)) => assert_eq!(name, "usdc"),
+        result => panic!("expected a duplicate name, got {:?}", result),
+    }
+}
@@ -0,0 +1,34 @@
+use std::{fmt, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// A manifest that is not valid TOML or JSON, or does not match the expected fields
+    Parse(String),
+    /// A name that is neither an account or contract of the manifest nor a formatted key
+    UnknownReference(String),
+    /// An amount that is not a decimal U256
+    InvalidAmount(String),
+    /// A name used twice among the accounts and contracts of the manifest
+    DuplicateName(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "io error: {}", error),
+            Error::Parse(error) => write!(f, "invalid manifest: {}", error),
+            Error::UnknownReference(name) => write!(f, "unknown account or contract: {}", name),
+            Error::InvalidAmount(amount) => write!(f, "invalid amount: {}", amount),
+            Error::DuplicateName(name) => write!(f, "name used twice: {}", name),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod backend;
+pub mod deployer;
+pub mod error;
+pub mod manifest;
+
+#[cfg(test)]
+pub mod deployer_tests;
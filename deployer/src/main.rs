@@ -0,0 +1,19 @@
+use deployer::{backend::TestEnvBackend, deployer::deploy, manifest::Manifest};
+use std::{env, path::Path, process};
+use tests_common::{now, TestEnv};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <manifest> <output>", args[0]);
+        process::exit(2);
+    }
+    let result = Manifest::read(Path::new(&args[1])).and_then(|manifest| {
+        let mut backend = TestEnvBackend::new(TestEnv::new(), now());
+        deploy(&mut backend, &manifest)?.write(Path::new(&args[2]))
+    });
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+use tests_common::U256;
+
+/// Protocol stack to deploy, read from a TOML or JSON file. Contracts and accounts are referred
+/// to by their name in the manifest, `deployer` is the account making the deploys, and anything
+/// else must be a formatted key such as `account-hash-...` or `hash-...`
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Accounts created for the stack, to receive fees or be white listed
+    #[serde(default)]
+    pub accounts: Vec<String>,
+    #[serde(default)]
+    pub wcspr: WcsprConfig,
+    #[serde(default)]
+    pub tokens: Vec<TokenConfig>,
+    /// Tokens the flash swapper borrows through, by name
+    #[serde(default)]
+    pub base_tokens: Vec<String>,
+    /// Accounts and contracts allowed to create pairs in the factory, the deployer always is
+    #[serde(default)]
+    pub white_list: Vec<String>,
+    #[serde(default)]
+    pub pairs: Vec<PairConfig>,
+    #[serde(default)]
+    pub fees: FeeConfig,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WcsprConfig {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl Default for WcsprConfig {
+    fn default() -> Self {
+        WcsprConfig {
+            name: "Wrapped CSPR".to_string(),
+            symbol: "WCSPR".to_string(),
+            decimals: 9,
+        }
+    }
+}
+
+/// Erc20 token, with its whole supply minted to the deployer
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenConfig {
+    /// Name the manifest refers to the token by, and its contract name
+    pub name: String,
+    pub token_name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Decimal string, as the supply may not fit in a TOML integer
+    #[serde(default)]
+    pub initial_supply: Option<String>,
+}
+
+/// Pair created through the factory. Settings left out take the pair installer's defaults
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PairConfig {
+    pub name: String,
+    pub token_a: String,
+    pub token_b: String,
+    #[serde(default)]
+    pub pair_type: Option<u8>,
+    #[serde(default)]
+    pub amplification: Option<String>,
+    #[serde(default)]
+    pub weight0: Option<String>,
+    #[serde(default)]
+    pub minimum_liquidity: Option<String>,
+}
+
+/// Factory fee settings, applied last so they cover every pair
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeeConfig {
+    #[serde(default)]
+    pub fee_to: Option<String>,
+    /// Protocol share of the LP fees of every pair, in basis points
+    #[serde(default)]
+    pub protocol_fee: Option<u64>,
+    /// Protocol share overriding `protocol_fee` for single pairs, by pair name
+    #[serde(default)]
+    pub pair_protocol_fees: BTreeMap<String, u64>,
+    /// Account or contract the fee settings are handed to once applied, such as a timelock
+    #[serde(default)]
+    pub fee_to_setter: Option<String>,
+}
+
+/// Hashes of a deployed contract, formatted as `hash-...`
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeployedContract {
+    pub package_hash: String,
+    pub contract_hash: String,
+}
+
+/// What a manifest deployed, written out for the clients of the stack
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DeployedManifest {
+    pub deployer: String,
+    /// Account hashes of the accounts of the manifest, by name
+    pub accounts: BTreeMap<String, String>,
+    /// Contracts by name, `factory`, `wcspr` and `flash_swapper` next to the tokens and pairs
+    pub contracts: BTreeMap<String, DeployedContract>,
+}
+
+impl Manifest {
+    /// Reads a manifest, as JSON if the file name ends in `.json` and as TOML otherwise
+    pub fn read(path: &Path) -> Result<Manifest, Error> {
+        let text: String = fs::read_to_string(path)?;
+        if is_json(path) {
+            serde_json::from_str(&text).map_err(|error| Error::Parse(error.to_string()))
+        } else {
+            toml::from_str(&text).map_err(|error| Error::Parse(error.to_string()))
+        }
+    }
+}
+
+impl DeployedManifest {
+    /// Writes the manifest, as JSON if the file name ends in `.json` and as TOML otherwise
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let text: String = if is_json(path) {
+            serde_json::to_string_pretty(self).map_err(|error| Error::Parse(error.to_string()))?
+        } else {
+            toml::to_string_pretty(self).map_err(|error| Error::Parse(error.to_string()))?
+        };
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |extension| extension == "json")
+}
+
+/// Parses a decimal amount of the manifest
+pub fn parse_amount(amount: &str) -> Result<U256, Error> {
+    U256::from_dec_str(amount).map_err(|_| Error::InvalidAmount(amount.to_string()))
+}
@@ -8,12 +8,24 @@ use common::{
 use std::collections::BTreeSet;
 use uniswap_erc20::{Address, ERC20};
 
+/// Share of every transfer that is burned, in basis points
+#[cfg(feature = "fee-on-transfer")]
+const TRANSFER_FEE: u64 = 100;
+
 #[derive(Default)]
 struct Token(OnChainContractStorage);
 impl Token {
     fn constructor(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
         ERC20::init(self, contract_hash, package_hash);
     }
+
+    #[cfg(feature = "fee-on-transfer")]
+    fn take_transfer_fee(&self, recipient: Address, amount: U256) {
+        let fee: U256 = amount.checked_mul(TRANSFER_FEE.into()).unwrap_or_revert() / 10_000;
+        if !fee.is_zero() {
+            self.burn(recipient, fee).unwrap_or_revert();
+        }
+    }
 }
 
 impl ERC20<OnChainContractStorage> for Token {}
@@ -133,6 +145,7 @@ fn approve() {
 /// # Parameters
 /// * `recipient` - Address that holds the account address of the user
 /// * `amount` - A U256 that holds the amount for transfer
+/// With the `fee-on-transfer` feature the recipient receives `amount` less the transfer fee, which is burned
 #[inline(always)]
 #[no_mangle]
 fn transfer() {
@@ -141,6 +154,8 @@ fn transfer() {
     Token::default()
         .transfer(recipient, amount)
         .unwrap_or_revert();
+    #[cfg(feature = "fee-on-transfer")]
+    Token::default().take_transfer_fee(recipient, amount);
 }
 
 /// This function is to transfer tokens against the address that has been approved before by owner
@@ -154,6 +169,8 @@ fn transfer() {
 /// should keep in mind that they have to set allowance to 0 first and verify if it was used before setting the new value.
 /// **Note:**  Teams who decide to wait for such a standard should make these
 /// recommendations to app developers who work with their token contract.
+/// With the `fee-on-transfer` feature the recipient receives `amount` less the transfer fee, which is
+/// burned, the whole `amount` is taken from the allowance
 #[inline(always)]
 #[no_mangle]
 fn transfer_from() {
@@ -163,6 +180,8 @@ fn transfer_from() {
     Token::default()
         .transfer_from(owner, recipient, amount)
         .unwrap_or_revert();
+    #[cfg(feature = "fee-on-transfer")]
+    Token::default().take_transfer_fee(recipient, amount);
 }
 
 /// This function is to mint token against the address that user provided
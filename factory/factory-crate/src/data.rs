@@ -1,4 +1,6 @@
 use common::{
+    bytesrepr::ToBytes,
+    contract_api::runtime,
     functions::{account_zero_address, zero_address},
     keys::*,
     unwrap_or_revert::UnwrapOrRevert,
@@ -90,6 +92,73 @@ impl Pairs {
     }
 }
 
+/// Pairs by their deterministic id, see `pair_id`
+pub struct PairIds {
+    dict: Dict,
+}
+
+impl PairIds {
+    pub fn instance() -> PairIds {
+        PairIds {
+            dict: Dict::instance(PAIR_IDS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(PAIR_IDS_DICT)
+    }
+
+    pub fn get(&self, pair_id: &str) -> Key {
+        self.dict.get(pair_id).unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, pair_id: &str, pair: Key) {
+        self.dict.set(pair_id, pair);
+    }
+}
+
+/// Hex encoded blake2b hash of the sorted tokens followed by the factory package hash, so the
+/// `pair_ids` dictionary key of a pair can be computed off-chain
+pub fn pair_id(token_a: Key, token_b: Key) -> String {
+    let (token0, token1) = if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    };
+    let mut preimage: Vec<u8> = Vec::new();
+    preimage.append(&mut token0.to_bytes().unwrap_or_revert());
+    preimage.append(&mut token1.to_bytes().unwrap_or_revert());
+    preimage.append(&mut get_package_hash().to_bytes().unwrap_or_revert());
+    runtime::blake2b(preimage)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+pub struct ProtocolFeeOverrides {
+    dict: Dict,
+}
+
+impl ProtocolFeeOverrides {
+    pub fn instance() -> ProtocolFeeOverrides {
+        ProtocolFeeOverrides {
+            dict: Dict::instance(PROTOCOL_FEE_OVERRIDES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(PROTOCOL_FEE_OVERRIDES_DICT)
+    }
+
+    pub fn get(&self, pair: &Key) -> Option<U256> {
+        self.dict.get_by_key(pair).unwrap_or_default()
+    }
+
+    pub fn set(&self, pair: &Key, protocol_fee: Option<U256>) {
+        self.dict.set_by_key(pair, protocol_fee);
+    }
+}
+
 pub fn set_fee_to(fee_to: Key) {
     set_key(FEE_TO, fee_to);
 }
@@ -121,3 +190,11 @@ pub fn set_owner(owner: Key) {
 pub fn get_owner() -> Key {
     get_key(OWNER).unwrap_or_else(account_zero_address)
 }
+
+pub fn set_protocol_fee(protocol_fee: U256) {
+    set_key(PROTOCOL_FEE, protocol_fee);
+}
+
+pub fn get_protocol_fee() -> U256 {
+    get_key(PROTOCOL_FEE).unwrap_or_revert()
+}
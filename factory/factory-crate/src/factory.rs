@@ -8,6 +8,11 @@ use common::{
 };
 use std::collections::BTreeMap;
 
+/// Protocol share of the LP fee growth in basis points, 1/6 as in uniswap v2
+const DEFAULT_PROTOCOL_FEE: u64 = 1_667;
+/// The protocol can never take more than half of the LP fee growth
+const MAX_PROTOCOL_FEE: u64 = 5_000;
+
 pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(
         &self,
@@ -23,6 +28,9 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         set_package_hash(package_hash);
         Pairs::init();
         Whitelists::init();
+        PairIds::init();
+        ProtocolFeeOverrides::init();
+        set_protocol_fee(DEFAULT_PROTOCOL_FEE.into());
     }
 
     fn create_pair(&self, token_a: Key, token_b: Key, pair_hash: Key) {
@@ -67,6 +75,7 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         // handling the pair creation by updating the storage
         self.set_pair(token0, token1, pair_hash);
         self.set_pair(token1, token0, pair_hash);
+        PairIds::instance().set(&pair_id(token0, token1), pair_hash);
         let mut pairs: Vec<Key> = get_all_pairs();
         pairs.push(pair_hash);
         set_all_pairs(pairs);
@@ -133,6 +142,7 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         // handling the pair creation by updating the storage
         self.set_pair(token0, token1, zero_address());
         self.set_pair(token1, token0, zero_address());
+        PairIds::instance().set(&pair_id(token0, token1), zero_address());
         let mut pairs: Vec<Key> = get_all_pairs();
         let index = pairs
             .iter()
@@ -153,6 +163,10 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         Pairs::instance().get(&token0, &token1)
     }
 
+    fn get_pair_by_id(&self, pair_id: String) -> Key {
+        PairIds::instance().get(&pair_id)
+    }
+
     fn set_pair(&self, token0: Key, token1: Key, value: Key) {
         Pairs::instance().set(&token0, &token1, value);
     }
@@ -171,6 +185,34 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         set_fee_to_setter(fee_to_setter);
     }
 
+    fn set_protocol_fee(&self, protocol_fee: U256) {
+        if self.get_caller() != get_fee_to_setter() {
+            runtime::revert(Errors::UniswapV2FactoryForbidden3);
+        }
+        if protocol_fee > MAX_PROTOCOL_FEE.into() {
+            runtime::revert(Errors::UniswapV2FactoryProtocolFeeTooHigh);
+        }
+        set_protocol_fee(protocol_fee);
+    }
+
+    /// Overrides the protocol fee of a single pair, `None` makes the pair follow the factory fee again
+    fn set_pair_protocol_fee(&self, pair: Key, protocol_fee: Option<U256>) {
+        if self.get_caller() != get_fee_to_setter() {
+            runtime::revert(Errors::UniswapV2FactoryForbidden4);
+        }
+        if protocol_fee.unwrap_or_default() > MAX_PROTOCOL_FEE.into() {
+            runtime::revert(Errors::UniswapV2FactoryProtocolFeeTooHigh);
+        }
+        ProtocolFeeOverrides::instance().set(&pair, protocol_fee);
+    }
+
+    /// Protocol fee in basis points that applies to `pair`
+    fn protocol_fee_of(&self, pair: Key) -> U256 {
+        ProtocolFeeOverrides::instance()
+            .get(&pair)
+            .unwrap_or_else(get_protocol_fee)
+    }
+
     fn set_white_list(&self, white_list: Key, value: Key) {
         if self.get_caller() != get_owner() {
             runtime::revert(Errors::UniswapV2FactoryNotOwner);
@@ -178,6 +220,14 @@ pub trait FACTORY<Storage: ContractStorage>: ContractContext<Storage> {
         Whitelists::instance().set(&white_list, value, zero_address());
     }
 
+    /// Hands the owner role to `new_owner`, for example a timelock administered by governance
+    fn transfer_ownership(&self, new_owner: Key) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2FactoryNotOwner);
+        }
+        set_owner(new_owner);
+    }
+
     fn emit(&self, factory_event: &FACTORYEvent) {
         match factory_event {
             FACTORYEvent::PairCreated {
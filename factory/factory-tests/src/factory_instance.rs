@@ -81,6 +81,35 @@ impl FACTORYInstance {
         );
     }
 
+    pub fn set_protocol_fee(&self, sender: AccountHash, protocol_fee: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_protocol_fee",
+            runtime_args! {
+                "protocol_fee" => protocol_fee,
+            },
+            time,
+        );
+    }
+
+    pub fn set_pair_protocol_fee<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        pair: T,
+        protocol_fee: Option<U256>,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_pair_protocol_fee",
+            runtime_args! {
+                "pair" => pair.into(),
+                "protocol_fee" => protocol_fee,
+            },
+            time,
+        );
+    }
+
     pub fn set_white_list<T: Into<Key>>(&self, sender: AccountHash, white_list: T, time: u64) {
         self.0.call_contract(
             sender,
@@ -92,6 +121,21 @@ impl FACTORYInstance {
         );
     }
 
+    pub fn transfer_ownership<T: Into<Key>>(&self, sender: AccountHash, new_owner: T, time: u64) {
+        self.0.call_contract(
+            sender,
+            "transfer_ownership",
+            runtime_args! {
+                "new_owner" => new_owner.into(),
+            },
+            time,
+        );
+    }
+
+    pub fn owner(&self) -> Key {
+        self.0.query_named_key(String::from("owner"))
+    }
+
     pub fn get_white_lists<T: Into<Key>>(&self, account: T) -> (Key, Key) {
         self.0
             .query_dictionary("white_lists", helpers::key_to_str(&account.into()))
@@ -126,4 +170,23 @@ impl FACTORYInstance {
             .query_dictionary("pairs", helpers::keys_to_str(&token0, &token1))
             .unwrap()
     }
+
+    pub fn get_pair_by_id<T: Into<Key>>(&self, token_a: T, token_b: T) -> Key {
+        let pair_id: String = helpers::pair_id(
+            &token_a.into(),
+            &token_b.into(),
+            self.contract_package_hash(),
+        );
+        self.0.query_dictionary("pair_ids", pair_id).unwrap()
+    }
+
+    pub fn protocol_fee(&self) -> U256 {
+        self.0.query_named_key(String::from("protocol_fee"))
+    }
+
+    pub fn pair_protocol_fee<T: Into<Key>>(&self, pair: T) -> Option<U256> {
+        self.0
+            .query_dictionary("protocol_fee_overrides", helpers::key_to_str(&pair.into()))
+            .unwrap()
+    }
 }
@@ -4,6 +4,7 @@ use tests_common::{
     deploys::{deploy_erc20, deploy_wcspr},
     functions::zero_address,
     helpers::*,
+    pair_info::PAIR_TYPE_CONSTANT_PRODUCT,
     *,
 };
 
@@ -30,7 +31,7 @@ fn deploy() -> (TestEnv, FACTORYInstance, AccountHash, TestContract) {
         owner,
         runtime_args! {
             "wcspr" => Key::Hash(wcspr.package_hash()),
-            "dai" => Key::Hash(dai.package_hash()),
+            "base_tokens" => vec![Key::Hash(dai.package_hash())],
             "uniswap_v2_factory" => Key::from(token.contract_package_hash())
         },
         now(),
@@ -41,12 +42,11 @@ fn deploy() -> (TestEnv, FACTORYInstance, AccountHash, TestContract) {
         "Pair",
         owner,
         runtime_args! {
-            "name" => NAME,
-            "symbol" => SYMBOL,
-            "decimals" => DECIMALS,
-            "initial_supply" => INIT_TOTAL_SUPPLY,
             "callee_package_hash" => Key::Hash(callee_contract.package_hash()),
-            "factory_hash" =>  Key::from(token.contract_package_hash())
+            "factory_hash" =>  Key::from(token.contract_package_hash()),
+            "pair_type" => PAIR_TYPE_CONSTANT_PRODUCT,
+            "amplification" => U256::zero(),
+            "weight0" => U256::zero()
         },
         now(),
     );
@@ -78,9 +78,42 @@ fn test_factory_set_fee_to() {
     assert_eq!(token.fee_to(), Key::Account(user));
 }
 
+#[test]
+fn test_factory_set_protocol_fee() {
+    let (_env, token, owner, _pair_hash) = deploy();
+    assert_eq!(token.protocol_fee(), 1_667.into());
+    token.set_protocol_fee(owner, 2_500.into(), now());
+    assert_eq!(token.protocol_fee(), 2_500.into());
+}
+
+#[test]
+#[should_panic]
+fn test_factory_set_protocol_fee_too_high() {
+    let (_env, token, owner, _pair_hash) = deploy();
+    token.set_protocol_fee(owner, 5_001.into(), now());
+}
+
+#[test]
+#[should_panic]
+fn test_factory_set_protocol_fee_with_non_fee_to_setter() {
+    let (env, token, _owner, _pair_hash) = deploy();
+    let user = env.next_user();
+    token.set_protocol_fee(user, 2_500.into(), now());
+}
+
+#[test]
+fn test_factory_set_pair_protocol_fee() {
+    let (_env, token, owner, pair) = deploy();
+    let pair = Key::Hash(pair.package_hash());
+    token.set_pair_protocol_fee(owner, pair, Some(0.into()), now());
+    assert_eq!(token.pair_protocol_fee(pair), Some(0.into()));
+    token.set_pair_protocol_fee(owner, pair, None, now());
+    assert_eq!(token.pair_protocol_fee(pair), None);
+}
+
 #[test]
 fn test_factory_create_pair() {
-    let (env, token, owner, pair_hash) = deploy();
+    let (env, token, owner, pair) = deploy();
     assert_eq!(token.fee_to_setter(), Key::Account(owner));
     let token0 = deploy_erc20(
         &env,
@@ -104,7 +137,7 @@ fn test_factory_create_pair() {
     );
     let token0 = Key::Hash(token0.package_hash());
     let token1 = Key::Hash(token1.package_hash());
-    let pair_hash = Key::Hash(pair_hash.package_hash());
+    let pair_hash = Key::Hash(pair.package_hash());
     let user = env.next_user();
     token.set_white_list(owner, Key::Account(user), now());
     assert_eq!(
@@ -119,6 +152,22 @@ fn test_factory_create_pair() {
     assert_eq!(pair_0_1, pair_hash);
     assert_eq!(pair_1_0, pair_hash);
     assert_eq!(all_pairs.len(), 1);
+    assert_eq!(token.get_pair_by_id(token0, token1), pair_hash);
+    assert_eq!(token.get_pair_by_id(token1, token0), pair_hash);
+    let (symbol0, symbol1) = if token0 < token1 {
+        ("TK-1", "TK-2")
+    } else {
+        ("TK-2", "TK-1")
+    };
+    assert_eq!(
+        pair.query_named_key::<String>("name".into()),
+        format!("{}-{} LP", symbol0, symbol1)
+    );
+    assert_eq!(
+        pair.query_named_key::<String>("symbol".into()),
+        format!("{}-{}-LP", symbol0, symbol1)
+    );
+    assert_eq!(pair.query_named_key::<u8>("decimals".into()), 9);
 }
 
 #[test]
@@ -170,6 +219,7 @@ fn test_factory_remove_pair() {
     assert_eq!(pair_0_1, zero_address());
     assert_eq!(pair_1_0, zero_address());
     assert_eq!(all_pairs.len(), 0);
+    assert_eq!(token.get_pair_by_id(token0, token1), zero_address());
 }
 
 #[test]
@@ -198,6 +248,28 @@ fn test_factory_set_white_list_with_non_owner() {
     token.set_white_list(user, Key::Account(user), now());
 }
 
+#[test]
+fn test_factory_transfer_ownership() {
+    let (env, token, owner, _) = deploy();
+    let user = env.next_user();
+    token.transfer_ownership(owner, Key::Account(user), now());
+    assert_eq!(token.owner(), Key::Account(user));
+    // the white list now takes the new owner's word
+    token.set_white_list(user, Key::Account(user), now());
+    assert_eq!(
+        token.get_white_lists(Key::Account(user)),
+        (Key::Account(user), zero_address())
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(31)")] // As only the owner can hand over the role
+fn test_factory_transfer_ownership_with_non_owner() {
+    let (env, token, _, _) = deploy();
+    let user = env.next_user();
+    token.transfer_ownership(user, Key::Account(user), now());
+}
+
 #[test]
 #[should_panic]
 fn test_calling_construction() {
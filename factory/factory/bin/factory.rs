@@ -147,6 +147,37 @@ fn get_pair() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+/// This function is to return the deterministic id of the pair of tokens provided by user, which is the key of the pair in the pair_ids dictionary
+///
+/// # Parameters
+///
+/// * `token_a` - A Key that holds the Hash of token_a
+///
+/// * `token_b` - A Key that holds the Hash of token_b
+///
+
+#[no_mangle]
+fn pair_id() {
+    let token_a: Key = runtime::get_named_arg("token_a");
+    let token_b: Key = runtime::get_named_arg("token_b");
+    let ret: String = factory_crate::data::pair_id(token_a, token_b);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the pair against the deterministic id provided by user. If pair not found it will return hash-0000000000000000000000000000000000000000000000000000000000000000
+///
+/// # Parameters
+///
+/// * `pair_id` - A String that holds the id returned by pair_id
+///
+
+#[no_mangle]
+fn get_pair_by_id() {
+    let pair_id: String = runtime::get_named_arg("pair_id");
+    let ret: Key = Factory::default().get_pair_by_id(pair_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 /// This function is to set the white list addresses which is only possible if the caller matched with owners's hash
 ///
 /// # Parameters
@@ -160,6 +191,70 @@ fn set_white_list() {
     Factory::default().set_white_list(white_list, white_list);
 }
 
+/// This function is to hand the owner role to another account or contract, only the owner can call it
+///
+/// # Parameters
+///
+/// * `new_owner` - A Key that holds the account or package hash of the new owner
+///
+
+#[no_mangle]
+fn transfer_ownership() {
+    let new_owner: Key = runtime::get_named_arg("new_owner");
+    Factory::default().transfer_ownership(new_owner);
+}
+
+/// This function is to return the protocol share of the LP fee growth in basis points that applies to all pairs
+///
+
+#[no_mangle]
+fn protocol_fee() {
+    runtime::ret(CLValue::from_t(get_protocol_fee()).unwrap_or_revert());
+}
+
+/// This function is to return the protocol fee in basis points that applies to the pair, taking its override into account
+///
+/// # Parameters
+///
+/// * `pair` - A Key that holds the Hash of Pair Contract
+///
+
+#[no_mangle]
+fn protocol_fee_of() {
+    let pair: Key = runtime::get_named_arg("pair");
+    let ret: U256 = Factory::default().protocol_fee_of(pair);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to set the protocol fee in basis points for all pairs which is only possible if the caller matched with fee to setter's hash
+///
+/// # Parameters
+///
+/// * `protocol_fee` - A U256 that holds the protocol fee in basis points, at most 5000
+///
+
+#[no_mangle]
+fn set_protocol_fee() {
+    let protocol_fee: U256 = runtime::get_named_arg("protocol_fee");
+    Factory::default().set_protocol_fee(protocol_fee);
+}
+
+/// This function is to override the protocol fee of a single pair which is only possible if the caller matched with fee to setter's hash
+///
+/// # Parameters
+///
+/// * `pair` - A Key that holds the Hash of Pair Contract
+///
+/// * `protocol_fee` - An Option<U256> that holds the protocol fee in basis points, None removes the override
+///
+
+#[no_mangle]
+fn set_pair_protocol_fee() {
+    let pair: Key = runtime::get_named_arg("pair");
+    let protocol_fee: Option<U256> = runtime::get_named_arg("protocol_fee");
+    Factory::default().set_pair_protocol_fee(pair, protocol_fee);
+}
+
 fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(EntryPoint::new(
@@ -202,6 +297,23 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pair_id",
+        vec![
+            Parameter::new("token_a", Key::cl_type()),
+            Parameter::new("token_b", Key::cl_type()),
+        ],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_pair_by_id",
+        vec![Parameter::new("pair_id", String::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "fee_to",
         vec![],
@@ -244,6 +356,37 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "protocol_fee",
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "protocol_fee_of",
+        vec![Parameter::new("pair", Key::cl_type())],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_protocol_fee",
+        vec![Parameter::new("protocol_fee", U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_pair_protocol_fee",
+        vec![
+            Parameter::new("pair", Key::cl_type()),
+            Parameter::new("protocol_fee", Option::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "set_white_list",
         vec![Parameter::new("white_list", Key::cl_type())],
@@ -251,6 +394,13 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_ownership",
+        vec![Parameter::new("new_owner", Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
 
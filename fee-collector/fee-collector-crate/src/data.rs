@@ -0,0 +1,54 @@
+use common::{
+    functions::{account_zero_address, zero_address},
+    keys::*,
+    *,
+};
+
+/// Pair each token is swapped through to reach the target token
+pub struct Routes {
+    dict: Dict,
+}
+
+impl Routes {
+    pub fn instance() -> Routes {
+        Routes {
+            dict: Dict::instance(ROUTES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ROUTES_DICT)
+    }
+
+    pub fn get(&self, token: &Key) -> Key {
+        self.dict.get_by_key(token).unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, token: &Key, pair: Key) {
+        self.dict.set_by_key(token, pair);
+    }
+}
+
+pub fn set_target_token(target_token: Key) {
+    set_key(TARGET_TOKEN, target_token);
+}
+
+pub fn get_target_token() -> Key {
+    get_key(TARGET_TOKEN).unwrap_or_else(zero_address)
+}
+
+pub fn set_treasury(treasury: Key) {
+    set_key(TREASURY, treasury);
+}
+
+pub fn get_treasury() -> Key {
+    get_key(TREASURY).unwrap_or_else(get_owner)
+}
+
+pub fn set_owner(owner: Key) {
+    set_key(OWNER, owner);
+}
+
+pub fn get_owner() -> Key {
+    get_key(OWNER).unwrap_or_else(account_zero_address)
+}
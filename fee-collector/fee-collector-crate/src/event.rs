@@ -0,0 +1,34 @@
+use common::*;
+
+pub enum FEECOLLECTOREvent {
+    Harvest {
+        pair: Key,
+        liquidity: U256,
+        token0: Key,
+        amount0: U256,
+        token1: Key,
+        amount1: U256,
+        target_token: Key,
+        target_amount: U256,
+        treasury: Key,
+    },
+}
+
+impl FEECOLLECTOREvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            FEECOLLECTOREvent::Harvest {
+                pair: _,
+                liquidity: _,
+                token0: _,
+                amount0: _,
+                token1: _,
+                amount1: _,
+                target_token: _,
+                target_amount: _,
+                treasury: _,
+            } => "harvest",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{data::*, event::FEECOLLECTOREvent};
+use common::{
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::*,
+    quote::get_pair_amount_out,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+pub trait FEECOLLECTOR<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        target_token: Key,
+        treasury: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        set_owner(self.get_caller());
+        set_target_token(target_token);
+        set_treasury(treasury);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+        Routes::init();
+    }
+
+    fn only_owner(&self) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::FeeCollectorNotOwner);
+        }
+    }
+
+    /// Sets the token harvested proceeds are swapped into, the zero address sends them unconverted
+    fn set_target_token(&self, target_token: Key) {
+        self.only_owner();
+        set_target_token(target_token);
+    }
+
+    fn set_treasury(&self, treasury: Key) {
+        self.only_owner();
+        set_treasury(treasury);
+    }
+
+    /// Registers the pair `token` is swapped through, the zero address removes the route
+    fn set_route(&self, token: Key, pair: Key) {
+        self.only_owner();
+        Routes::instance().set(&token, pair);
+    }
+
+    /// Burns the LP tokens this contract holds in every pair and sends the proceeds to the
+    /// treasury, swapping them into the target token where a route is registered.
+    /// `amounts_out_min` holds, for each pair, the least target token the swaps of its token0
+    /// and token1 must return, so a route pair moved before the harvest cannot set the price
+    fn harvest(&self, pairs: Vec<Key>, amounts_out_min: Vec<(U256, U256)>) {
+        self.only_owner();
+        if pairs.len() != amounts_out_min.len() {
+            runtime::revert(Errors::FeeCollectorInvalidAmountsOutMin);
+        }
+        for (pair, amounts_out_min) in pairs.into_iter().zip(amounts_out_min) {
+            self.harvest_pair(pair, amounts_out_min);
+        }
+    }
+
+    fn harvest_pair(&self, pair: Key, (amount0_out_min, amount1_out_min): (U256, U256)) {
+        let pair_package_hash: ContractPackageHash = pair.into_hash().unwrap_or_revert().into();
+        let liquidity: U256 = runtime::call_versioned_contract(
+            pair_package_hash,
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::Contract(get_package_hash())
+            },
+        );
+        if liquidity.is_zero() {
+            return;
+        }
+        let token0: Key =
+            runtime::call_versioned_contract(pair_package_hash, None, "token0", runtime_args! {});
+        let token1: Key =
+            runtime::call_versioned_contract(pair_package_hash, None, "token1", runtime_args! {});
+        let () = runtime::call_versioned_contract(
+            pair_package_hash,
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(pair),
+                "amount" => liquidity
+            },
+        );
+        let (amount0, amount1): (U256, U256) = runtime::call_versioned_contract(
+            pair_package_hash,
+            None,
+            "burn",
+            runtime_args! {
+                "to" => Key::from(get_package_hash())
+            },
+        );
+        let target_amount: U256 = self
+            .convert(token0, amount0, amount0_out_min)
+            .checked_add(self.convert(token1, amount1, amount1_out_min))
+            .unwrap_or_revert_with(Errors::FeeCollectorOverFlow);
+        self.emit(&FEECOLLECTOREvent::Harvest {
+            pair,
+            liquidity,
+            token0,
+            amount0,
+            token1,
+            amount1,
+            target_token: get_target_token(),
+            target_amount,
+            treasury: get_treasury(),
+        });
+    }
+
+    /// Sends `amount` of `token` to the treasury, swapped into the target token if it has a route,
+    /// and returns the amount of target token it was worth. A swap returning less than
+    /// `amount_out_min` reverts
+    fn convert(&self, token: Key, amount: U256, amount_out_min: U256) -> U256 {
+        if amount.is_zero() {
+            return 0.into();
+        }
+        let target_token: Key = get_target_token();
+        if token == target_token {
+            self.transfer(token, get_treasury(), amount);
+            return amount;
+        }
+        let route: Key = Routes::instance().get(&token);
+        if target_token == zero_address() || route == zero_address() {
+            self.transfer(token, get_treasury(), amount);
+            return 0.into();
+        }
+        let route_package_hash: ContractPackageHash = route.into_hash().unwrap_or_revert().into();
+        let route_token0: Key =
+            runtime::call_versioned_contract(route_package_hash, None, "token0", runtime_args! {});
+        let route_token1: Key =
+            runtime::call_versioned_contract(route_package_hash, None, "token1", runtime_args! {});
+        if !((route_token0 == token && route_token1 == target_token)
+            || (route_token1 == token && route_token0 == target_token))
+        {
+            runtime::revert(Errors::FeeCollectorInvalidRoute);
+        }
+        let (reserve0, reserve1, _): (U128, U128, u64) = runtime::call_versioned_contract(
+            route_package_hash,
+            None,
+            "get_reserves",
+            runtime_args! {},
+        );
+        let (reserve_in, reserve_out) = if route_token0 == token {
+            (reserve0, reserve1)
+        } else {
+            (reserve1, reserve0)
+        };
+        if reserve_in.is_zero() || reserve_out.is_zero() {
+            self.transfer(token, get_treasury(), amount);
+            return 0.into();
+        }
+        let amount_out: U256 = get_pair_amount_out(
+            route,
+            route_token0 == token,
+            amount,
+            U256::from(reserve_in.as_u128()),
+            U256::from(reserve_out.as_u128()),
+        );
+        if amount_out.is_zero() {
+            self.transfer(token, get_treasury(), amount);
+            return 0.into();
+        }
+        if amount_out < amount_out_min {
+            runtime::revert(Errors::FeeCollectorInsufficientOutputAmount);
+        }
+        let (amount0_out, amount1_out): (U256, U256) = if route_token0 == token {
+            (0.into(), amount_out)
+        } else {
+            (amount_out, 0.into())
+        };
+        self.transfer(token, route, amount);
+        let () = runtime::call_versioned_contract(
+            route_package_hash,
+            None,
+            "swap",
+            runtime_args! {
+                "amount0_out" => amount0_out,
+                "amount1_out" => amount1_out,
+                "to" => get_treasury(),
+                "data" => ""
+            },
+        );
+        amount_out
+    }
+
+    fn transfer(&self, token: Key, recipient: Key, amount: U256) {
+        let () = runtime::call_versioned_contract(
+            token.into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(recipient),
+                "amount" => amount
+            },
+        );
+    }
+
+    fn emit(&self, fee_collector_event: &FEECOLLECTOREvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", fee_collector_event.type_name());
+        match fee_collector_event {
+            FEECOLLECTOREvent::Harvest {
+                pair,
+                liquidity,
+                token0,
+                amount0,
+                token1,
+                amount1,
+                target_token,
+                target_amount,
+                treasury,
+            } => {
+                event.insert("pair", pair.to_string());
+                event.insert("liquidity", liquidity.to_string());
+                event.insert("token0", token0.to_string());
+                event.insert("amount0", amount0.to_string());
+                event.insert("token1", token1.to_string());
+                event.insert("amount1", amount1.to_string());
+                event.insert("target_token", target_token.to_string());
+                event.insert("target_amount", target_amount.to_string());
+                event.insert("treasury", treasury.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod data;
+pub mod event;
+mod fee_collector;
+
+pub use common::*;
+pub use fee_collector::FEECOLLECTOR;
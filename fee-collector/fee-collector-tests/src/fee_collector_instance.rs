@@ -0,0 +1,91 @@
+use tests_common::{account::AccountHash, deploys::deploy_fee_collector, *};
+
+pub struct FEECOLLECTORInstance(pub TestContract);
+
+impl FEECOLLECTORInstance {
+    pub fn new(
+        env: &TestEnv,
+        sender: AccountHash,
+        target_token: Key,
+        treasury: Key,
+        time: u64,
+    ) -> FEECOLLECTORInstance {
+        FEECOLLECTORInstance(deploy_fee_collector(
+            env,
+            sender,
+            target_token,
+            treasury,
+            time,
+        ))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    pub fn harvest(
+        &self,
+        sender: AccountHash,
+        pairs: Vec<Key>,
+        amounts_out_min: Vec<(U256, U256)>,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "harvest",
+            runtime_args! {
+                "pairs" => pairs,
+                "amounts_out_min" => amounts_out_min,
+            },
+            time,
+        );
+    }
+
+    pub fn set_target_token(&self, sender: AccountHash, target_token: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_target_token",
+            runtime_args! {
+                "target_token" => target_token,
+            },
+            time,
+        );
+    }
+
+    pub fn set_treasury(&self, sender: AccountHash, treasury: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_treasury",
+            runtime_args! {
+                "treasury" => treasury,
+            },
+            time,
+        );
+    }
+
+    pub fn set_route(&self, sender: AccountHash, token: Key, pair: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_route",
+            runtime_args! {
+                "token" => token,
+                "pair" => pair,
+            },
+            time,
+        );
+    }
+
+    pub fn target_token(&self) -> Key {
+        self.0.query_named_key(String::from("target_token"))
+    }
+
+    pub fn treasury(&self) -> Key {
+        self.0.query_named_key(String::from("treasury"))
+    }
+
+    pub fn route(&self, token: Key) -> Key {
+        self.0
+            .query_dictionary("routes", helpers::key_to_str(&token))
+            .unwrap()
+    }
+}
@@ -0,0 +1,210 @@
+use crate::fee_collector_instance::FEECOLLECTORInstance;
+use tests_common::{account::AccountHash, deploys::*, functions::zero_address, helpers::*, *};
+
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    FEECOLLECTORInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let wcspr = deploy_wcspr(
+        &env,
+        "WCSPR",
+        owner,
+        WRAPPED_CSPR.into(),
+        "WCSPR".into(),
+        9,
+        0.into(),
+        now(),
+    );
+    let token_a = deploy_erc20(&env, "ERC20-1", owner, "TokenA", "TK-A", 9, 0.into(), now());
+    let token_b = deploy_erc20(&env, "ERC20-2", owner, "TokenB", "TK-B", 9, 0.into(), now());
+    let flash_swapper = deploy_flashswapper(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    let pair = deploy_pair(
+        &env,
+        "PAIR",
+        owner,
+        Key::Hash(flash_swapper.package_hash()),
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(token_a.package_hash()),
+            "token_b" => Key::Hash(token_b.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    let fee_collector =
+        FEECOLLECTORInstance::new(&env, owner, zero_address(), Key::Account(owner), now());
+    // stands in for the protocol fee the pair mints to fee_to
+    for token in [&token_a, &token_b] {
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => AMOUNT
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => fee_collector.package_hash()
+        },
+        now(),
+    );
+    (env, owner, fee_collector, token_a, token_b, pair)
+}
+
+fn balance(token: &TestContract, owner: AccountHash) -> U256 {
+    token.query(BALANCES, address_to_str(&Address::Account(owner)))
+}
+
+#[test]
+fn test_fee_collector_deploy() {
+    let (_, owner, fee_collector, _, _, _) = deploy();
+    assert_eq!(fee_collector.target_token(), zero_address());
+    assert_eq!(fee_collector.treasury(), Key::Account(owner));
+}
+
+#[test]
+fn test_fee_collector_harvest() {
+    let (env, owner, fee_collector, token_a, token_b, pair) = deploy();
+    let treasury = env.next_user();
+    fee_collector.set_treasury(owner, Key::Account(treasury), now());
+    fee_collector.harvest(
+        owner,
+        vec![Key::Hash(pair.package_hash())],
+        vec![(0.into(), 0.into())],
+        now(),
+    );
+    // everything but the minimum liquidity locked in the pair
+    assert_eq!(balance(&token_a, treasury), AMOUNT - 1_000);
+    assert_eq!(balance(&token_b, treasury), AMOUNT - 1_000);
+    assert_eq!(
+        pair.query::<U256>(
+            BALANCES,
+            address_to_str(&Address::Contract(fee_collector.0.package_hash().into()))
+        ),
+        0.into()
+    );
+}
+
+#[test]
+fn test_fee_collector_harvest_into_target_token() {
+    let (env, owner, fee_collector, token_a, token_b, pair) = deploy();
+    let treasury = env.next_user();
+    fee_collector.set_treasury(owner, Key::Account(treasury), now());
+    fee_collector.set_target_token(owner, Key::Hash(token_b.package_hash()), now());
+    fee_collector.set_route(
+        owner,
+        Key::Hash(token_a.package_hash()),
+        Key::Hash(pair.package_hash()),
+        now(),
+    );
+    assert_eq!(
+        fee_collector.route(Key::Hash(token_a.package_hash())),
+        Key::Hash(pair.package_hash())
+    );
+    fee_collector.harvest(
+        owner,
+        vec![Key::Hash(pair.package_hash())],
+        vec![token_a_out_min(&pair, &token_a, 999.into())],
+        now(),
+    );
+    // token a is swapped into the 1,000 of each token left in the pair
+    assert_eq!(balance(&token_a, treasury), 0.into());
+    assert_eq!(balance(&token_b, treasury), AMOUNT - 1_000 + 999);
+}
+
+/// Minimums of a harvest of the pair asking `amount_out_min` for token a
+fn token_a_out_min(
+    pair: &TestContract,
+    token_a: &TestContract,
+    amount_out_min: U256,
+) -> (U256, U256) {
+    if pair.query_named_key::<Key>("token0".into()) == Key::Hash(token_a.package_hash()) {
+        (amount_out_min, 0.into())
+    } else {
+        (0.into(), amount_out_min)
+    }
+}
+
+#[test]
+#[should_panic(expected = "User(195)")] // As the route pays 999 token b for token a
+fn test_fee_collector_harvest_below_amount_out_min() {
+    let (_, owner, fee_collector, token_a, token_b, pair) = deploy();
+    fee_collector.set_target_token(owner, Key::Hash(token_b.package_hash()), now());
+    fee_collector.set_route(
+        owner,
+        Key::Hash(token_a.package_hash()),
+        Key::Hash(pair.package_hash()),
+        now(),
+    );
+    fee_collector.harvest(
+        owner,
+        vec![Key::Hash(pair.package_hash())],
+        vec![token_a_out_min(&pair, &token_a, 1_000.into())],
+        now(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(196)")] // As every pair needs its minimums
+fn test_fee_collector_harvest_without_amounts_out_min() {
+    let (_, owner, fee_collector, _, _, pair) = deploy();
+    fee_collector.harvest(owner, vec![Key::Hash(pair.package_hash())], vec![], now());
+}
+
+#[test]
+#[should_panic]
+fn test_fee_collector_harvest_with_non_owner() {
+    let (env, _, fee_collector, _, _, pair) = deploy();
+    let user = env.next_user();
+    fee_collector.harvest(
+        user,
+        vec![Key::Hash(pair.package_hash())],
+        vec![(0.into(), 0.into())],
+        now(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_fee_collector_set_route_with_non_owner() {
+    let (env, _, fee_collector, token_a, _, pair) = deploy();
+    let user = env.next_user();
+    fee_collector.set_route(
+        user,
+        Key::Hash(token_a.package_hash()),
+        Key::Hash(pair.package_hash()),
+        now(),
+    );
+}
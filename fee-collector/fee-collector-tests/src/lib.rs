@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod fee_collector_tests;
+
+#[cfg(test)]
+pub mod fee_collector_instance;
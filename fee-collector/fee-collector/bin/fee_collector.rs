@@ -0,0 +1,268 @@
+#![no_main]
+
+use fee_collector_crate::{
+    contract_api::{runtime, storage},
+    data::*,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct FeeCollector(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for FeeCollector {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl FEECOLLECTOR<OnChainContractStorage> for FeeCollector {}
+
+impl FeeCollector {
+    fn constructor(
+        &self,
+        target_token: Key,
+        treasury: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        FEECOLLECTOR::init(self, target_token, treasury, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let target_token: Key = runtime::get_named_arg("target_token");
+    let treasury: Key = runtime::get_named_arg("treasury");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    FeeCollector::default().constructor(target_token, treasury, contract_hash, package_hash);
+}
+
+/// This function is to burn the LP tokens held in every pair and send the proceeds to the treasury
+///
+/// # Parameters
+///
+/// * `pairs` - A list of Keys that holds the Hash of the pairs to harvest
+///
+/// * `amounts_out_min` - A list of U256 pairs that holds, for each pair, the least target token its token0 and token1 must be swapped for
+///
+
+#[no_mangle]
+fn harvest() {
+    let pairs: Vec<Key> = runtime::get_named_arg("pairs");
+    let amounts_out_min: Vec<(U256, U256)> = runtime::get_named_arg("amounts_out_min");
+    FeeCollector::default().harvest(pairs, amounts_out_min);
+}
+
+/// This function is to set the token the harvested proceeds are swapped into
+///
+/// # Parameters
+///
+/// * `target_token` - A Key that holds the Hash of the target token, the zero address turns conversion off
+///
+
+#[no_mangle]
+fn set_target_token() {
+    let target_token: Key = runtime::get_named_arg("target_token");
+    FeeCollector::default().set_target_token(target_token);
+}
+
+/// This function is to set the treasury that receives the harvested proceeds
+///
+/// # Parameters
+///
+/// * `treasury` - A Key that holds the account address or contract hash of the treasury
+///
+
+#[no_mangle]
+fn set_treasury() {
+    let treasury: Key = runtime::get_named_arg("treasury");
+    FeeCollector::default().set_treasury(treasury);
+}
+
+/// This function is to set the pair a token is swapped through to reach the target token
+///
+/// # Parameters
+///
+/// * `token` - A Key that holds the Hash of the token
+///
+/// * `pair` - A Key that holds the Hash of the pair of token and the target token, the zero address removes the route
+///
+
+#[no_mangle]
+fn set_route() {
+    let token: Key = runtime::get_named_arg("token");
+    let pair: Key = runtime::get_named_arg("pair");
+    FeeCollector::default().set_route(token, pair);
+}
+
+/// This function is to return the token the harvested proceeds are swapped into
+///
+
+#[no_mangle]
+fn target_token() {
+    runtime::ret(CLValue::from_t(get_target_token()).unwrap_or_revert());
+}
+
+/// This function is to return the treasury that receives the harvested proceeds
+///
+
+#[no_mangle]
+fn treasury() {
+    runtime::ret(CLValue::from_t(get_treasury()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("target_token", Key::cl_type()),
+            Parameter::new("treasury", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "harvest",
+        vec![
+            Parameter::new("pairs", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new(
+                "amounts_out_min",
+                CLType::List(Box::new(<(U256, U256)>::cl_type())),
+            ),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_target_token",
+        vec![Parameter::new("target_token", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_treasury",
+        vec![Parameter::new("treasury", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_route",
+        vec![
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("pair", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "target_token",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "treasury",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let target_token: Key = runtime::get_named_arg("target_token");
+        let treasury: Key = runtime::get_named_arg("treasury");
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "target_token" => target_token,
+            "treasury" => treasury,
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use common::{keys::*, unwrap_or_revert::UnwrapOrRevert, *};
+use common::{
+    functions::{account_zero_address, zero_address},
+    keys::*,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
 
 pub fn set_wcspr(wcspr: Key) {
     set_key(WCSPR, wcspr);
@@ -8,12 +14,12 @@ pub fn get_wcspr() -> Key {
     get_key(WCSPR).unwrap_or_revert()
 }
 
-pub fn set_dai(dai: Key) {
-    set_key(DAI, dai);
+pub fn set_base_tokens(base_tokens: Vec<Key>) {
+    set_key(BASE_TOKENS, base_tokens);
 }
 
-pub fn get_dai() -> Key {
-    get_key(DAI).unwrap_or_revert()
+pub fn get_base_tokens() -> Vec<Key> {
+    get_key(BASE_TOKENS).unwrap_or_default()
 }
 
 pub fn set_permissioned_pair_address(permissioned_pair_address: Key) {
@@ -47,3 +53,43 @@ pub fn set_uniswap_v2_pair(uniswap_v2_pair: Key) {
 pub fn get_uniswap_v2_pair() -> Key {
     get_key(UNISWAP_V2_PAIR).unwrap_or_revert()
 }
+
+pub fn set_owner(owner: Key) {
+    set_key(OWNER, owner);
+}
+
+pub fn get_owner() -> Key {
+    get_key(OWNER).unwrap_or_else(account_zero_address)
+}
+
+pub fn set_borrower(borrower: Key) {
+    set_key(BORROWER, borrower);
+}
+
+pub fn get_borrower() -> Key {
+    get_key(BORROWER).unwrap_or_else(zero_address)
+}
+
+pub fn set_protocol_fee(protocol_fee: U256) {
+    set_key(PROTOCOL_FEE, protocol_fee);
+}
+
+pub fn get_protocol_fee() -> U256 {
+    get_key(PROTOCOL_FEE).unwrap_or_default()
+}
+
+pub fn set_treasury(treasury: Key) {
+    set_key(TREASURY, treasury);
+}
+
+pub fn get_treasury() -> Key {
+    get_key(TREASURY).unwrap_or_else(zero_address)
+}
+
+pub fn set_last_event(last_event: BTreeMap<String, String>) {
+    set_key(LAST_EVENT, last_event);
+}
+
+pub fn get_last_event() -> BTreeMap<String, String> {
+    get_key(LAST_EVENT).unwrap_or_default()
+}
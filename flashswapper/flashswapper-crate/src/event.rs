@@ -1,25 +1,64 @@
-use common::{U256 as TokenId, *};
+use common::*;
 
 pub enum FLASHSWAPPEREvent {
-    Mint {
-        recipient: Key,
-        token_ids: Vec<TokenId>,
+    FlashLoan {
+        borrower: Key,
+        pair: Key,
+        amount: U256,
+        fee: U256,
+        protocol_fee: U256,
+        token_pay: Key,
     },
-    Burn {
-        owner: Key,
-        token_ids: Vec<TokenId>,
+    FlashSwap {
+        borrower: Key,
+        pair: Key,
+        token_borrow: Key,
+        amount: U256,
+        fee: U256,
+        protocol_fee: U256,
+        token_pay: Key,
     },
-    Approve {
-        owner: Key,
-        spender: Key,
-        token_ids: Vec<TokenId>,
-    },
-    Transfer {
-        sender: Key,
-        recipient: Key,
-        token_ids: Vec<TokenId>,
-    },
-    MetadataUpdate {
-        token_id: TokenId,
+    TriangularFlashSwap {
+        borrower: Key,
+        pairs: Vec<Key>,
+        token_borrow: Key,
+        amount: U256,
+        fee: U256,
+        protocol_fee: U256,
+        token_pay: Key,
     },
 }
+
+impl FLASHSWAPPEREvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            FLASHSWAPPEREvent::FlashLoan {
+                borrower: _,
+                pair: _,
+                amount: _,
+                fee: _,
+                protocol_fee: _,
+                token_pay: _,
+            } => "flash_loan",
+            FLASHSWAPPEREvent::FlashSwap {
+                borrower: _,
+                pair: _,
+                token_borrow: _,
+                amount: _,
+                fee: _,
+                protocol_fee: _,
+                token_pay: _,
+            } => "flash_swap",
+            FLASHSWAPPEREvent::TriangularFlashSwap {
+                borrower: _,
+                pairs: _,
+                token_borrow: _,
+                amount: _,
+                fee: _,
+                protocol_fee: _,
+                token_pay: _,
+            } => "triangular_flash_swap",
+        }
+        .to_string()
+    }
+}
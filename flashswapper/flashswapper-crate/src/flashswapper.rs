@@ -1,13 +1,22 @@
-use crate::data::*;
+use crate::{data::*, event::FLASHSWAPPEREvent};
 use common::{
-    contract_api::runtime, errors::Errors, functions::*, unwrap_or_revert::UnwrapOrRevert, *,
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::*,
+    quote::{get_pair_amount_in, require_constant_product},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
 };
+use std::collections::BTreeMap;
+
+/// Highest protocol surcharge the owner can set, in basis points
+const MAX_PROTOCOL_FEE: u64 = 100;
 
 pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(
         &self,
         wcspr: Key,
-        dai: Key,
+        base_tokens: Vec<Key>,
         uniswap_v2_factory: Key,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
@@ -15,20 +24,51 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
     ) {
         set_wcspr(wcspr);
         set_cspr(zero_address());
-        set_dai(dai);
+        set_base_tokens(base_tokens);
         set_uniswap_v2_factory(uniswap_v2_factory);
+        set_owner(self.get_caller());
+        set_protocol_fee(0.into());
+        set_treasury(zero_address());
         set_contract_hash(contract_hash);
         set_package_hash(package_hash);
         set_purse(purse);
     }
 
+    fn set_base_tokens(&self, base_tokens: Vec<Key>) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperNotOwner);
+        }
+        set_base_tokens(base_tokens);
+    }
+
+    /// @notice Sets the surcharge, in basis points of the repayment, that is sent to the treasury on top of the pair fee
+    fn set_protocol_fee(&self, protocol_fee: U256) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperNotOwner);
+        }
+        if protocol_fee > MAX_PROTOCOL_FEE.into() {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperProtocolFeeTooHigh);
+        }
+        set_protocol_fee(protocol_fee);
+    }
+
+    /// @notice Sets the key that receives the protocol surcharge, no surcharge is taken while it is the zero address
+    fn set_treasury(&self, treasury: Key) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperNotOwner);
+        }
+        set_treasury(treasury);
+    }
+
     fn start_swap(
         &mut self,
         _token_borrow: Key,
         _amount: U256,
         _token_pay: Key,
+        _pair: Option<Key>,
         _user_data: String,
     ) {
+        set_borrower(self.get_caller());
         let mut is_borrowing_cspr: bool = false;
         let mut is_paying_cspr: bool = false;
         let mut token_borrow: Key = _token_borrow; //btc
@@ -41,12 +81,17 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             is_paying_cspr = true;
             token_pay = get_wcspr(); // we'll wrap the user's cspr before sending it back to UniswapV2
         }
+        if token_borrow != token_pay && _pair.is_some() {
+            // only a flash loan borrows from a single pair the caller can choose
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperInvalidPair);
+        }
         if token_borrow == token_pay {
             self.simple_flash_loan(
                 token_borrow,
                 _amount,
                 is_borrowing_cspr,
                 is_paying_cspr,
+                _pair,
                 _user_data,
             );
         } else if token_borrow == get_wcspr() || token_pay == get_wcspr() {
@@ -102,6 +147,14 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
                 _is_paying_cspr,
                 _user_data.into(),
             );
+        } else if _swap_type == "path_swap" {
+            self.path_flash_swap_execute(
+                _token_borrow,
+                _amount,
+                _token_pay,
+                _triangle_data.into(),
+                _user_data.into(),
+            );
         } else {
             self.triangular_flash_swap_execute(
                 _token_borrow,
@@ -115,32 +168,21 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
 
     /// @notice This function is used when the user repays with the same token they borrowed
     /// @dev This initiates the flash borrow. See `simpleFlashLoanExecute` for the code that executes after the borrow.
+    /// @dev The pair is `_pair` if the caller supplied one, otherwise the deepest `_token_borrow` pair
+    ///     against wcspr or one of the configured base tokens.
     fn simple_flash_loan(
         &self,
         _token_borrow: Key,
         _amount: U256,
         _is_borrowing_cspr: bool,
         _is_paying_cspr: bool,
+        _pair: Option<Key>,
         _data: String,
     ) {
-        let mut other_token: Key = get_dai();
-        let wcspr: Key = get_wcspr();
-        let uniswap_v2_factory: Key = get_uniswap_v2_factory();
-        if _token_borrow != wcspr {
-            other_token = wcspr;
-        }
-        let uniswap_v2_factory_hash_add_array = match uniswap_v2_factory {
-            Key::Hash(package) => package,
-            _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+        let permissioned_pair_address: Key = match _pair {
+            Some(pair) => self.validate_loan_pair(_token_borrow, pair),
+            None => self.deepest_loan_pair(_token_borrow),
         };
-        let uniswap_v2_factory_hash_add: ContractPackageHash =
-            ContractPackageHash::new(uniswap_v2_factory_hash_add_array);
-        let permissioned_pair_address: Key = runtime::call_versioned_contract(
-            uniswap_v2_factory_hash_add,
-            None,
-            "get_pair",
-            runtime_args! {"token0" => _token_borrow, "token1"  => other_token },
-        );
         set_permissioned_pair_address(permissioned_pair_address);
         let pair_address: Key = get_permissioned_pair_address();
         // in before 0 address was hash-0000000000000000000000000000000000000000000000000000000000000000
@@ -214,6 +256,71 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         );
     }
 
+    /// @notice Checks that a caller-supplied pair is registered in the factory and holds _token_borrow
+    fn validate_loan_pair(&self, _token_borrow: Key, _pair: Key) -> Key {
+        let pair_package_hash: ContractPackageHash = _pair.into_hash().unwrap_or_revert().into();
+        let token0: Key =
+            runtime::call_versioned_contract(pair_package_hash, None, "token0", runtime_args! {});
+        let token1: Key =
+            runtime::call_versioned_contract(pair_package_hash, None, "token1", runtime_args! {});
+        if _token_borrow != token0 && _token_borrow != token1 {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperInvalidPair);
+        }
+        let registered_pair: Key = runtime::call_versioned_contract(
+            get_uniswap_v2_factory()
+                .into_hash()
+                .unwrap_or_revert()
+                .into(),
+            None,
+            "get_pair",
+            runtime_args! {"token0" => token0, "token1" => token1},
+        );
+        if registered_pair != _pair {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperInvalidPair);
+        }
+        _pair
+    }
+
+    /// @notice Returns the pair holding the most _token_borrow among the _token_borrow/wcspr pair
+    ///     and the _token_borrow/base token pairs, or the zero address if none of them exists
+    fn deepest_loan_pair(&self, _token_borrow: Key) -> Key {
+        let factory_package_hash: ContractPackageHash = get_uniswap_v2_factory()
+            .into_hash()
+            .unwrap_or_revert()
+            .into();
+        let token_borrow_package_hash: ContractPackageHash =
+            _token_borrow.into_hash().unwrap_or_revert().into();
+        let mut candidates: Vec<Key> = vec![get_wcspr()];
+        candidates.extend(get_base_tokens());
+        let mut deepest_pair: Key = zero_address();
+        let mut deepest_balance: U256 = 0.into();
+        for other_token in candidates {
+            if other_token == _token_borrow {
+                continue;
+            }
+            let pair: Key = runtime::call_versioned_contract(
+                factory_package_hash,
+                None,
+                "get_pair",
+                runtime_args! {"token0" => _token_borrow, "token1" => other_token},
+            );
+            if pair == zero_address() {
+                continue;
+            }
+            let pair_balance: U256 = runtime::call_versioned_contract(
+                token_borrow_package_hash,
+                None,
+                "balance_of",
+                runtime_args! {"address" => pair},
+            );
+            if deepest_pair == zero_address() || pair_balance > deepest_balance {
+                deepest_pair = pair;
+                deepest_balance = pair_balance;
+            }
+        }
+        deepest_pair
+    }
+
     /// @notice This is the code that is executed after `simpleFlashLoan` initiated the flash-borrow
     /// @dev When this code executes, this contract will hold the flash-borrowed _amount of _token_borrow
 
@@ -235,16 +342,12 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         let cspr: Key = get_cspr();
         if _is_borrowing_cspr {
             // call withdraw from WCSPR and transfer cspr to 'to'
-            let res: Result<(), u32> = runtime::call_versioned_contract(
+            let () = runtime::call_versioned_contract(
                 wcspr_hash_add,
                 None,
                 "withdraw",
-                runtime_args! {"to_purse" => get_purse(), "amount" => U512::from(_amount.as_u128())},
+                runtime_args! {"purse" => get_purse(), "amount" => U512::from(_amount.as_u128())},
             );
-            match res {
-                Ok(()) => (),
-                Err(err) => runtime::revert(err),
-            }
         }
         let fee: U256 = ((_amount * U256::from(3)) / 997)
             .checked_add(U256::from(1))
@@ -258,12 +361,16 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             _token_borrow
         };
         let token_to_repay: Key = if _is_paying_cspr { cspr } else { _token_borrow };
+        let protocol_fee: U256 = self.protocol_surcharge(amount_to_repay);
+        let amount_to_collect: U256 = amount_to_repay
+            .checked_add(protocol_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperOverFlow2);
         // do whatever the user wants
         self.execute(
             token_borrowed,
             _amount,
             token_to_repay,
-            amount_to_repay,
+            amount_to_collect,
             _user_data,
         );
         // payback the loan
@@ -271,16 +378,12 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
 
         if _is_paying_cspr {
             let caller_purse: URef = get_purse(); // get this contract's purse
-            let res: Result<(), u32> = runtime::call_versioned_contract(
+            let () = runtime::call_versioned_contract(
                 wcspr_hash_add,
                 None,
                 "deposit",
-                runtime_args! { "purse" => caller_purse, "amount" => U512::from(amount_to_repay.as_u128())},
+                runtime_args! { "purse" => caller_purse, "amount" => U512::from(amount_to_collect.as_u128())},
             );
-            match res {
-                Ok(()) => (),
-                Err(err) => runtime::revert(err),
-            }
         }
         let _token_borrow_hash_add_array = match _token_borrow {
             Key::Hash(package) => package,
@@ -288,16 +391,21 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         };
         let _token_borrow_hash_add: ContractPackageHash =
             ContractPackageHash::new(_token_borrow_hash_add_array);
-        let res: Result<(), u32> = runtime::call_versioned_contract(
+        () = runtime::call_versioned_contract(
             _token_borrow_hash_add,
             None,
             "transfer",
             runtime_args! {"recipient"=>_pair_address , "amount" => amount_to_repay},
         );
-        match res {
-            Ok(()) => (),
-            Err(err) => runtime::revert(err),
-        }
+        self.pay_protocol_surcharge(_token_borrow_hash_add, protocol_fee);
+        self.emit(&FLASHSWAPPEREvent::FlashLoan {
+            borrower: get_borrower(),
+            pair: _pair_address,
+            amount: _amount,
+            fee,
+            protocol_fee,
+            token_pay: token_to_repay,
+        });
     }
 
     /// @notice This function is used when either the _tokenBorrow or _tokenPay is wcspr or cspr
@@ -432,16 +540,12 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             ContractPackageHash::new(wcspr_address_hash_add_array);
         if is_borrowing_cspr {
             // call withdraw from WCSPR and transfer cspr to 'to'
-            let res: Result<(), u32> = runtime::call_versioned_contract(
+            let () = runtime::call_versioned_contract(
                 wcspr_package_hash,
                 None,
                 "withdraw",
-                runtime_args! {"to_purse" => get_purse(), "amount" => U512::from(amount.as_u128())},
+                runtime_args! {"purse" => get_purse(), "amount" => U512::from(amount.as_u128())},
             );
-            match res {
-                Ok(()) => (),
-                Err(err) => runtime::revert(err),
-            }
         }
         // compute the amount of _tokenPay that needs to be repaid
         let pair_address: Key = get_permissioned_pair_address(); // gas efficiency
@@ -471,6 +575,8 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             "balance_of",
             runtime_args! {"address" => pair_address},
         );
+        // the repayment is priced on x * y = k
+        require_constant_product(pair_address);
         let amount_1000: U256 = U256::from(1000);
         let amount_997: U256 = 997.into();
         let amount_1: U256 = 1.into();
@@ -492,39 +598,45 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         } else {
             _token_to_repay = token_pay;
         }
+        let protocol_fee: U256 = self.protocol_surcharge(amount_to_repay);
+        let amount_to_collect: U256 = amount_to_repay
+            .checked_add(protocol_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperOverFlow3);
         // do whatever the user wants
         self.execute(
             _token_borrowed,
             amount,
             _token_to_repay,
-            amount_to_repay,
+            amount_to_collect,
             _user_data,
         );
         // payback loan
         // wrap cspr if necessary
         if is_paying_cspr {
             let caller_purse: URef = get_purse(); // get this contract's purse
-            let _deposit_result: Result<(), u32> = runtime::call_versioned_contract(
+            let () = runtime::call_versioned_contract(
                 wcspr_package_hash,
                 None,
                 "deposit",
-                runtime_args! { "purse" => caller_purse, "amount" => U512::from(amount_to_repay.as_u128())},
+                runtime_args! { "purse" => caller_purse, "amount" => U512::from(amount_to_collect.as_u128())},
             );
-            match _deposit_result {
-                Ok(()) => (),
-                Err(err) => runtime::revert(err),
-            }
         }
-        let res: Result<(), u32> = runtime::call_versioned_contract(
+        () = runtime::call_versioned_contract(
             token_pay_package_hash,
             None,
             "transfer",
             runtime_args! {"recipient" => _pair_address, "amount" => amount_to_repay},
         );
-        match res {
-            Ok(()) => (),
-            Err(err) => runtime::revert(err),
-        }
+        self.pay_protocol_surcharge(token_pay_package_hash, protocol_fee);
+        self.emit(&FLASHSWAPPEREvent::FlashSwap {
+            borrower: get_borrower(),
+            pair: _pair_address,
+            token_borrow: _token_borrowed,
+            amount,
+            fee: self.pair_fee(amount_to_repay),
+            protocol_fee,
+            token_pay: _token_to_repay,
+        });
     }
 
     /// @notice This function is used when neither the _tokenBorrow nor the _tokenPay is wcspr
@@ -595,9 +707,6 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             // _amount is too big
             runtime::revert(Errors::UniswapV2CoreFlashSwapperAmountTooBig);
         }
-        let pair_balance_token_borrow_after: U256 = pair_balance_token_borrow_before
-            .checked_sub(amount)
-            .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperUnderFlow);
         //convert Key to ContractPackageHash
         let wcspr_address_hash_add_array = match wcspr {
             Key::Hash(package) => package,
@@ -611,12 +720,12 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             "balance_of",
             runtime_args! {"address" => borrow_pair_address},
         );
-        let amount_1000: U256 = 1000.into();
-        let amount_997: U256 = 997.into();
-        let amount_1: U256 = 1.into();
-        let amount_of_wcspr: U256 = ((amount_1000 * pair_balance_wcspr * amount)
-            / (amount_997 * pair_balance_token_borrow_after))
-            + amount_1;
+        let amount_of_wcspr: U256 = get_pair_amount_in(
+            borrow_pair_address,
+            amount,
+            pair_balance_wcspr,
+            pair_balance_token_borrow_before,
+        );
         // using a helper function here to avoid "stack too deep" :(
         self.triangular_flash_swap_helper(
             token_borrow,
@@ -782,16 +891,12 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         };
         let wcspr_package_hash: ContractPackageHash =
             ContractPackageHash::new(wcspr_address_hash_add_array);
-        let res: Result<(), u32> = runtime::call_versioned_contract(
+        () = runtime::call_versioned_contract(
             wcspr_package_hash,
             None,
             "transfer",
             runtime_args! {"recipient" => borrow_pair_address, "amount" => amount_of_wcspr},
         );
-        match res {
-            Ok(()) => (),
-            Err(err) => runtime::revert(err),
-        }
         let flash_swapper_address: Key = get_package_hash().into();
         let _result: () = runtime::call_versioned_contract(
             borrow_pair_package_hash,
@@ -820,25 +925,295 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
             "balance_of",
             runtime_args! {"address" => pay_pair_address},
         );
+        // the repayment is priced on x * y = k
+        require_constant_product(pay_pair_address);
         let amount_1000: U256 = 1000.into();
         let amount_997: U256 = 997.into();
         let amount_1: U256 = 1.into();
         let amount_to_repay: U256 = ((amount_1000 * pair_balance_token_pay * amount_of_wcspr)
             / (amount_997 * pair_balance_wcspr))
             + amount_1;
+        let protocol_fee: U256 = self.protocol_surcharge(amount_to_repay);
         // Step 4: Do whatever the user wants (arb, liqudiation, etc)
-        self.execute(token_borrow, amount, token_pay, amount_to_repay, user_data);
+        self.execute(
+            token_borrow,
+            amount,
+            token_pay,
+            amount_to_repay
+                .checked_add(protocol_fee)
+                .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperOverFlow3),
+            user_data,
+        );
         // Step 5: Pay back the flash-borrow to the _tokenPay/wcspr pool
-        let res: Result<(), u32> = runtime::call_versioned_contract(
+        () = runtime::call_versioned_contract(
             token_pay_package_hash,
             None,
             "transfer",
             runtime_args! {"recipient" => pay_pair_address, "amount" => amount_to_repay},
         );
-        match res {
-            Ok(()) => (),
-            Err(err) => runtime::revert(err),
+        self.pay_protocol_surcharge(token_pay_package_hash, protocol_fee);
+        self.emit(&FLASHSWAPPEREvent::TriangularFlashSwap {
+            borrower: get_borrower(),
+            pairs: vec![pay_pair_address, borrow_pair_address],
+            token_borrow,
+            amount,
+            fee: self.pair_fee(amount_to_repay),
+            protocol_fee,
+            token_pay,
+        });
+    }
+
+    /// @notice Flash-borrows _amount of the first token of _path and repays using the last token of _path
+    /// @dev _path is [token_borrow, hop_1, ..., hop_n, token_pay]. We flash-borrow hop_n from the token_pay/hop_n
+    ///     pair and then swap it hop by hop back to token_borrow, so every pair of the path is used once.
+    ///     A triangular swap is the path [token_borrow, wcspr, token_pay].
+    /// @dev The required input of every hop is computed up front against the current reserves. See
+    ///     `pathFlashSwapExecute` for the code that executes after the borrow.
+    fn start_path_swap(&mut self, _path: Vec<Key>, _amount: U256, _user_data: String) {
+        if _path.len() < 2 {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperInvalidPath);
         }
+        set_borrower(self.get_caller());
+        // tokens flow from token_pay to token_borrow, so walk the path backwards
+        let reversed_path: Vec<Key> = _path.iter().rev().cloned().collect();
+        let factory_package_hash: ContractPackageHash = get_uniswap_v2_factory()
+            .into_hash()
+            .unwrap_or_revert()
+            .into();
+        let mut pairs: Vec<Key> = Vec::new();
+        for hop in reversed_path.windows(2) {
+            let pair: Key = runtime::call_versioned_contract(
+                factory_package_hash,
+                None,
+                "get_pair",
+                runtime_args! {"token0" => hop[0], "token1" => hop[1]},
+            );
+            if pair == zero_address() {
+                // requested pair is not available
+                runtime::revert(
+                    Errors::UniswapV2CoreFlashSwapperRequestedRequestedPairIsNotAvailable,
+                );
+            }
+            pairs.push(pair);
+        }
+        // amounts[i] is the amount of reversed_path[i] that goes into pairs[i]
+        let mut amounts: Vec<U256> = vec![0.into(); reversed_path.len()];
+        amounts[reversed_path.len() - 1] = _amount;
+        for i in (1..reversed_path.len()).rev() {
+            let (reserve_in, reserve_out) =
+                self.get_pair_reserves(pairs[i - 1], reversed_path[i - 1]);
+            amounts[i - 1] = get_pair_amount_in(pairs[i - 1], amounts[i], reserve_in, reserve_out);
+        }
+        set_permissioned_pair_address(pairs[0]);
+        let path_data: String = format!(
+            "{}{}{}{}{}",
+            self.join_hashes(&reversed_path),
+            ":",
+            self.join_hashes(&pairs),
+            ":",
+            amounts
+                .iter()
+                .map(|amount| amount.to_string())
+                .collect::<Vec<String>>()
+                .join(".")
+        );
+        let data: String = format!(
+            "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
+            "path_swap",
+            ",",
+            self.key_to_hash(_path[0]),
+            ",",
+            _amount,
+            ",",
+            self.key_to_hash(reversed_path[0]),
+            ",",
+            false,
+            ",",
+            false,
+            ",",
+            path_data,
+            ",",
+            _user_data
+        );
+        let (amount0_out, amount1_out) =
+            self.get_amounts_out(pairs[0], reversed_path[1], amounts[1]);
+        let _result: () = runtime::call_versioned_contract(
+            pairs[0].into_hash().unwrap_or_revert().into(),
+            None,
+            "swap",
+            runtime_args! {"amount0_out" => amount0_out, "amount1_out" => amount1_out, "to" => Key::from(get_package_hash()), "data" => data},
+        );
+    }
+
+    /// @notice This is the code that is executed after `startPathSwap` initiated the flash-borrow
+    /// @dev When this code executes, this contract will hold the first hop of the reversed path. Every
+    ///     remaining hop is swapped with a normal swap until we hold _amount of _tokenBorrow.
+    fn path_flash_swap_execute(
+        &mut self,
+        token_borrow: Key,
+        amount: U256,
+        token_pay: Key,
+        path_data: String,
+        user_data: String,
+    ) {
+        // decode _pathData
+        let decoded_path_data: Vec<&str> = path_data.split(':').collect();
+        let reversed_path: Vec<Key> = self.split_hashes(decoded_path_data[0]);
+        let pairs: Vec<Key> = self.split_hashes(decoded_path_data[1]);
+        let amounts: Vec<U256> = decoded_path_data[2]
+            .split('.')
+            .map(|amount| amount.parse().unwrap_or_revert())
+            .collect();
+        let flash_swapper_address: Key = get_package_hash().into();
+        // swap every hop after the flash-borrowed one through its own pair
+        for i in 1..pairs.len() {
+            () = runtime::call_versioned_contract(
+                reversed_path[i].into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {"recipient" => Address::from(pairs[i]), "amount" => amounts[i]},
+            );
+            let (amount0_out, amount1_out) =
+                self.get_amounts_out(pairs[i], reversed_path[i + 1], amounts[i + 1]);
+            let _result: () = runtime::call_versioned_contract(
+                pairs[i].into_hash().unwrap_or_revert().into(),
+                None,
+                "swap",
+                runtime_args! {"amount0_out" => amount0_out, "amount1_out" => amount1_out, "to" => flash_swapper_address, "data" => ""},
+            );
+        }
+        let amount_to_repay: U256 = amounts[0];
+        let protocol_fee: U256 = self.protocol_surcharge(amount_to_repay);
+        let amount_to_collect: U256 = amount_to_repay
+            .checked_add(protocol_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperOverFlow3);
+        // do whatever the user wants
+        self.execute(
+            token_borrow,
+            amount,
+            token_pay,
+            amount_to_collect,
+            user_data,
+        );
+        // the whole path reverts unless we can pay back the flash-borrow
+        let token_pay_package_hash: ContractPackageHash =
+            token_pay.into_hash().unwrap_or_revert().into();
+        let token_pay_balance: U256 = runtime::call_versioned_contract(
+            token_pay_package_hash,
+            None,
+            "balance_of",
+            runtime_args! {"address" => Address::from(flash_swapper_address)},
+        );
+        if token_pay_balance < amount_to_collect {
+            runtime::revert(Errors::UniswapV2CoreFlashSwapperInsufficientRepayment);
+        }
+        () = runtime::call_versioned_contract(
+            token_pay_package_hash,
+            None,
+            "transfer",
+            runtime_args! {"recipient" => Address::from(pairs[0]), "amount" => amount_to_repay},
+        );
+        self.pay_protocol_surcharge(token_pay_package_hash, protocol_fee);
+        self.emit(&FLASHSWAPPEREvent::TriangularFlashSwap {
+            borrower: get_borrower(),
+            pairs,
+            token_borrow,
+            amount,
+            fee: self.pair_fee(amount_to_repay),
+            protocol_fee,
+            token_pay,
+        });
+    }
+
+    /// @notice Returns the protocol surcharge owed on top of _amount_to_repay
+    fn protocol_surcharge(&self, _amount_to_repay: U256) -> U256 {
+        if get_treasury() == zero_address() {
+            return 0.into();
+        }
+        _amount_to_repay
+            .checked_mul(get_protocol_fee())
+            .unwrap_or_revert_with(Errors::UniswapV2CoreFlashSwapperOverFlow1)
+            / 10_000
+    }
+
+    /// @notice Sends the protocol surcharge in the token of _token_package_hash to the treasury
+    fn pay_protocol_surcharge(
+        &self,
+        _token_package_hash: ContractPackageHash,
+        _protocol_fee: U256,
+    ) {
+        if _protocol_fee == 0.into() {
+            return;
+        }
+        () = runtime::call_versioned_contract(
+            _token_package_hash,
+            None,
+            "transfer",
+            runtime_args! {"recipient" => Address::from(get_treasury()), "amount" => _protocol_fee},
+        );
+    }
+
+    /// @notice Returns the part of _amount_in that the pair keeps as its 0.3% swap fee
+    fn pair_fee(&self, _amount_in: U256) -> U256 {
+        _amount_in - (_amount_in * 997) / 1000
+    }
+
+    /// @notice Returns the reserves of _pair ordered as (reserve of _token_in, reserve of the other token)
+    fn get_pair_reserves(&self, _pair: Key, _token_in: Key) -> (U256, U256) {
+        let pair_package_hash: ContractPackageHash = _pair.into_hash().unwrap_or_revert().into();
+        let (reserve0, reserve1, _): (U128, U128, u64) = runtime::call_versioned_contract(
+            pair_package_hash,
+            None,
+            "get_reserves",
+            runtime_args! {},
+        );
+        let token0: Key =
+            runtime::call_versioned_contract(pair_package_hash, None, "token0", runtime_args! {});
+        let reserve0: U256 = U256::from(reserve0.as_u128());
+        let reserve1: U256 = U256::from(reserve1.as_u128());
+        if _token_in == token0 {
+            (reserve0, reserve1)
+        } else {
+            (reserve1, reserve0)
+        }
+    }
+
+    /// @notice Returns the (amount0_out, amount1_out) arguments of `swap` for taking _amount of _token_out out of _pair
+    fn get_amounts_out(&self, _pair: Key, _token_out: Key, _amount: U256) -> (U256, U256) {
+        let token0: Key = runtime::call_versioned_contract(
+            _pair.into_hash().unwrap_or_revert().into(),
+            None,
+            "token0",
+            runtime_args! {},
+        );
+        if _token_out == token0 {
+            (_amount, 0.into())
+        } else {
+            (0.into(), _amount)
+        }
+    }
+
+    /// @notice Returns the hex hash of a contract package Key, as used in the swap data
+    fn key_to_hash(&self, _key: Key) -> String {
+        let package_hash: ContractPackageHash = _key.into_hash().unwrap_or_revert().into();
+        let package_hash_str: String = package_hash.to_formatted_string();
+        let package_hash_vec: Vec<&str> = package_hash_str.split('-').collect();
+        package_hash_vec[1].into()
+    }
+
+    fn join_hashes(&self, _keys: &[Key]) -> String {
+        _keys
+            .iter()
+            .map(|key| self.key_to_hash(*key))
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    fn split_hashes(&self, _hashes: &str) -> Vec<Key> {
+        _hashes
+            .split('.')
+            .map(|hash| Key::from_formatted_str(&format!("{}{}", "hash-", hash)).unwrap_or_revert())
+            .collect()
     }
 
     // @notice This is where the user's custom logic goes
@@ -858,4 +1233,75 @@ pub trait FLASHSWAPPER<Storage: ContractStorage>: ContractContext<Storage> {
         _user_data: String,
     ) {
     }
+    fn emit(&self, flashswapper_event: &FLASHSWAPPEREvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", flashswapper_event.type_name());
+        match flashswapper_event {
+            FLASHSWAPPEREvent::FlashLoan {
+                borrower,
+                pair,
+                amount,
+                fee,
+                protocol_fee,
+                token_pay,
+            } => {
+                event.insert("borrower", borrower.to_string());
+                event.insert("pair", pair.to_string());
+                event.insert("amount", amount.to_string());
+                event.insert("fee", fee.to_string());
+                event.insert("protocol_fee", protocol_fee.to_string());
+                event.insert("token_pay", token_pay.to_string());
+            }
+            FLASHSWAPPEREvent::FlashSwap {
+                borrower,
+                pair,
+                token_borrow,
+                amount,
+                fee,
+                protocol_fee,
+                token_pay,
+            } => {
+                event.insert("borrower", borrower.to_string());
+                event.insert("pair", pair.to_string());
+                event.insert("token_borrow", token_borrow.to_string());
+                event.insert("amount", amount.to_string());
+                event.insert("fee", fee.to_string());
+                event.insert("protocol_fee", protocol_fee.to_string());
+                event.insert("token_pay", token_pay.to_string());
+            }
+            FLASHSWAPPEREvent::TriangularFlashSwap {
+                borrower,
+                pairs,
+                token_borrow,
+                amount,
+                fee,
+                protocol_fee,
+                token_pay,
+            } => {
+                event.insert("borrower", borrower.to_string());
+                event.insert(
+                    "pairs",
+                    pairs
+                        .iter()
+                        .map(|pair| pair.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                );
+                event.insert("token_borrow", token_borrow.to_string());
+                event.insert("amount", amount.to_string());
+                event.insert("fee", fee.to_string());
+                event.insert("protocol_fee", protocol_fee.to_string());
+                event.insert("token_pay", token_pay.to_string());
+            }
+        };
+        // the latest event is also kept under a named key, so it can be read without an event indexer
+        set_last_event(
+            event
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        );
+        storage::new_uref(event);
+    }
 }
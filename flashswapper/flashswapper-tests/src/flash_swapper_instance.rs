@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use tests_common::{account::AccountHash, contract_api::runtime, *};
 
 pub struct FlashSwapperInstance(TestContract);
@@ -8,7 +9,7 @@ impl FlashSwapperInstance {
         contract_name: &str,
         sender: AccountHash,
         wcspr: Key,
-        dai: Key,
+        base_tokens: Vec<Key>,
         uniswap_v2_factory: Key,
         time: u64,
     ) -> FlashSwapperInstance {
@@ -20,12 +21,16 @@ impl FlashSwapperInstance {
             runtime_args! {
                 "uniswap_v2_factory" => uniswap_v2_factory,
                 "wcspr" => wcspr,
-                "dai" => dai,
+                "base_tokens" => base_tokens,
             },
             time,
         ))
     }
 
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
     pub fn mint_with_caller(&self, caller: Key, recipient: Key, amount: U256) {
         let caller_hash_add_array = match caller {
             Key::Hash(package) => package,
@@ -43,7 +48,7 @@ impl FlashSwapperInstance {
         &self,
         sender: AccountHash,
         wcspr: Key,
-        dai: Key,
+        base_tokens: Vec<Key>,
         uniswap_v2_factory: Key,
         time: u64,
     ) {
@@ -52,19 +57,21 @@ impl FlashSwapperInstance {
             "constructor",
             runtime_args! {
                 "wcspr" => wcspr,
-                "dai" => dai,
+                "base_tokens" => base_tokens,
                 "uniswap_v2_factory" => uniswap_v2_factory,
             },
             time,
         );
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn start_swap(
         &self,
         sender: AccountHash,
         token_borrow: Key,
         amount: U256,
         token_pay: Key,
+        pair: Option<Key>,
         user_data: String,
         time: u64,
     ) {
@@ -75,12 +82,82 @@ impl FlashSwapperInstance {
                 "token_borrow" => token_borrow,
                 "amount" => amount,
                 "token_pay" => token_pay,
+                "pair" => pair,
                 "user_data" => user_data,
             },
             time,
         );
     }
 
+    pub fn start_path_swap(
+        &self,
+        sender: AccountHash,
+        path: Vec<Key>,
+        amount: U256,
+        user_data: String,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "start_path_swap",
+            runtime_args! {
+                "path" => path,
+                "amount" => amount,
+                "user_data" => user_data,
+            },
+            time,
+        );
+    }
+
+    pub fn set_base_tokens(&self, sender: AccountHash, base_tokens: Vec<Key>, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_base_tokens",
+            runtime_args! {
+                "base_tokens" => base_tokens,
+            },
+            time,
+        );
+    }
+
+    pub fn set_protocol_fee(&self, sender: AccountHash, protocol_fee: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_protocol_fee",
+            runtime_args! {
+                "protocol_fee" => protocol_fee,
+            },
+            time,
+        );
+    }
+
+    pub fn set_treasury(&self, sender: AccountHash, treasury: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_treasury",
+            runtime_args! {
+                "treasury" => treasury,
+            },
+            time,
+        );
+    }
+
+    pub fn protocol_fee(&self) -> U256 {
+        self.0.query_named_key(String::from("protocol_fee"))
+    }
+
+    pub fn treasury(&self) -> Key {
+        self.0.query_named_key(String::from("treasury"))
+    }
+
+    pub fn last_event(&self) -> BTreeMap<String, String> {
+        self.0.query_named_key(String::from("last_event"))
+    }
+
+    pub fn base_tokens(&self) -> Vec<Key> {
+        self.0.query_named_key(String::from("base_tokens"))
+    }
+
     pub fn uniswap_v2_call(
         &self,
         sender: AccountHash,
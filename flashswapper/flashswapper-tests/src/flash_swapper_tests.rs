@@ -48,7 +48,7 @@ fn deploy_flash_swapper() -> (
         "flash_swapper",
         owner,
         Key::Hash(wcspr.contract_hash()),
-        Key::Hash(dai.contract_hash()),
+        vec![Key::Hash(dai.contract_hash())],
         Key::Hash(factory.contract_hash()),
         now(),
     );
@@ -57,10 +57,530 @@ fn deploy_flash_swapper() -> (
 
 #[test]
 fn test_flash_swapper_deploy() {
-    let (_, flash_swapper, _, _, _, _, _) = deploy_flash_swapper();
+    let (_, flash_swapper, _, _, _, dai, _) = deploy_flash_swapper();
     let self_hash: ContractHash = flash_swapper.self_contract_hash();
     assert_ne!(self_hash, zero_address().into_hash().unwrap().into());
+    assert_eq!(
+        flash_swapper.base_tokens(),
+        vec![Key::Hash(dai.contract_hash())]
+    );
+}
+
+#[test]
+fn test_flash_swapper_set_base_tokens() {
+    let (_, flash_swapper, owner, _, _, dai, btc) = deploy_flash_swapper();
+    let base_tokens: Vec<Key> = vec![
+        Key::Hash(dai.contract_hash()),
+        Key::Hash(btc.contract_hash()),
+    ];
+    flash_swapper.set_base_tokens(owner, base_tokens.clone(), now());
+    assert_eq!(flash_swapper.base_tokens(), base_tokens);
+}
+
+#[test]
+#[should_panic]
+fn test_flash_swapper_set_base_tokens_with_non_owner() {
+    let (env, flash_swapper, _, _, _, _, btc) = deploy_flash_swapper();
+    let user = env.next_user();
+    flash_swapper.set_base_tokens(user, vec![Key::Hash(btc.contract_hash())], now());
+}
+
+#[test]
+fn test_flash_swapper_set_protocol_fee() {
+    let (env, flash_swapper, owner, _, _, _, _) = deploy_flash_swapper();
+    assert_eq!(flash_swapper.protocol_fee(), 0.into());
+    assert_eq!(flash_swapper.treasury(), zero_address());
+    let treasury = env.next_user();
+    flash_swapper.set_treasury(owner, Key::Account(treasury), now());
+    flash_swapper.set_protocol_fee(owner, 30.into(), now());
+    assert_eq!(flash_swapper.treasury(), Key::Account(treasury));
+    assert_eq!(flash_swapper.protocol_fee(), 30.into());
+}
+
+#[test]
+#[should_panic]
+fn test_flash_swapper_set_protocol_fee_too_high() {
+    let (_, flash_swapper, owner, _, _, _, _) = deploy_flash_swapper();
+    flash_swapper.set_protocol_fee(owner, 101.into(), now());
+}
+
+#[test]
+#[should_panic]
+fn test_flash_swapper_set_treasury_with_non_owner() {
+    let (env, flash_swapper, _, _, _, _, _) = deploy_flash_swapper();
+    let user = env.next_user();
+    flash_swapper.set_treasury(user, Key::Account(user), now());
+}
+
+#[test]
+#[should_panic]
+fn test_flash_swapper_start_path_swap_with_short_path() {
+    let (_, flash_swapper, owner, _, _, dai, _) = deploy_flash_swapper();
+    flash_swapper.start_path_swap(
+        owner,
+        vec![Key::Hash(dai.package_hash())],
+        500.into(),
+        "".into(),
+        now(),
+    );
+}
+/// Deploys a pair of token_a and token_b through the factory, with the flash swapper as its callee
+/// and AMOUNT of each token as liquidity minted to the owner
+fn create_pair_with_liquidity(
+    env: &TestEnv,
+    owner: AccountHash,
+    factory: &TestContract,
+    flash_swapper: &FlashSwapperInstance,
+    contract_name: &str,
+    token_a: &TestContract,
+    token_b: &TestContract,
+) -> TestContract {
+    let pair = deploy_pair(
+        env,
+        contract_name,
+        owner,
+        flash_swapper.package_hash(),
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(token_a.package_hash()),
+            "token_b" => Key::Hash(token_b.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    for token in [token_a, token_b] {
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => AMOUNT
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner)
+        },
+        now(),
+    );
+    pair
+}
+
+/// Returns a flash swapper without base tokens, the factory it borrows from and three tokens
+fn deploy_tokens() -> (
+    TestEnv,
+    FlashSwapperInstance,
+    AccountHash,
+    TestContract,
+    [TestContract; 3],
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let wcspr = deploy_wcspr(
+        &env,
+        "WCSPR",
+        owner,
+        WRAPPED_CSPR.into(),
+        "WCSPR".into(),
+        9,
+        0.into(),
+        now(),
+    );
+    let flash_swapper = FlashSwapperInstance::new(
+        &env,
+        "flash_swapper",
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    let token_a = deploy_erc20(&env, "ERC20-1", owner, "TokenA", "TK-A", 9, 0.into(), now());
+    let token_b = deploy_erc20(&env, "ERC20-2", owner, "TokenB", "TK-B", 9, 0.into(), now());
+    let token_c = deploy_erc20(&env, "ERC20-3", owner, "TokenC", "TK-C", 9, 0.into(), now());
+    (
+        env,
+        flash_swapper,
+        owner,
+        factory,
+        [token_a, token_b, token_c],
+    )
+}
+
+/// Returns a flash swapper and three tokens, with the pairs token_a/token_b and token_b/token_c
+/// created through the factory
+fn deploy_path() -> (
+    TestEnv,
+    FlashSwapperInstance,
+    AccountHash,
+    [TestContract; 3],
+    [TestContract; 2],
+) {
+    let (env, flash_swapper, owner, factory, [token_a, token_b, token_c]) = deploy_tokens();
+    let pair_ab = create_pair_with_liquidity(
+        &env,
+        owner,
+        &factory,
+        &flash_swapper,
+        "PAIR-AB",
+        &token_a,
+        &token_b,
+    );
+    let pair_bc = create_pair_with_liquidity(
+        &env,
+        owner,
+        &factory,
+        &flash_swapper,
+        "PAIR-BC",
+        &token_b,
+        &token_c,
+    );
+    (
+        env,
+        flash_swapper,
+        owner,
+        [token_a, token_b, token_c],
+        [pair_ab, pair_bc],
+    )
+}
+
+/// Returns a flash swapper with token_b and token_c as base tokens and the pairs token_a/token_b
+/// and token_a/token_c, where token_a/token_c holds 10,000,000,000 more token_a
+fn deploy_loan() -> (
+    TestEnv,
+    FlashSwapperInstance,
+    AccountHash,
+    [TestContract; 3],
+    [TestContract; 2],
+) {
+    let (env, flash_swapper, owner, factory, [token_a, token_b, token_c]) = deploy_tokens();
+    flash_swapper.set_base_tokens(
+        owner,
+        vec![
+            Key::Hash(token_b.package_hash()),
+            Key::Hash(token_c.package_hash()),
+        ],
+        now(),
+    );
+    let pair_ab = create_pair_with_liquidity(
+        &env,
+        owner,
+        &factory,
+        &flash_swapper,
+        "PAIR-AB",
+        &token_a,
+        &token_b,
+    );
+    let pair_ac = create_pair_with_liquidity(
+        &env,
+        owner,
+        &factory,
+        &flash_swapper,
+        "PAIR-AC",
+        &token_a,
+        &token_c,
+    );
+    token_a.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Contract(pair_ac.package_hash().into()),
+            "amount" => U256::from(10_000_000_000u64)
+        },
+        now(),
+    );
+    pair_ac.call_contract(owner, "sync", runtime_args! {}, now());
+    (
+        env,
+        flash_swapper,
+        owner,
+        [token_a, token_b, token_c],
+        [pair_ab, pair_ac],
+    )
+}
+
+fn balance(token: &TestContract, address: Address) -> U256 {
+    token.query(BALANCES, address_to_str(&address))
 }
+
+fn reserve(pair: &TestContract, token: &TestContract) -> U128 {
+    if pair.query_named_key::<Key>("token0".into()) == Key::Hash(token.package_hash()) {
+        pair.query_named_key("reserve0".into())
+    } else {
+        pair.query_named_key("reserve1".into())
+    }
+}
+
+#[test]
+fn test_flash_swapper_start_path_swap() {
+    let (_, flash_swapper, owner, [token_a, token_b, token_c], [pair_ab, pair_bc]) = deploy_path();
+    let flash_swapper_address = Address::from(flash_swapper.package_hash());
+    // 1,013,140,432 token_b buy 1,000,000,000 token_a and 1,026,589,796 token_c buy that token_b
+    token_c.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => flash_swapper_address,
+            "amount" => U256::from(1_026_589_796u64)
+        },
+        now(),
+    );
+    flash_swapper.start_path_swap(
+        owner,
+        vec![
+            Key::Hash(token_a.package_hash()),
+            Key::Hash(token_b.package_hash()),
+            Key::Hash(token_c.package_hash()),
+        ],
+        1_000_000_000u64.into(),
+        "".into(),
+        now(),
+    );
+    assert_eq!(
+        balance(&token_a, flash_swapper_address),
+        1_000_000_000u64.into()
+    );
+    assert_eq!(balance(&token_b, flash_swapper_address), 0.into());
+    assert_eq!(balance(&token_c, flash_swapper_address), 0.into());
+    assert_eq!(reserve(&pair_ab, &token_a), U128::from(99_000_000_000u64));
+    assert_eq!(reserve(&pair_ab, &token_b), U128::from(101_013_140_432u64));
+    assert_eq!(reserve(&pair_bc, &token_b), U128::from(98_986_859_568u64));
+    assert_eq!(reserve(&pair_bc, &token_c), U128::from(101_026_589_796u64));
+}
+
+#[test]
+#[should_panic] // As the flash swapper cannot repay the first hop of the path
+fn test_flash_swapper_start_path_swap_insufficient_repayment() {
+    let (_, flash_swapper, owner, [token_a, token_b, token_c], _) = deploy_path();
+    flash_swapper.start_path_swap(
+        owner,
+        vec![
+            Key::Hash(token_a.package_hash()),
+            Key::Hash(token_b.package_hash()),
+            Key::Hash(token_c.package_hash()),
+        ],
+        1_000_000_000u64.into(),
+        "".into(),
+        now(),
+    );
+}
+
+/// Lends 1,000,000,000 token_a and funds the flash swapper with the 3,009,028 fee on top
+fn start_loan(
+    flash_swapper: &FlashSwapperInstance,
+    owner: AccountHash,
+    token_a: &TestContract,
+    pair: Option<Key>,
+) {
+    token_a.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::from(flash_swapper.package_hash()),
+            "amount" => U256::from(3_009_028u64)
+        },
+        now(),
+    );
+    flash_swapper.start_swap(
+        owner,
+        Key::Hash(token_a.package_hash()),
+        1_000_000_000u64.into(),
+        Key::Hash(token_a.package_hash()),
+        pair,
+        "".into(),
+        now(),
+    );
+}
+
+#[test]
+fn test_flash_swapper_simple_flash_loan_from_deepest_pair() {
+    let (_, flash_swapper, owner, [token_a, _, _], [pair_ab, pair_ac]) = deploy_loan();
+    start_loan(&flash_swapper, owner, &token_a, None);
+    assert_eq!(
+        balance(&token_a, Address::from(flash_swapper.package_hash())),
+        0.into()
+    );
+    assert_eq!(reserve(&pair_ab, &token_a), U128::from(100_000_000_000u64));
+    assert_eq!(reserve(&pair_ac, &token_a), U128::from(110_003_009_028u64));
+}
+
+#[test]
+fn test_flash_swapper_simple_flash_loan_from_given_pair() {
+    let (_, flash_swapper, owner, [token_a, _, _], [pair_ab, pair_ac]) = deploy_loan();
+    start_loan(
+        &flash_swapper,
+        owner,
+        &token_a,
+        Some(Key::Hash(pair_ab.package_hash())),
+    );
+    assert_eq!(reserve(&pair_ab, &token_a), U128::from(100_003_009_028u64));
+    assert_eq!(reserve(&pair_ac, &token_a), U128::from(110_000_000_000u64));
+}
+
+#[test]
+#[should_panic(expected = "User(98)")] // As the pair is not the one the factory has for its tokens
+fn test_flash_swapper_simple_flash_loan_from_unregistered_pair() {
+    let (env, flash_swapper, owner, [token_a, token_b, _], _) = deploy_loan();
+    // a factory of another account, which lists the owner too
+    let other = env.next_user();
+    let factory = deploy_factory(&env, other, Key::Account(other), now());
+    factory.call_contract(
+        other,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    let pair = create_pair_with_liquidity(
+        &env,
+        owner,
+        &factory,
+        &flash_swapper,
+        "PAIR-OTHER",
+        &token_a,
+        &token_b,
+    );
+    start_loan(
+        &flash_swapper,
+        owner,
+        &token_a,
+        Some(Key::Hash(pair.package_hash())),
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(98)")] // As a pair can only be given for a flash loan
+fn test_flash_swapper_start_swap_with_pair_for_flash_swap() {
+    let (_, flash_swapper, owner, [token_a, token_b, _], [pair_ab, _]) = deploy_loan();
+    flash_swapper.start_swap(
+        owner,
+        Key::Hash(token_a.package_hash()),
+        1_000_000_000u64.into(),
+        Key::Hash(token_b.package_hash()),
+        Some(Key::Hash(pair_ab.package_hash())),
+        "".into(),
+        now(),
+    );
+}
+
+#[test]
+fn test_flash_swapper_simple_flash_loan_with_protocol_fee() {
+    let (env, flash_swapper, owner, [token_a, _, _], [_, pair_ac]) = deploy_loan();
+    let treasury = env.next_user();
+    flash_swapper.set_treasury(owner, Key::Account(treasury), now());
+    flash_swapper.set_protocol_fee(owner, 100.into(), now());
+    // 1% of the 1,003,009,028 repaid to the pair
+    token_a.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::from(flash_swapper.package_hash()),
+            "amount" => U256::from(10_030_090u64)
+        },
+        now(),
+    );
+    start_loan(&flash_swapper, owner, &token_a, None);
+    assert_eq!(
+        balance(&token_a, Address::Account(treasury)),
+        10_030_090u64.into()
+    );
+    assert_eq!(
+        balance(&token_a, Address::from(flash_swapper.package_hash())),
+        0.into()
+    );
+    assert_eq!(reserve(&pair_ac, &token_a), U128::from(110_003_009_028u64));
+    let event = flash_swapper.last_event();
+    assert_eq!(event["event_type"], "flash_loan");
+    assert_eq!(event["borrower"], Key::Account(owner).to_string());
+    assert_eq!(event["pair"], Key::Hash(pair_ac.package_hash()).to_string());
+    assert_eq!(event["amount"], "1000000000");
+    assert_eq!(event["fee"], "3009028");
+    assert_eq!(event["protocol_fee"], "10030090");
+    assert_eq!(
+        event["token_pay"],
+        Key::Hash(token_a.package_hash()).to_string()
+    );
+}
+
+#[test]
+fn test_flash_swapper_start_path_swap_with_protocol_fee() {
+    let (env, flash_swapper, owner, [token_a, token_b, token_c], [pair_ab, pair_bc]) =
+        deploy_path();
+    let treasury = env.next_user();
+    flash_swapper.set_treasury(owner, Key::Account(treasury), now());
+    flash_swapper.set_protocol_fee(owner, 100.into(), now());
+    // the 1,026,589,796 token_c of the first hop and 1% on top
+    token_c.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::from(flash_swapper.package_hash()),
+            "amount" => U256::from(1_036_855_693u64)
+        },
+        now(),
+    );
+    flash_swapper.start_path_swap(
+        owner,
+        vec![
+            Key::Hash(token_a.package_hash()),
+            Key::Hash(token_b.package_hash()),
+            Key::Hash(token_c.package_hash()),
+        ],
+        1_000_000_000u64.into(),
+        "".into(),
+        now(),
+    );
+    assert_eq!(
+        balance(&token_c, Address::Account(treasury)),
+        10_265_897u64.into()
+    );
+    assert_eq!(
+        balance(&token_c, Address::from(flash_swapper.package_hash())),
+        0.into()
+    );
+    assert_eq!(reserve(&pair_bc, &token_c), U128::from(101_026_589_796u64));
+    let event = flash_swapper.last_event();
+    assert_eq!(event["event_type"], "triangular_flash_swap");
+    assert_eq!(
+        event["pairs"],
+        format!(
+            "{},{}",
+            Key::Hash(pair_bc.package_hash()),
+            Key::Hash(pair_ab.package_hash())
+        )
+    );
+    assert_eq!(
+        event["token_borrow"],
+        Key::Hash(token_a.package_hash()).to_string()
+    );
+    assert_eq!(event["amount"], "1000000000");
+    assert_eq!(event["fee"], "3079770");
+    assert_eq!(event["protocol_fee"], "10265897");
+    assert_eq!(
+        event["token_pay"],
+        Key::Hash(token_c.package_hash()).to_string()
+    );
+}
+
 // todo:
 // will be done later when purses are supported in test cases
 
@@ -233,7 +753,7 @@ fn test_calling_construction() {
     flash_swapper.constructor(
         owner,
         Key::Hash(wcspr.contract_hash()),
-        Key::Hash(dai.contract_hash()),
+        vec![Key::Hash(dai.contract_hash())],
         Key::Hash(factory.contract_hash()),
         now(),
     );
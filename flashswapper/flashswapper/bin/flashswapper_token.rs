@@ -23,7 +23,7 @@ impl Token {
     fn constructor(
         &self,
         wcspr: Key,
-        dai: Key,
+        base_tokens: Vec<Key>,
         uniswap_v2_factory: Key,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
@@ -32,7 +32,7 @@ impl Token {
         FLASHSWAPPER::init(
             self,
             wcspr,
-            dai,
+            base_tokens,
             uniswap_v2_factory,
             contract_hash,
             package_hash,
@@ -44,7 +44,7 @@ impl Token {
 #[no_mangle]
 fn constructor() {
     let wcspr: Key = runtime::get_named_arg("wcspr");
-    let dai: Key = runtime::get_named_arg("dai");
+    let base_tokens: Vec<Key> = runtime::get_named_arg("base_tokens");
     let uniswap_v2_factory: Key = runtime::get_named_arg("uniswap_v2_factory");
     let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
     let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
@@ -52,7 +52,7 @@ fn constructor() {
     let purse: URef = runtime::get_named_arg("purse");
     Token::default().constructor(
         wcspr,
-        dai,
+        base_tokens,
         uniswap_v2_factory,
         contract_hash,
         package_hash,
@@ -64,6 +64,8 @@ fn constructor() {
 /// @param token_borrow The address of the token you want to flash-borrow, use 0x0 for ETH
 /// @param amount The amount of token_borrow you will borrow
 /// @param token_pay The address of the token you want to use to payback the flash-borrow, use 0x0 for ETH
+/// @param pair Optional pair to borrow from when token_borrow and token_pay are the same, otherwise the deepest pair is used.
+///     It must be None when they differ
 /// @param user_data Data that will be passed to the `execute` function for the user
 /// @dev Depending on your use case, you may want to add access controls to this function
 
@@ -72,8 +74,62 @@ fn start_swap() {
     let token_borrow: Key = runtime::get_named_arg("token_borrow");
     let amount: U256 = runtime::get_named_arg("amount");
     let token_pay: Key = runtime::get_named_arg("token_pay");
+    let pair: Option<Key> = runtime::get_named_arg("pair");
     let user_data: String = runtime::get_named_arg("user_data");
-    Token::default().start_swap(token_borrow, amount, token_pay, user_data);
+    Token::default().start_swap(token_borrow, amount, token_pay, pair, user_data);
+}
+
+/// @notice Flash-borrows amount of the first token of path and repays using the last token of path
+/// @param path The tokens to route through, from the token you want to flash-borrow to the token you pay back with
+/// @param amount The amount of the first token of path you will borrow
+/// @param user_data Data that will be passed to the `execute` function for the user
+/// @dev Every consecutive pair of tokens in path must have a Uniswap V2 pair
+
+#[no_mangle]
+fn start_path_swap() {
+    let path: Vec<Key> = runtime::get_named_arg("path");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let user_data: String = runtime::get_named_arg("user_data");
+    Token::default().start_path_swap(path, amount, user_data);
+}
+
+/// This function is to set the base tokens that simple flash loans may be routed through
+///
+/// # Parameters
+///
+/// * `base_tokens` - A list of Keys that holds the Hash of the base tokens
+///
+
+#[no_mangle]
+fn set_base_tokens() {
+    let base_tokens: Vec<Key> = runtime::get_named_arg("base_tokens");
+    Token::default().set_base_tokens(base_tokens);
+}
+
+/// This function is to set the protocol surcharge taken on top of the pair fee
+///
+/// # Parameters
+///
+/// * `protocol_fee` - A U256 that holds the surcharge in basis points of the repayment
+///
+
+#[no_mangle]
+fn set_protocol_fee() {
+    let protocol_fee: U256 = runtime::get_named_arg("protocol_fee");
+    Token::default().set_protocol_fee(protocol_fee);
+}
+
+/// This function is to set the treasury that receives the protocol surcharge
+///
+/// # Parameters
+///
+/// * `treasury` - A Key that holds the account address or contract hash of the treasury
+///
+
+#[no_mangle]
+fn set_treasury() {
+    let treasury: Key = runtime::get_named_arg("treasury");
+    Token::default().set_treasury(treasury);
 }
 
 /// @notice Function is called by the Uniswap V2 pair's `swap` function
@@ -101,7 +157,7 @@ fn get_entry_points() -> EntryPoints {
         "constructor",
         vec![
             Parameter::new("wcspr", Key::cl_type()),
-            Parameter::new("dai", Key::cl_type()),
+            Parameter::new("base_tokens", CLType::List(Box::new(Key::cl_type()))),
             Parameter::new("uniswap_v2_factory", Key::cl_type()),
             Parameter::new("contract_hash", ContractHash::cl_type()),
             Parameter::new("package_hash", ContractPackageHash::cl_type()),
@@ -117,12 +173,48 @@ fn get_entry_points() -> EntryPoints {
             Parameter::new("token_borrow", Key::cl_type()),
             Parameter::new("amount", U256::cl_type()),
             Parameter::new("token_pay", Key::cl_type()),
+            Parameter::new("pair", CLType::Option(Box::new(Key::cl_type()))),
             Parameter::new("user_data", String::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "start_path_swap",
+        vec![
+            Parameter::new("path", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("user_data", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_base_tokens",
+        vec![Parameter::new(
+            "base_tokens",
+            CLType::List(Box::new(Key::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_protocol_fee",
+        vec![Parameter::new("protocol_fee", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_treasury",
+        vec![Parameter::new("treasury", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "uniswap_v2_call",
         vec![
@@ -159,12 +251,12 @@ fn call() {
 
         let uniswap_v2_factory: Key = runtime::get_named_arg("uniswap_v2_factory");
         let wcspr: Key = runtime::get_named_arg("wcspr");
-        let dai: Key = runtime::get_named_arg("dai");
+        let base_tokens: Vec<Key> = runtime::get_named_arg("base_tokens");
         let purse: URef = system::create_purse();
         // Prepare constructor args
         let constructor_args = runtime_args! {
             "wcspr" => wcspr,
-            "dai" => dai,
+            "base_tokens" => base_tokens,
             "uniswap_v2_factory" => uniswap_v2_factory,
             "contract_hash" => contract_hash,
             "package_hash"=> package_hash,
@@ -0,0 +1,102 @@
+use common::{functions::zero_address, keys::*, *};
+
+/// Account each account delegates its votes to, the zero address until it delegates
+pub struct Delegates {
+    dict: Dict,
+}
+
+impl Delegates {
+    pub fn instance() -> Delegates {
+        Delegates {
+            dict: Dict::instance(DELEGATES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(DELEGATES_DICT)
+    }
+
+    pub fn get(&self, delegator: &Key) -> Key {
+        self.dict.get_by_key(delegator).unwrap_or_else(zero_address)
+    }
+
+    pub fn set(&self, delegator: &Key, delegatee: Key) {
+        self.dict.set_by_key(delegator, delegatee);
+    }
+}
+
+/// Vote checkpoints of an account by their index, each holding the block timestamp from which
+/// the account had the votes
+pub struct VoteCheckpoints {
+    dict: Dict,
+}
+
+impl VoteCheckpoints {
+    pub fn instance() -> VoteCheckpoints {
+        VoteCheckpoints {
+            dict: Dict::instance(VOTE_CHECKPOINTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(VOTE_CHECKPOINTS_DICT)
+    }
+
+    pub fn get(&self, account: &Key, index: &u32) -> (u64, U256) {
+        self.dict.get_by_keys((account, index)).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key, index: &u32, checkpoint: (u64, U256)) {
+        self.dict.set_by_keys((account, index), checkpoint);
+    }
+}
+
+/// Number of vote checkpoints recorded for an account
+pub struct VoteCheckpointCounts {
+    dict: Dict,
+}
+
+impl VoteCheckpointCounts {
+    pub fn instance() -> VoteCheckpointCounts {
+        VoteCheckpointCounts {
+            dict: Dict::instance(VOTE_CHECKPOINT_COUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(VOTE_CHECKPOINT_COUNTS_DICT)
+    }
+
+    pub fn get(&self, account: &Key) -> u32 {
+        self.dict.get_by_key(account).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key, count: u32) {
+        self.dict.set_by_key(account, count);
+    }
+}
+
+/// Nonce the next signed delegation of an account must carry
+pub struct Nonces {
+    dict: Dict,
+}
+
+impl Nonces {
+    pub fn instance() -> Nonces {
+        Nonces {
+            dict: Dict::instance(NONCES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(NONCES_DICT)
+    }
+
+    pub fn get(&self, account: &Key) -> U256 {
+        self.dict.get_by_key(account).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key, nonce: U256) {
+        self.dict.set_by_key(account, nonce);
+    }
+}
@@ -0,0 +1,32 @@
+use common::*;
+
+pub enum GOVERNANCETOKENEvent {
+    DelegateChanged {
+        delegator: Key,
+        from_delegate: Key,
+        to_delegate: Key,
+    },
+    DelegateVotesChanged {
+        delegate: Key,
+        previous_balance: U256,
+        new_balance: U256,
+    },
+}
+
+impl GOVERNANCETOKENEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            GOVERNANCETOKENEvent::DelegateChanged {
+                delegator: _,
+                from_delegate: _,
+                to_delegate: _,
+            } => "delegate_changed",
+            GOVERNANCETOKENEvent::DelegateVotesChanged {
+                delegate: _,
+                previous_balance: _,
+                new_balance: _,
+            } => "delegate_votes_changed",
+        }
+        .to_string()
+    }
+}
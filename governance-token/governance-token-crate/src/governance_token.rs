@@ -0,0 +1,251 @@
+use crate::{data::*, event::GOVERNANCETOKENEvent};
+use casper_erc20_crate::Error;
+use common::{
+    bytesrepr::ToBytes,
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::{block_timestamp, zero_address},
+    signature::{signer_account_hash, verify_signature},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use erc20_secure_crate::{data::get_package_hash, Address, ERC20};
+use std::collections::BTreeMap;
+
+/// Prefix of the digest signed for `delegate_by_sig`, so it can not be replayed as another
+/// meta transaction
+const DELEGATION_TYPEHASH: &str = "delegate_by_sig";
+
+pub trait GOVERNANCETOKEN<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC20<Storage>
+{
+    fn init(&mut self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        ERC20::init(self, contract_hash, package_hash);
+        Delegates::init();
+        VoteCheckpoints::init();
+        VoteCheckpointCounts::init();
+        Nonces::init();
+    }
+
+    fn transfer(&self, recipient: Address, amount: U256) -> Result<(), Error> {
+        let sender: Key = self.get_caller();
+        ERC20::transfer(self, recipient, amount)?;
+        self.move_delegates(
+            self.delegates(sender),
+            self.delegates(Key::from(recipient)),
+            amount,
+        );
+        Ok(())
+    }
+
+    fn transfer_from(&self, owner: Address, recipient: Address, amount: U256) -> Result<(), Error> {
+        ERC20::transfer_from(self, owner, recipient, amount)?;
+        self.move_delegates(
+            self.delegates(Key::from(owner)),
+            self.delegates(Key::from(recipient)),
+            amount,
+        );
+        Ok(())
+    }
+
+    fn mint(&self, to: Address, amount: U256) -> Result<(), Error> {
+        ERC20::mint(self, to, amount)?;
+        self.move_delegates(zero_address(), self.delegates(Key::from(to)), amount);
+        Ok(())
+    }
+
+    fn burn(&self, from: Address, amount: U256) -> Result<(), Error> {
+        ERC20::burn(self, from, amount)?;
+        self.move_delegates(self.delegates(Key::from(from)), zero_address(), amount);
+        Ok(())
+    }
+
+    fn delegates(&self, delegator: Key) -> Key {
+        Delegates::instance().get(&delegator)
+    }
+
+    /// Delegates the votes of the caller to `delegatee`
+    fn delegate(&self, delegatee: Key) {
+        self._delegate(self.get_caller(), delegatee);
+    }
+
+    /// Delegates the votes of the account of `public_key` to `delegatee`, with its ed25519
+    /// signature of `delegation_digest`
+    fn delegate_by_sig(
+        &self,
+        delegatee: Key,
+        nonce: U256,
+        expiry: u64,
+        public_key: String,
+        signature: String,
+    ) {
+        let digest: [u8; 32] = self.delegation_digest(delegatee, nonce, expiry);
+        if !verify_signature(&public_key, &signature, &digest) {
+            runtime::revert(Errors::GovernanceTokenInvalidSignature);
+        }
+        let signatory: Key = Key::from(
+            signer_account_hash(&public_key)
+                .unwrap_or_revert_with(Errors::GovernanceTokenInvalidSignature),
+        );
+        let nonces = Nonces::instance();
+        if nonce != nonces.get(&signatory) {
+            runtime::revert(Errors::GovernanceTokenInvalidNonce);
+        }
+        nonces.set(
+            &signatory,
+            nonce
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Errors::GovernanceTokenOverFlow),
+        );
+        if block_timestamp() > expiry {
+            runtime::revert(Errors::GovernanceTokenSignatureExpired);
+        }
+        self._delegate(signatory, delegatee);
+    }
+
+    /// Blake2b hash of the delegation a signer approves, bound to this token
+    fn delegation_digest(&self, delegatee: Key, nonce: U256, expiry: u64) -> [u8; 32] {
+        let mut preimage: Vec<u8> = DELEGATION_TYPEHASH
+            .to_string()
+            .to_bytes()
+            .unwrap_or_revert();
+        preimage.append(&mut get_package_hash().to_bytes().unwrap_or_revert());
+        preimage.append(&mut delegatee.to_bytes().unwrap_or_revert());
+        preimage.append(&mut nonce.to_bytes().unwrap_or_revert());
+        preimage.append(&mut expiry.to_bytes().unwrap_or_revert());
+        runtime::blake2b(preimage)
+    }
+
+    fn nonces(&self, account: Key) -> U256 {
+        Nonces::instance().get(&account)
+    }
+
+    fn num_checkpoints(&self, account: Key) -> u32 {
+        VoteCheckpointCounts::instance().get(&account)
+    }
+
+    fn get_current_votes(&self, account: Key) -> U256 {
+        let count: u32 = self.num_checkpoints(account);
+        if count == 0 {
+            return 0.into();
+        }
+        VoteCheckpoints::instance().get(&account, &(count - 1)).1
+    }
+
+    /// Votes `account` had at the end of `timestamp`, which must be before the current block
+    fn get_prior_votes(&self, account: Key, timestamp: u64) -> U256 {
+        if timestamp >= block_timestamp() {
+            runtime::revert(Errors::GovernanceTokenNotYetDetermined);
+        }
+        let count: u32 = self.num_checkpoints(account);
+        if count == 0 {
+            return 0.into();
+        }
+        let checkpoints = VoteCheckpoints::instance();
+        // the most recent and the first checkpoint answer most queries without a search
+        let (last_timestamp, last_votes) = checkpoints.get(&account, &(count - 1));
+        if last_timestamp <= timestamp {
+            return last_votes;
+        }
+        if checkpoints.get(&account, &0).0 > timestamp {
+            return 0.into();
+        }
+        let (mut lower, mut upper): (u32, u32) = (0, count - 1);
+        while upper > lower {
+            let center: u32 = upper - (upper - lower) / 2; // ceil, avoiding overflow
+            let (center_timestamp, center_votes) = checkpoints.get(&account, &center);
+            if center_timestamp == timestamp {
+                return center_votes;
+            } else if center_timestamp < timestamp {
+                lower = center;
+            } else {
+                upper = center - 1;
+            }
+        }
+        checkpoints.get(&account, &lower).1
+    }
+
+    fn _delegate(&self, delegator: Key, delegatee: Key) {
+        let current_delegate: Key = self.delegates(delegator);
+        let delegator_balance: U256 = ERC20::balance_of(self, Address::from(delegator));
+        Delegates::instance().set(&delegator, delegatee);
+        self.emit(&GOVERNANCETOKENEvent::DelegateChanged {
+            delegator,
+            from_delegate: current_delegate,
+            to_delegate: delegatee,
+        });
+        self.move_delegates(current_delegate, delegatee, delegator_balance);
+    }
+
+    fn move_delegates(&self, src_rep: Key, dst_rep: Key, amount: U256) {
+        if src_rep == dst_rep || amount.is_zero() {
+            return;
+        }
+        if src_rep != zero_address() {
+            let src_rep_old: U256 = self.get_current_votes(src_rep);
+            let src_rep_new: U256 = src_rep_old
+                .checked_sub(amount)
+                .unwrap_or_revert_with(Errors::GovernanceTokenUnderFlow);
+            self.write_checkpoint(src_rep, src_rep_old, src_rep_new);
+        }
+        if dst_rep != zero_address() {
+            let dst_rep_old: U256 = self.get_current_votes(dst_rep);
+            let dst_rep_new: U256 = dst_rep_old
+                .checked_add(amount)
+                .unwrap_or_revert_with(Errors::GovernanceTokenOverFlow);
+            self.write_checkpoint(dst_rep, dst_rep_old, dst_rep_new);
+        }
+    }
+
+    /// Records `new_votes` of `delegatee` from the current block, overwriting a checkpoint
+    /// already written in it
+    fn write_checkpoint(&self, delegatee: Key, old_votes: U256, new_votes: U256) {
+        let checkpoints = VoteCheckpoints::instance();
+        let counts = VoteCheckpointCounts::instance();
+        let timestamp: u64 = block_timestamp();
+        let count: u32 = counts.get(&delegatee);
+        if count > 0 && checkpoints.get(&delegatee, &(count - 1)).0 == timestamp {
+            checkpoints.set(&delegatee, &(count - 1), (timestamp, new_votes));
+        } else {
+            checkpoints.set(&delegatee, &count, (timestamp, new_votes));
+            counts.set(
+                &delegatee,
+                count
+                    .checked_add(1)
+                    .unwrap_or_revert_with(Errors::GovernanceTokenOverFlow),
+            );
+        }
+        self.emit(&GOVERNANCETOKENEvent::DelegateVotesChanged {
+            delegate: delegatee,
+            previous_balance: old_votes,
+            new_balance: new_votes,
+        });
+    }
+
+    fn emit(&self, governance_token_event: &GOVERNANCETOKENEvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", governance_token_event.type_name());
+        match governance_token_event {
+            GOVERNANCETOKENEvent::DelegateChanged {
+                delegator,
+                from_delegate,
+                to_delegate,
+            } => {
+                event.insert("delegator", delegator.to_string());
+                event.insert("from_delegate", from_delegate.to_string());
+                event.insert("to_delegate", to_delegate.to_string());
+            }
+            GOVERNANCETOKENEvent::DelegateVotesChanged {
+                delegate,
+                previous_balance,
+                new_balance,
+            } => {
+                event.insert("delegate", delegate.to_string());
+                event.insert("previous_balance", previous_balance.to_string());
+                event.insert("new_balance", new_balance.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod data;
+pub mod event;
+mod governance_token;
+
+pub use casperlabs_ownable::OWNABLE;
+pub use common::*;
+pub use erc20_secure_crate::{Address, ERC20};
+pub use governance_token::GOVERNANCETOKEN;
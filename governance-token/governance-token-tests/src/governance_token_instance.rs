@@ -0,0 +1,178 @@
+use cryptoxide::ed25519;
+use tests_common::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    deploys::deploy_governance_token,
+    digest::{Update, VariableOutput},
+    helpers::*,
+    keys::CALLS,
+    multicall::Call,
+    *,
+};
+
+pub struct GOVERNANCETOKENInstance(pub TestContract);
+
+impl GOVERNANCETOKENInstance {
+    pub fn new(
+        env: &TestEnv,
+        sender: AccountHash,
+        supply: U256,
+        time: u64,
+    ) -> GOVERNANCETOKENInstance {
+        GOVERNANCETOKENInstance(deploy_governance_token(
+            env,
+            "GOVERNANCE",
+            sender,
+            NAME,
+            SYMBOL,
+            DECIMALS,
+            supply,
+            time,
+        ))
+    }
+
+    pub fn transfer(&self, sender: AccountHash, recipient: Address, amount: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount,
+            },
+            time,
+        );
+    }
+
+    pub fn delegate(&self, sender: AccountHash, delegatee: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "delegate",
+            runtime_args! {
+                "delegatee" => delegatee,
+            },
+            time,
+        );
+    }
+
+    /// Signs the delegation with the ed25519 `secret_key` and submits it from `sender`
+    #[allow(clippy::too_many_arguments)]
+    pub fn delegate_by_sig(
+        &self,
+        sender: AccountHash,
+        secret_key: &[u8; 64],
+        public_key: &[u8; 32],
+        delegatee: Key,
+        nonce: U256,
+        expiry: u64,
+        time: u64,
+    ) {
+        let signature: [u8; 64] = ed25519::signature(
+            &self.delegation_digest(delegatee, nonce, expiry),
+            secret_key,
+        );
+        self.0.call_contract(
+            sender,
+            "delegate_by_sig",
+            runtime_args! {
+                "delegatee" => delegatee,
+                "nonce" => nonce,
+                "expiry" => expiry,
+                "public_key" => bytes_to_str(public_key),
+                "signature" => bytes_to_str(&signature),
+            },
+            time,
+        );
+    }
+
+    /// Digest the token expects a delegation to be signed over, computed the same way
+    pub fn delegation_digest(&self, delegatee: Key, nonce: U256, expiry: u64) -> [u8; 32] {
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update("delegate_by_sig".to_string().to_bytes().unwrap());
+        hasher.update(
+            ContractPackageHash::new(self.0.package_hash())
+                .to_bytes()
+                .unwrap(),
+        );
+        hasher.update(delegatee.to_bytes().unwrap());
+        hasher.update(nonce.to_bytes().unwrap());
+        hasher.update(expiry.to_bytes().unwrap());
+
+        let mut ret = [0u8; 32];
+        hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+        ret
+    }
+
+    pub fn delegates(&self, delegator: Key) -> Key {
+        self.0
+            .query_dictionary("delegates", key_to_str(&delegator))
+            .unwrap()
+    }
+
+    pub fn nonces(&self, account: Key) -> U256 {
+        self.0
+            .query_dictionary("nonces", key_to_str(&account))
+            .unwrap()
+    }
+
+    pub fn num_checkpoints(&self, account: Key) -> u32 {
+        self.0
+            .query_dictionary("vote_checkpoint_counts", key_to_str(&account))
+            .unwrap()
+    }
+
+    pub fn checkpoint(&self, account: Key, index: u32) -> (u64, U256) {
+        self.0
+            .query_dictionary("vote_checkpoints", key_and_index_to_str(&account, index))
+            .unwrap()
+    }
+
+    /// Reads `get_prior_votes` in a block at `time` through the multicall session code, which
+    /// keeps the result under a named key of `sender`
+    pub fn get_prior_votes(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        account: Key,
+        timestamp: u64,
+        time: u64,
+    ) -> U256 {
+        let calls: Vec<Call> = vec![Call {
+            package_hash: Key::Hash(self.0.package_hash()),
+            entry_point: "get_prior_votes".into(),
+            args: runtime_args! {
+                "account" => account,
+                "timestamp" => timestamp
+            },
+            capture: Some("prior_votes".into()),
+        }];
+        TestContract::new(
+            env,
+            "session-code-multicall.wasm",
+            "session-code-multicall",
+            sender,
+            runtime_args! {
+                CALLS => Bytes::from(calls.to_bytes().unwrap())
+            },
+            time,
+        );
+        let ret: Bytes = env.query_account_named_key(sender, &["prior_votes".into()]);
+        let (ret, _): (U256, _) = FromBytes::from_bytes(&ret).unwrap();
+        ret
+    }
+}
+
+/// Bytes as comma separated decimals, the way the token takes public keys and signatures
+pub fn bytes_to_str(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| byte.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Ed25519 key pair of `seed` with the account hash of its public key
+pub fn signer(seed: u8) -> ([u8; 64], [u8; 32], AccountHash) {
+    let (secret_key, public_key) = ed25519::keypair(&[seed; 32]);
+    let account_hash = AccountHash::from(&PublicKey::ed25519_from_bytes(public_key).unwrap());
+    (secret_key, public_key, account_hash)
+}
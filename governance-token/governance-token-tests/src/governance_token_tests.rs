@@ -0,0 +1,174 @@
+use crate::governance_token_instance::*;
+use tests_common::{account::AccountHash, *};
+
+const SUPPLY: U256 = U256([1_000_000_000_000, 0, 0, 0]);
+
+fn deploy() -> (TestEnv, AccountHash, GOVERNANCETOKENInstance) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let token = GOVERNANCETOKENInstance::new(&env, owner, SUPPLY, now());
+    (env, owner, token)
+}
+
+#[test]
+fn test_delegate_moves_votes_with_transfers() {
+    let (env, owner, token) = deploy();
+    let user = env.next_user();
+    let amount: U256 = SUPPLY / 4;
+    let time = now();
+    token.delegate(owner, Key::Account(owner), time);
+    assert_eq!(token.delegates(Key::Account(owner)), Key::Account(owner));
+    assert_eq!(token.checkpoint(Key::Account(owner), 0), (time, SUPPLY));
+    // the user has not delegated, so the votes of what it receives are not counted
+    token.transfer(owner, Address::Account(user), amount, time + 1_000);
+    assert_eq!(
+        token.checkpoint(Key::Account(owner), 1),
+        (time + 1_000, SUPPLY - amount)
+    );
+    token.delegate(user, Key::Account(owner), time + 2_000);
+    // a second change in the same block overwrites its checkpoint
+    token.0.call_contract(
+        owner,
+        "burn",
+        runtime_args! {
+            "from" => Address::Account(owner),
+            "amount" => amount,
+        },
+        time + 2_000,
+    );
+    assert_eq!(token.num_checkpoints(Key::Account(owner)), 3);
+    assert_eq!(
+        token.checkpoint(Key::Account(owner), 2),
+        (time + 2_000, SUPPLY - amount)
+    );
+}
+
+/// Gives the owner four vote checkpoints, 1,000 ms apart from `time`, each a quarter of the supply
+/// below the one before
+fn write_checkpoints(
+    env: &TestEnv,
+    owner: AccountHash,
+    token: &GOVERNANCETOKENInstance,
+    time: u64,
+) {
+    let user = env.next_user();
+    let amount: U256 = SUPPLY / 4;
+    token.delegate(owner, Key::Account(owner), time);
+    for i in 1..4 {
+        token.transfer(owner, Address::Account(user), amount, time + i * 1_000);
+    }
+    assert_eq!(token.num_checkpoints(Key::Account(owner)), 4);
+}
+
+#[test]
+fn test_get_prior_votes() {
+    let (env, owner, token) = deploy();
+    let amount: U256 = SUPPLY / 4;
+    let time = now();
+    write_checkpoints(&env, owner, &token, time);
+    let prior_votes = |timestamp: u64| {
+        token.get_prior_votes(&env, owner, Key::Account(owner), timestamp, time + 10_000)
+    };
+    // before the first checkpoint
+    assert_eq!(prior_votes(time - 1), 0.into());
+    // exactly on checkpoints, found by the search or the first and last shortcuts
+    assert_eq!(prior_votes(time), SUPPLY);
+    assert_eq!(prior_votes(time + 1_000), SUPPLY - amount);
+    assert_eq!(prior_votes(time + 2_000), SUPPLY - amount * 2);
+    assert_eq!(prior_votes(time + 3_000), SUPPLY - amount * 3);
+    // between two checkpoints, the votes of the earlier one
+    assert_eq!(prior_votes(time + 500), SUPPLY);
+    assert_eq!(prior_votes(time + 1_500), SUPPLY - amount);
+    assert_eq!(prior_votes(time + 2_500), SUPPLY - amount * 2);
+    // after the last checkpoint
+    assert_eq!(prior_votes(time + 5_000), SUPPLY - amount * 3);
+    // an account without checkpoints
+    assert_eq!(
+        token.get_prior_votes(
+            &env,
+            owner,
+            Key::Account(env.next_user()),
+            time,
+            time + 10_000
+        ),
+        0.into()
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(163)")] // As the votes of the current block can still change
+fn test_get_prior_votes_not_yet_determined() {
+    let (env, owner, token) = deploy();
+    let time = now();
+    write_checkpoints(&env, owner, &token, time);
+    token.get_prior_votes(
+        &env,
+        owner,
+        Key::Account(owner),
+        time + 10_000,
+        time + 10_000,
+    );
+}
+
+#[test]
+fn test_delegate_by_sig() {
+    let (env, owner, token) = deploy();
+    let relayer = env.next_user();
+    let (secret_key, public_key, signer) = signer(1);
+    let amount: U256 = SUPPLY / 4;
+    let time = now();
+    token.transfer(owner, Address::Account(signer), amount, time);
+    token.delegate_by_sig(
+        relayer,
+        &secret_key,
+        &public_key,
+        Key::Account(relayer),
+        0.into(),
+        time + 10_000,
+        time + 1_000,
+    );
+    assert_eq!(token.delegates(Key::Account(signer)), Key::Account(relayer));
+    assert_eq!(token.nonces(Key::Account(signer)), 1.into());
+    assert_eq!(
+        token.checkpoint(Key::Account(relayer), 0),
+        (time + 1_000, amount)
+    );
+}
+
+#[test]
+#[should_panic] // As the nonce was used by the first delegation
+fn test_delegate_by_sig_replayed() {
+    let (env, _, token) = deploy();
+    let relayer = env.next_user();
+    let (secret_key, public_key, _) = signer(1);
+    let time = now();
+    for _ in 0..2 {
+        token.delegate_by_sig(
+            relayer,
+            &secret_key,
+            &public_key,
+            Key::Account(relayer),
+            0.into(),
+            time + 10_000,
+            time,
+        );
+    }
+}
+
+#[test]
+#[should_panic] // As the signature expired
+fn test_delegate_by_sig_expired() {
+    let (env, _, token) = deploy();
+    let relayer = env.next_user();
+    let (secret_key, public_key, _) = signer(1);
+    let time = now();
+    token.delegate_by_sig(
+        relayer,
+        &secret_key,
+        &public_key,
+        Key::Account(relayer),
+        0.into(),
+        time,
+        time + 1_000,
+    );
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod governance_token_tests;
+
+#[cfg(test)]
+pub mod governance_token_instance;
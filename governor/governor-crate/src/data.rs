@@ -0,0 +1,142 @@
+use common::{functions::zero_address, keys::*, proposal::Proposal, *};
+
+pub fn set_timelock(timelock: Key) {
+    set_key(TIMELOCK, timelock);
+}
+
+pub fn get_timelock() -> Key {
+    get_key(TIMELOCK).unwrap_or_else(zero_address)
+}
+
+pub fn set_governance_token(governance_token: Key) {
+    set_key(GOVERNANCE_TOKEN, governance_token);
+}
+
+pub fn get_governance_token() -> Key {
+    get_key(GOVERNANCE_TOKEN).unwrap_or_else(zero_address)
+}
+
+pub fn set_guardian(guardian: Key) {
+    set_key(GUARDIAN, guardian);
+}
+
+pub fn get_guardian() -> Key {
+    get_key(GUARDIAN).unwrap_or_else(zero_address)
+}
+
+pub fn set_voting_delay(voting_delay: u64) {
+    set_key(VOTING_DELAY, voting_delay);
+}
+
+pub fn get_voting_delay() -> u64 {
+    get_key(VOTING_DELAY).unwrap_or_default()
+}
+
+pub fn set_voting_period(voting_period: u64) {
+    set_key(VOTING_PERIOD, voting_period);
+}
+
+pub fn get_voting_period() -> u64 {
+    get_key(VOTING_PERIOD).unwrap_or_default()
+}
+
+pub fn set_proposal_threshold(proposal_threshold: U256) {
+    set_key(PROPOSAL_THRESHOLD, proposal_threshold);
+}
+
+pub fn get_proposal_threshold() -> U256 {
+    get_key(PROPOSAL_THRESHOLD).unwrap_or_default()
+}
+
+pub fn set_quorum_votes(quorum_votes: U256) {
+    set_key(QUORUM_VOTES, quorum_votes);
+}
+
+pub fn get_quorum_votes() -> U256 {
+    get_key(QUORUM_VOTES).unwrap_or_default()
+}
+
+pub fn set_proposal_count(proposal_count: U256) {
+    set_key(PROPOSAL_COUNT, proposal_count);
+}
+
+pub fn get_proposal_count() -> U256 {
+    get_key(PROPOSAL_COUNT).unwrap_or_default()
+}
+
+/// Proposals by their id, as a decimal string
+pub struct Proposals {
+    dict: Dict,
+}
+
+impl Proposals {
+    pub fn instance() -> Proposals {
+        Proposals {
+            dict: Dict::instance(PROPOSALS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(PROPOSALS_DICT)
+    }
+
+    pub fn get(&self, proposal_id: U256) -> Option<Proposal> {
+        self.dict.get(&proposal_id.to_string())
+    }
+
+    pub fn set(&self, proposal_id: U256, proposal: Proposal) {
+        self.dict.set(&proposal_id.to_string(), proposal);
+    }
+}
+
+/// Id of the latest proposal of each proposer, 0 before its first one
+pub struct LatestProposalIds {
+    dict: Dict,
+}
+
+impl LatestProposalIds {
+    pub fn instance() -> LatestProposalIds {
+        LatestProposalIds {
+            dict: Dict::instance(LATEST_PROPOSAL_IDS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(LATEST_PROPOSAL_IDS_DICT)
+    }
+
+    pub fn get(&self, proposer: &Key) -> U256 {
+        self.dict.get_by_key(proposer).unwrap_or_default()
+    }
+
+    pub fn set(&self, proposer: &Key, proposal_id: U256) {
+        self.dict.set_by_key(proposer, proposal_id);
+    }
+}
+
+/// Vote of each voter on each proposal, as (has_voted, support, votes)
+pub struct Receipts {
+    dict: Dict,
+}
+
+impl Receipts {
+    pub fn instance() -> Receipts {
+        Receipts {
+            dict: Dict::instance(RECEIPTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(RECEIPTS_DICT)
+    }
+
+    pub fn get(&self, proposal_id: &U256, voter: &Key) -> (bool, bool, U256) {
+        self.dict
+            .get_by_keys((proposal_id, voter))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, proposal_id: &U256, voter: &Key, receipt: (bool, bool, U256)) {
+        self.dict.set_by_keys((proposal_id, voter), receipt);
+    }
+}
@@ -0,0 +1,54 @@
+use common::*;
+
+pub enum GOVERNOREvent {
+    ProposalCreated {
+        proposal_id: U256,
+        proposer: Key,
+        start_time: u64,
+        end_time: u64,
+        description: String,
+    },
+    VoteCast {
+        voter: Key,
+        proposal_id: U256,
+        support: bool,
+        votes: U256,
+    },
+    ProposalCanceled {
+        proposal_id: U256,
+    },
+    ProposalQueued {
+        proposal_id: U256,
+        eta: u64,
+    },
+    ProposalExecuted {
+        proposal_id: U256,
+    },
+}
+
+impl GOVERNOREvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            GOVERNOREvent::ProposalCreated {
+                proposal_id: _,
+                proposer: _,
+                start_time: _,
+                end_time: _,
+                description: _,
+            } => "proposal_created",
+            GOVERNOREvent::VoteCast {
+                voter: _,
+                proposal_id: _,
+                support: _,
+                votes: _,
+            } => "vote_cast",
+            GOVERNOREvent::ProposalCanceled { proposal_id: _ } => "proposal_canceled",
+            GOVERNOREvent::ProposalQueued {
+                proposal_id: _,
+                eta: _,
+            } => "proposal_queued",
+            GOVERNOREvent::ProposalExecuted { proposal_id: _ } => "proposal_executed",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{data::*, event::GOVERNOREvent};
+use common::{
+    bytesrepr::{Bytes, FromBytes},
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::block_timestamp,
+    proposal::*,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+/// Most calls a single proposal can make
+pub const PROPOSAL_MAX_OPERATIONS: usize = 10;
+
+pub trait GOVERNOR<Storage: ContractStorage>: ContractContext<Storage> {
+    #[allow(clippy::too_many_arguments)]
+    fn init(
+        &self,
+        timelock: Key,
+        governance_token: Key,
+        guardian: Key,
+        voting_delay: u64,
+        voting_period: u64,
+        proposal_threshold: U256,
+        quorum_votes: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        set_timelock(timelock);
+        set_governance_token(governance_token);
+        set_guardian(guardian);
+        set_voting_delay(voting_delay);
+        set_voting_period(voting_period);
+        set_proposal_threshold(proposal_threshold);
+        set_quorum_votes(quorum_votes);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+        Proposals::init();
+        LatestProposalIds::init();
+        Receipts::init();
+    }
+
+    fn only_guardian(&self) {
+        if self.get_caller() != get_guardian() {
+            runtime::revert(Errors::GovernorNotGuardian);
+        }
+    }
+
+    /// Proposes calls for the timelock to make, each an `entry_point` of a `target` package with
+    /// `args`, serialized `RuntimeArgs`. The caller needs more votes than the proposal threshold
+    /// and no other pending or active proposal
+    fn propose(
+        &self,
+        targets: Vec<Key>,
+        entry_points: Vec<String>,
+        args: Vec<Bytes>,
+        description: String,
+    ) -> U256 {
+        let proposer: Key = self.get_caller();
+        let now: u64 = block_timestamp();
+        if self.prior_votes(proposer, now.saturating_sub(1)) <= get_proposal_threshold() {
+            runtime::revert(Errors::GovernorBelowThreshold);
+        }
+        if targets.is_empty()
+            || targets.len() > PROPOSAL_MAX_OPERATIONS
+            || targets.len() != entry_points.len()
+            || targets.len() != args.len()
+        {
+            runtime::revert(Errors::GovernorInvalidActions);
+        }
+        let latest_proposal_ids = LatestProposalIds::instance();
+        let latest_proposal_id: U256 = latest_proposal_ids.get(&proposer);
+        if !latest_proposal_id.is_zero() {
+            let latest_state: u8 = self.state(latest_proposal_id);
+            if latest_state == PROPOSAL_STATE_PENDING || latest_state == PROPOSAL_STATE_ACTIVE {
+                runtime::revert(Errors::GovernorLiveProposal);
+            }
+        }
+        let start_time: u64 = now
+            .checked_add(get_voting_delay())
+            .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        let end_time: u64 = start_time
+            .checked_add(get_voting_period())
+            .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        let proposal_id: U256 = get_proposal_count()
+            .checked_add(1.into())
+            .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        Proposals::instance().set(
+            proposal_id,
+            Proposal {
+                proposer,
+                targets,
+                entry_points,
+                args,
+                eta: 0,
+                start_time,
+                end_time,
+                for_votes: 0.into(),
+                against_votes: 0.into(),
+                canceled: false,
+                executed: false,
+            },
+        );
+        set_proposal_count(proposal_id);
+        latest_proposal_ids.set(&proposer, proposal_id);
+        self.emit(&GOVERNOREvent::ProposalCreated {
+            proposal_id,
+            proposer,
+            start_time,
+            end_time,
+            description,
+        });
+        proposal_id
+    }
+
+    /// Votes for or against an active proposal, with the votes the caller had when it started
+    fn cast_vote(&self, proposal_id: U256, support: bool) {
+        if self.state(proposal_id) != PROPOSAL_STATE_ACTIVE {
+            runtime::revert(Errors::GovernorInvalidState);
+        }
+        let voter: Key = self.get_caller();
+        let receipts = Receipts::instance();
+        if receipts.get(&proposal_id, &voter).0 {
+            runtime::revert(Errors::GovernorAlreadyVoted);
+        }
+        let mut proposal: Proposal = self.get_proposal(proposal_id);
+        let votes: U256 = self.prior_votes(voter, proposal.start_time);
+        if support {
+            proposal.for_votes = proposal
+                .for_votes
+                .checked_add(votes)
+                .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        } else {
+            proposal.against_votes = proposal
+                .against_votes
+                .checked_add(votes)
+                .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        }
+        Proposals::instance().set(proposal_id, proposal);
+        receipts.set(&proposal_id, &voter, (true, support, votes));
+        self.emit(&GOVERNOREvent::VoteCast {
+            voter,
+            proposal_id,
+            support,
+            votes,
+        });
+    }
+
+    /// Queues the calls of a succeeded proposal in the timelock, executable after its delay
+    fn queue(&self, proposal_id: U256) {
+        if self.state(proposal_id) != PROPOSAL_STATE_SUCCEEDED {
+            runtime::revert(Errors::GovernorInvalidState);
+        }
+        let timelock: Key = get_timelock();
+        let delay: u64 = runtime::call_versioned_contract(
+            timelock.into_hash().unwrap_or_revert().into(),
+            None,
+            "delay",
+            runtime_args! {},
+        );
+        let eta: u64 = block_timestamp()
+            .checked_add(delay)
+            .unwrap_or_revert_with(Errors::GovernorOverFlow);
+        let mut proposal: Proposal = self.get_proposal(proposal_id);
+        for i in 0..proposal.targets.len() {
+            let _: Key = self.call_timelock(
+                "queue_transaction",
+                proposal.targets[i],
+                proposal.entry_points[i].clone(),
+                proposal.args[i].clone(),
+                eta,
+            );
+        }
+        proposal.eta = eta;
+        Proposals::instance().set(proposal_id, proposal);
+        self.emit(&GOVERNOREvent::ProposalQueued { proposal_id, eta });
+    }
+
+    /// Has the timelock execute the calls of a queued proposal, once its eta is reached
+    fn execute(&self, proposal_id: U256) {
+        if self.state(proposal_id) != PROPOSAL_STATE_QUEUED {
+            runtime::revert(Errors::GovernorInvalidState);
+        }
+        let mut proposal: Proposal = self.get_proposal(proposal_id);
+        proposal.executed = true;
+        Proposals::instance().set(proposal_id, proposal.clone());
+        for i in 0..proposal.targets.len() {
+            let _: Bytes = self.call_timelock(
+                "execute_transaction",
+                proposal.targets[i],
+                proposal.entry_points[i].clone(),
+                proposal.args[i].clone(),
+                proposal.eta,
+            );
+        }
+        self.emit(&GOVERNOREvent::ProposalExecuted { proposal_id });
+    }
+
+    /// Cancels a proposal not yet executed, by the guardian, or by anyone once its proposer
+    /// holds no more votes than the proposal threshold
+    fn cancel(&self, proposal_id: U256) {
+        if self.state(proposal_id) == PROPOSAL_STATE_EXECUTED {
+            runtime::revert(Errors::GovernorInvalidState);
+        }
+        let mut proposal: Proposal = self.get_proposal(proposal_id);
+        if self.get_caller() != get_guardian()
+            && self.prior_votes(proposal.proposer, block_timestamp().saturating_sub(1))
+                > get_proposal_threshold()
+        {
+            runtime::revert(Errors::GovernorProposerAboveThreshold);
+        }
+        proposal.canceled = true;
+        Proposals::instance().set(proposal_id, proposal.clone());
+        if proposal.eta != 0 {
+            for i in 0..proposal.targets.len() {
+                let _: Key = self.call_timelock(
+                    "cancel_transaction",
+                    proposal.targets[i],
+                    proposal.entry_points[i].clone(),
+                    proposal.args[i].clone(),
+                    proposal.eta,
+                );
+            }
+        }
+        self.emit(&GOVERNOREvent::ProposalCanceled { proposal_id });
+    }
+
+    /// Takes over as admin of the timelock, once it names the governor its pending admin
+    fn accept_admin(&self) {
+        self.only_guardian();
+        let () = runtime::call_versioned_contract(
+            get_timelock().into_hash().unwrap_or_revert().into(),
+            None,
+            "accept_admin",
+            runtime_args! {},
+        );
+    }
+
+    fn state(&self, proposal_id: U256) -> u8 {
+        if proposal_id.is_zero() || proposal_id > get_proposal_count() {
+            runtime::revert(Errors::GovernorProposalNotFound);
+        }
+        let proposal: Proposal = self.get_proposal(proposal_id);
+        let now: u64 = block_timestamp();
+        if proposal.canceled {
+            PROPOSAL_STATE_CANCELED
+        } else if now <= proposal.start_time {
+            PROPOSAL_STATE_PENDING
+        } else if now <= proposal.end_time {
+            PROPOSAL_STATE_ACTIVE
+        } else if proposal.for_votes <= proposal.against_votes
+            || proposal.for_votes < get_quorum_votes()
+        {
+            PROPOSAL_STATE_DEFEATED
+        } else if proposal.eta == 0 {
+            PROPOSAL_STATE_SUCCEEDED
+        } else if proposal.executed {
+            PROPOSAL_STATE_EXECUTED
+        } else if now >= proposal.eta.saturating_add(self.grace_period()) {
+            PROPOSAL_STATE_EXPIRED
+        } else {
+            PROPOSAL_STATE_QUEUED
+        }
+    }
+
+    fn get_proposal(&self, proposal_id: U256) -> Proposal {
+        Proposals::instance()
+            .get(proposal_id)
+            .unwrap_or_revert_with(Errors::GovernorProposalNotFound)
+    }
+
+    fn get_receipt(&self, proposal_id: U256, voter: Key) -> (bool, bool, U256) {
+        Receipts::instance().get(&proposal_id, &voter)
+    }
+
+    fn prior_votes(&self, account: Key, timestamp: u64) -> U256 {
+        runtime::call_versioned_contract(
+            get_governance_token().into_hash().unwrap_or_revert().into(),
+            None,
+            "get_prior_votes",
+            runtime_args! {
+                "account" => account,
+                "timestamp" => timestamp
+            },
+        )
+    }
+
+    fn grace_period(&self) -> u64 {
+        runtime::call_versioned_contract(
+            get_timelock().into_hash().unwrap_or_revert().into(),
+            None,
+            "grace_period",
+            runtime_args! {},
+        )
+    }
+
+    fn call_timelock<T: CLTyped + FromBytes>(
+        &self,
+        entry_point: &str,
+        target: Key,
+        target_entry_point: String,
+        args: Bytes,
+        eta: u64,
+    ) -> T {
+        runtime::call_versioned_contract(
+            get_timelock().into_hash().unwrap_or_revert().into(),
+            None,
+            entry_point,
+            runtime_args! {
+                "target" => target,
+                "entry_point" => target_entry_point,
+                "args" => args,
+                "eta" => eta
+            },
+        )
+    }
+
+    fn emit(&self, governor_event: &GOVERNOREvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", governor_event.type_name());
+        match governor_event {
+            GOVERNOREvent::ProposalCreated {
+                proposal_id,
+                proposer,
+                start_time,
+                end_time,
+                description,
+            } => {
+                event.insert("proposal_id", proposal_id.to_string());
+                event.insert("proposer", proposer.to_string());
+                event.insert("start_time", start_time.to_string());
+                event.insert("end_time", end_time.to_string());
+                event.insert("description", description.to_string());
+            }
+            GOVERNOREvent::VoteCast {
+                voter,
+                proposal_id,
+                support,
+                votes,
+            } => {
+                event.insert("voter", voter.to_string());
+                event.insert("proposal_id", proposal_id.to_string());
+                event.insert("support", support.to_string());
+                event.insert("votes", votes.to_string());
+            }
+            GOVERNOREvent::ProposalCanceled { proposal_id }
+            | GOVERNOREvent::ProposalExecuted { proposal_id } => {
+                event.insert("proposal_id", proposal_id.to_string());
+            }
+            GOVERNOREvent::ProposalQueued { proposal_id, eta } => {
+                event.insert("proposal_id", proposal_id.to_string());
+                event.insert("eta", eta.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
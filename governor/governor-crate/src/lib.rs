@@ -0,0 +1,6 @@
+pub mod data;
+pub mod event;
+mod governor;
+
+pub use common::*;
+pub use governor::GOVERNOR;
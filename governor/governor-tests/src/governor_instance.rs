@@ -0,0 +1,107 @@
+use tests_common::{
+    account::AccountHash, bytesrepr::Bytes, deploys::deploy_governor, proposal::Proposal, *,
+};
+
+pub struct GOVERNORInstance(pub TestContract);
+
+impl GOVERNORInstance {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        env: &TestEnv,
+        sender: AccountHash,
+        timelock: Key,
+        governance_token: Key,
+        voting_delay: u64,
+        voting_period: u64,
+        proposal_threshold: U256,
+        quorum_votes: U256,
+        time: u64,
+    ) -> GOVERNORInstance {
+        GOVERNORInstance(deploy_governor(
+            env,
+            sender,
+            timelock,
+            governance_token,
+            voting_delay,
+            voting_period,
+            proposal_threshold,
+            quorum_votes,
+            time,
+        ))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    pub fn propose(
+        &self,
+        sender: AccountHash,
+        targets: Vec<Key>,
+        entry_points: Vec<String>,
+        args: Vec<Bytes>,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "propose",
+            runtime_args! {
+                "targets" => targets,
+                "entry_points" => entry_points,
+                "args" => args,
+                "description" => String::from("proposal"),
+            },
+            time,
+        );
+    }
+
+    pub fn cast_vote(&self, sender: AccountHash, proposal_id: U256, support: bool, time: u64) {
+        self.0.call_contract(
+            sender,
+            "cast_vote",
+            runtime_args! {
+                "proposal_id" => proposal_id,
+                "support" => support,
+            },
+            time,
+        );
+    }
+
+    fn call_proposal(&self, sender: AccountHash, entry_point: &str, proposal_id: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            entry_point,
+            runtime_args! {
+                "proposal_id" => proposal_id,
+            },
+            time,
+        );
+    }
+
+    pub fn queue(&self, sender: AccountHash, proposal_id: U256, time: u64) {
+        self.call_proposal(sender, "queue", proposal_id, time);
+    }
+
+    pub fn execute(&self, sender: AccountHash, proposal_id: U256, time: u64) {
+        self.call_proposal(sender, "execute", proposal_id, time);
+    }
+
+    pub fn cancel(&self, sender: AccountHash, proposal_id: U256, time: u64) {
+        self.call_proposal(sender, "cancel", proposal_id, time);
+    }
+
+    pub fn accept_admin(&self, sender: AccountHash, time: u64) {
+        self.0
+            .call_contract(sender, "accept_admin", runtime_args! {}, time);
+    }
+
+    pub fn proposal_count(&self) -> U256 {
+        self.0.query_named_key(String::from("proposal_count"))
+    }
+
+    pub fn proposal(&self, proposal_id: U256) -> Proposal {
+        self.0
+            .query_dictionary("proposals", proposal_id.to_string())
+            .unwrap()
+    }
+}
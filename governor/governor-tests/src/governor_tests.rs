@@ -0,0 +1,199 @@
+use crate::governor_instance::*;
+use tests_common::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    deploys::*,
+    *,
+};
+
+const SUPPLY: U256 = U256([1_000_000_000_000, 0, 0, 0]);
+const THRESHOLD: U256 = U256([10_000_000_000, 0, 0, 0]);
+const QUORUM: U256 = U256([400_000_000_000, 0, 0, 0]);
+const DELAY: u64 = 172_800_000;
+const VOTING_DELAY: u64 = 1_000;
+const VOTING_PERIOD: u64 = 60_000;
+
+/// Deploys a governor guarded by the owner that administers the fee settings of a factory through
+/// a timelock, with all the votes of the governance token delegated to the owner
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    GOVERNORInstance,
+    TestContract,
+    TestContract,
+    u64,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let time = now();
+    let token = deploy_governance_token(
+        &env,
+        "GOVERNANCE",
+        owner,
+        NAME,
+        SYMBOL,
+        DECIMALS,
+        SUPPLY,
+        time,
+    );
+    token.call_contract(
+        owner,
+        "delegate",
+        runtime_args! {
+            "delegatee" => Key::Account(owner)
+        },
+        time,
+    );
+    let timelock = deploy_timelock(&env, owner, Key::Account(owner), DELAY, time);
+    let governor = GOVERNORInstance::new(
+        &env,
+        owner,
+        Key::Hash(timelock.package_hash()),
+        Key::Hash(token.package_hash()),
+        VOTING_DELAY,
+        VOTING_PERIOD,
+        THRESHOLD,
+        QUORUM,
+        time,
+    );
+    timelock.call_contract(
+        owner,
+        "set_pending_admin",
+        runtime_args! {
+            "pending_admin" => governor.package_hash()
+        },
+        time,
+    );
+    governor.accept_admin(owner, time);
+    let factory = deploy_factory(&env, owner, Key::Hash(timelock.package_hash()), time);
+    (env, owner, governor, factory, token, time)
+}
+
+/// Proposes to set the fee recipient of the factory to `fee_to`
+fn propose_set_fee_to(
+    governor: &GOVERNORInstance,
+    sender: AccountHash,
+    factory: &TestContract,
+    fee_to: Key,
+    time: u64,
+) {
+    let args = runtime_args! {
+        "fee_to" => fee_to
+    };
+    governor.propose(
+        sender,
+        vec![Key::Hash(factory.package_hash())],
+        vec!["set_fee_to".to_string()],
+        vec![Bytes::from(args.to_bytes().unwrap())],
+        time,
+    );
+}
+
+#[test]
+fn test_governor_proposal_sets_factory_fee_to() {
+    let (env, owner, governor, factory, _, time) = deploy();
+    let fee_to = Key::Account(env.next_user());
+    propose_set_fee_to(&governor, owner, &factory, fee_to, time + 1);
+    let proposal_id: U256 = governor.proposal_count();
+    assert_eq!(proposal_id, 1.into());
+    let proposal = governor.proposal(proposal_id);
+    assert_eq!(proposal.start_time, time + 1 + VOTING_DELAY);
+    governor.cast_vote(owner, proposal_id, true, proposal.start_time + 1);
+    assert_eq!(governor.proposal(proposal_id).for_votes, SUPPLY);
+    let queued_at = proposal.end_time + 1;
+    governor.queue(owner, proposal_id, queued_at);
+    assert_eq!(governor.proposal(proposal_id).eta, queued_at + DELAY);
+    governor.execute(owner, proposal_id, queued_at + DELAY);
+    assert!(governor.proposal(proposal_id).executed);
+    let ret: Key = factory.query_named_key(String::from("fee_to"));
+    assert_eq!(ret, fee_to);
+}
+
+#[test]
+#[should_panic]
+fn test_governor_propose_below_threshold() {
+    let (env, _, governor, factory, _, time) = deploy();
+    let user = env.next_user();
+    propose_set_fee_to(&governor, user, &factory, Key::Account(user), time + 1);
+}
+
+#[test]
+#[should_panic]
+fn test_governor_queue_defeated() {
+    let (env, owner, governor, factory, _, time) = deploy();
+    let fee_to = Key::Account(env.next_user());
+    propose_set_fee_to(&governor, owner, &factory, fee_to, time + 1);
+    let proposal_id: U256 = governor.proposal_count();
+    governor.cast_vote(owner, proposal_id, false, time + 1 + VOTING_DELAY + 1);
+    let end_time: u64 = governor.proposal(proposal_id).end_time;
+    governor.queue(owner, proposal_id, end_time + 1);
+}
+
+/// Proposes to set the factory fee recipient and queues the proposal with all the votes for it.
+/// Returns the proposal id and the time it was queued at
+fn propose_and_queue(
+    env: &TestEnv,
+    owner: AccountHash,
+    governor: &GOVERNORInstance,
+    factory: &TestContract,
+    time: u64,
+) -> (U256, u64) {
+    propose_set_fee_to(
+        governor,
+        owner,
+        factory,
+        Key::Account(env.next_user()),
+        time + 1,
+    );
+    let proposal_id: U256 = governor.proposal_count();
+    let proposal = governor.proposal(proposal_id);
+    governor.cast_vote(owner, proposal_id, true, proposal.start_time + 1);
+    let queued_at = proposal.end_time + 1;
+    governor.queue(owner, proposal_id, queued_at);
+    (proposal_id, queued_at)
+}
+
+#[test]
+fn test_governor_cancel_by_guardian() {
+    let (env, owner, governor, factory, _, time) = deploy();
+    let (proposal_id, queued_at) = propose_and_queue(&env, owner, &governor, &factory, time);
+    // the proposer still holds every vote, only the guardian can cancel
+    governor.cancel(owner, proposal_id, queued_at + 1);
+    let proposal = governor.proposal(proposal_id);
+    assert!(proposal.canceled);
+    assert!(!proposal.executed);
+}
+
+#[test]
+#[should_panic(expected = "User(180)")] // As a canceled proposal can no longer be executed
+fn test_governor_execute_canceled() {
+    let (env, owner, governor, factory, _, time) = deploy();
+    let (proposal_id, queued_at) = propose_and_queue(&env, owner, &governor, &factory, time);
+    governor.cancel(owner, proposal_id, queued_at + 1);
+    governor.execute(owner, proposal_id, queued_at + DELAY);
+}
+
+#[test]
+fn test_governor_cancel_proposer_below_threshold() {
+    let (env, owner, governor, factory, token, time) = deploy();
+    let (proposal_id, queued_at) = propose_and_queue(&env, owner, &governor, &factory, time);
+    // once the proposer's votes drop to the threshold anyone can cancel
+    token.call_contract(
+        owner,
+        "delegate",
+        runtime_args! {
+            "delegatee" => Key::Account(env.next_user())
+        },
+        queued_at + 1,
+    );
+    governor.cancel(env.next_user(), proposal_id, queued_at + 2);
+    assert!(governor.proposal(proposal_id).canceled);
+}
+
+#[test]
+#[should_panic(expected = "User(194)")] // As the proposer still holds more votes than the threshold
+fn test_governor_cancel_proposer_above_threshold() {
+    let (env, owner, governor, factory, _, time) = deploy();
+    let (proposal_id, queued_at) = propose_and_queue(&env, owner, &governor, &factory, time);
+    governor.cancel(env.next_user(), proposal_id, queued_at + 1);
+}
@@ -0,0 +1,430 @@
+#![no_main]
+
+use governor_crate::{
+    bytesrepr::Bytes,
+    contract_api::{runtime, storage},
+    data::*,
+    proposal::Proposal,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct Governor(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for Governor {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl GOVERNOR<OnChainContractStorage> for Governor {}
+
+impl Governor {
+    #[allow(clippy::too_many_arguments)]
+    fn constructor(
+        &self,
+        timelock: Key,
+        governance_token: Key,
+        guardian: Key,
+        voting_delay: u64,
+        voting_period: u64,
+        proposal_threshold: U256,
+        quorum_votes: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        GOVERNOR::init(
+            self,
+            timelock,
+            governance_token,
+            guardian,
+            voting_delay,
+            voting_period,
+            proposal_threshold,
+            quorum_votes,
+            contract_hash,
+            package_hash,
+        );
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let timelock: Key = runtime::get_named_arg("timelock");
+    let governance_token: Key = runtime::get_named_arg("governance_token");
+    let guardian: Key = runtime::get_named_arg("guardian");
+    let voting_delay: u64 = runtime::get_named_arg("voting_delay");
+    let voting_period: u64 = runtime::get_named_arg("voting_period");
+    let proposal_threshold: U256 = runtime::get_named_arg("proposal_threshold");
+    let quorum_votes: U256 = runtime::get_named_arg("quorum_votes");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Governor::default().constructor(
+        timelock,
+        governance_token,
+        guardian,
+        voting_delay,
+        voting_period,
+        proposal_threshold,
+        quorum_votes,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// This function is to propose calls for the timelock to make, the caller needs more votes than the proposal threshold
+///
+/// # Parameters
+///
+/// * `targets` - A Vec<Key> that holds the package hashes of the contracts called
+///
+/// * `entry_points` - A Vec<String> that holds the name of the entry point called on each target
+///
+/// * `args` - A Vec<Bytes> that holds the serialized RuntimeArgs of each call
+///
+/// * `description` - A String that holds the description of the proposal
+///
+
+#[no_mangle]
+fn propose() {
+    let targets: Vec<Key> = runtime::get_named_arg("targets");
+    let entry_points: Vec<String> = runtime::get_named_arg("entry_points");
+    let args: Vec<Bytes> = runtime::get_named_arg("args");
+    let description: String = runtime::get_named_arg("description");
+    let ret: U256 = Governor::default().propose(targets, entry_points, args, description);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to vote on an active proposal, with the votes the caller had when the voting started
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+/// * `support` - A bool that holds whether the vote is for the proposal
+///
+
+#[no_mangle]
+fn cast_vote() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    let support: bool = runtime::get_named_arg("support");
+    Governor::default().cast_vote(proposal_id, support);
+}
+
+/// This function is to queue the calls of a succeeded proposal in the timelock
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+
+#[no_mangle]
+fn queue() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    Governor::default().queue(proposal_id);
+}
+
+/// This function is to have the timelock execute the calls of a queued proposal once its eta is reached
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+
+#[no_mangle]
+fn execute() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    Governor::default().execute(proposal_id);
+}
+
+/// This function is to cancel a proposal not yet executed, by the guardian or once its proposer falls to the proposal threshold
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+
+#[no_mangle]
+fn cancel() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    Governor::default().cancel(proposal_id);
+}
+
+/// This function is to take over as admin of the timelock once it names the governor its pending admin, only the guardian can call it
+///
+
+#[no_mangle]
+fn accept_admin() {
+    Governor::default().accept_admin();
+}
+
+/// This function is to return the state of a proposal, one of the PROPOSAL_STATE_* constants
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+
+#[no_mangle]
+fn state() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    runtime::ret(CLValue::from_t(Governor::default().state(proposal_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn get_proposal() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    let ret: Proposal = Governor::default().get_proposal(proposal_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the vote of a voter on a proposal, as (has_voted, support, votes)
+///
+/// # Parameters
+///
+/// * `proposal_id` - A U256 that holds the id of the proposal
+///
+/// * `voter` - A Key that holds the account or package hash of the voter
+///
+
+#[no_mangle]
+fn get_receipt() {
+    let proposal_id: U256 = runtime::get_named_arg("proposal_id");
+    let voter: Key = runtime::get_named_arg("voter");
+    let ret: (bool, bool, U256) = Governor::default().get_receipt(proposal_id, voter);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn proposal_count() {
+    runtime::ret(CLValue::from_t(get_proposal_count()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn timelock() {
+    runtime::ret(CLValue::from_t(get_timelock()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn guardian() {
+    runtime::ret(CLValue::from_t(get_guardian()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("timelock", Key::cl_type()),
+            Parameter::new("governance_token", Key::cl_type()),
+            Parameter::new("guardian", Key::cl_type()),
+            Parameter::new("voting_delay", u64::cl_type()),
+            Parameter::new("voting_period", u64::cl_type()),
+            Parameter::new("proposal_threshold", U256::cl_type()),
+            Parameter::new("quorum_votes", U256::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "propose",
+        vec![
+            Parameter::new("targets", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new("entry_points", CLType::List(Box::new(String::cl_type()))),
+            Parameter::new("args", CLType::List(Box::new(Bytes::cl_type()))),
+            Parameter::new("description", String::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cast_vote",
+        vec![
+            Parameter::new("proposal_id", U256::cl_type()),
+            Parameter::new("support", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queue",
+        vec![Parameter::new("proposal_id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute",
+        vec![Parameter::new("proposal_id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel",
+        vec![Parameter::new("proposal_id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "state",
+        vec![Parameter::new("proposal_id", U256::cl_type())],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_proposal",
+        vec![Parameter::new("proposal_id", U256::cl_type())],
+        Proposal::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_receipt",
+        vec![
+            Parameter::new("proposal_id", U256::cl_type()),
+            Parameter::new("voter", Key::cl_type()),
+        ],
+        CLType::Tuple3([
+            Box::new(bool::cl_type()),
+            Box::new(bool::cl_type()),
+            Box::new(U256::cl_type()),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_admin",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "proposal_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "timelock",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "guardian",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let timelock: Key = runtime::get_named_arg("timelock");
+        let governance_token: Key = runtime::get_named_arg("governance_token");
+        let guardian: Key = runtime::get_named_arg("guardian");
+        let voting_delay: u64 = runtime::get_named_arg("voting_delay");
+        let voting_period: u64 = runtime::get_named_arg("voting_period");
+        let proposal_threshold: U256 = runtime::get_named_arg("proposal_threshold");
+        let quorum_votes: U256 = runtime::get_named_arg("quorum_votes");
+
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "timelock" => timelock,
+            "governance_token" => governance_token,
+            "guardian" => guardian,
+            "voting_delay" => voting_delay,
+            "voting_period" => voting_period,
+            "proposal_threshold" => proposal_threshold,
+            "quorum_votes" => quorum_votes,
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
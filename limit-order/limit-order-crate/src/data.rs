@@ -0,0 +1,34 @@
+use common::{keys::*, order::Order, *};
+
+/// Orders by their id, as a decimal string
+pub struct Orders {
+    dict: Dict,
+}
+
+impl Orders {
+    pub fn instance() -> Orders {
+        Orders {
+            dict: Dict::instance(ORDERS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ORDERS_DICT)
+    }
+
+    pub fn get(&self, order_id: U256) -> Option<Order> {
+        self.dict.get(&order_id.to_string())
+    }
+
+    pub fn set(&self, order_id: U256, order: Order) {
+        self.dict.set(&order_id.to_string(), order);
+    }
+}
+
+pub fn set_orders_count(orders_count: U256) {
+    set_key(ORDERS_COUNT, orders_count);
+}
+
+pub fn get_orders_count() -> U256 {
+    get_key(ORDERS_COUNT).unwrap_or_default()
+}
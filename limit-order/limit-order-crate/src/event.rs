@@ -0,0 +1,56 @@
+use common::*;
+
+pub enum LIMITORDEREvent {
+    OrderPlaced {
+        order_id: U256,
+        owner: Key,
+        pair: Key,
+        token_in: Key,
+        amount_in: U256,
+        amount_out_min: U256,
+        tip: U256,
+        deadline: U256,
+    },
+    OrderExecuted {
+        order_id: U256,
+        keeper: Key,
+        amount_in: U256,
+        amount_out: U256,
+        tip: U256,
+    },
+    OrderCancelled {
+        order_id: U256,
+        owner: Key,
+        refund: U256,
+    },
+}
+
+impl LIMITORDEREvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            LIMITORDEREvent::OrderPlaced {
+                order_id: _,
+                owner: _,
+                pair: _,
+                token_in: _,
+                amount_in: _,
+                amount_out_min: _,
+                tip: _,
+                deadline: _,
+            } => "order_placed",
+            LIMITORDEREvent::OrderExecuted {
+                order_id: _,
+                keeper: _,
+                amount_in: _,
+                amount_out: _,
+                tip: _,
+            } => "order_executed",
+            LIMITORDEREvent::OrderCancelled {
+                order_id: _,
+                owner: _,
+                refund: _,
+            } => "order_cancelled",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod data;
+pub mod event;
+mod limit_order;
+
+pub use common::*;
+pub use limit_order::LIMITORDER;
@@ -0,0 +1,270 @@
+use crate::{data::*, event::LIMITORDEREvent};
+use common::{
+    calls::{reserves, tokens, transfer, transfer_from},
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::block_timestamp,
+    order::*,
+    quote::get_pair_amount_out,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+pub trait LIMITORDER<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+        Orders::init();
+    }
+
+    /// Pulls `amount_in` plus `tip` of `token_in` from the caller and opens an order selling
+    /// `amount_in` through `pair` for at least `amount_out_min` of the other token
+    fn place_order(
+        &self,
+        pair: Key,
+        token_in: Key,
+        amount_in: U256,
+        amount_out_min: U256,
+        tip: U256,
+        deadline: U256,
+    ) -> U256 {
+        if amount_in.is_zero() || amount_out_min.is_zero() {
+            runtime::revert(Errors::LimitOrderInvalidAmount);
+        }
+        if deadline < U256::from(block_timestamp()) {
+            runtime::revert(Errors::LimitOrderExpired);
+        }
+        let owner: Key = self.get_caller();
+        let (token0, token1) = tokens(pair);
+        let token_out: Key = if token_in == token0 {
+            token1
+        } else if token_in == token1 {
+            token0
+        } else {
+            runtime::revert(Errors::LimitOrderInvalidToken);
+        };
+        transfer_from(
+            token_in,
+            owner,
+            Key::from(get_package_hash()),
+            amount_in
+                .checked_add(tip)
+                .unwrap_or_revert_with(Errors::LimitOrderOverFlow),
+        );
+        let order_id: U256 = get_orders_count();
+        set_orders_count(
+            order_id
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Errors::LimitOrderOverFlow),
+        );
+        Orders::instance().set(
+            order_id,
+            Order {
+                owner,
+                pair,
+                token_in,
+                token_out,
+                amount_in,
+                amount_out_min,
+                tip,
+                deadline,
+                filled: 0.into(),
+                status: ORDER_STATUS_OPEN,
+            },
+        );
+        self.emit(&LIMITORDEREvent::OrderPlaced {
+            order_id,
+            owner,
+            pair,
+            token_in,
+            amount_in,
+            amount_out_min,
+            tip,
+            deadline,
+        });
+        order_id
+    }
+
+    /// Swaps `amount_in` of what is left of the order through its pair, sending the output to the
+    /// order owner and the matching share of the tip to the caller. Reverts unless the pair pays
+    /// at least the limit price for it
+    fn execute_order(&self, order_id: U256, amount_in: U256) -> U256 {
+        let mut order: Order = self.get_order(order_id);
+        if order.status != ORDER_STATUS_OPEN {
+            runtime::revert(Errors::LimitOrderNotOpen);
+        }
+        if order.deadline < U256::from(block_timestamp()) {
+            runtime::revert(Errors::LimitOrderExpired);
+        }
+        let remaining: U256 = order
+            .amount_in
+            .checked_sub(order.filled)
+            .unwrap_or_revert_with(Errors::LimitOrderUnderFlow);
+        if amount_in.is_zero() || amount_in > remaining {
+            runtime::revert(Errors::LimitOrderInvalidAmount);
+        }
+        let (token0, _) = tokens(order.pair);
+        let zero_for_one: bool = order.token_in == token0;
+        let (reserve_in, reserve_out) = reserves(order.pair, zero_for_one);
+        let amount_out: U256 =
+            get_pair_amount_out(order.pair, zero_for_one, amount_in, reserve_in, reserve_out);
+        // the limit price applied to the part filled, rounded up in favour of the owner
+        let amount_out_min: U256 = (order
+            .amount_out_min
+            .checked_mul(amount_in)
+            .unwrap_or_revert_with(Errors::LimitOrderOverFlow)
+            + order.amount_in
+            - 1)
+            / order.amount_in;
+        if amount_out < amount_out_min {
+            runtime::revert(Errors::LimitOrderPriceNotReached);
+        }
+        let filled: U256 = order
+            .filled
+            .checked_add(amount_in)
+            .unwrap_or_revert_with(Errors::LimitOrderOverFlow);
+        let tip: U256 = self
+            .tip_share(&order, filled)
+            .checked_sub(self.tip_share(&order, order.filled))
+            .unwrap_or_revert_with(Errors::LimitOrderUnderFlow);
+        order.filled = filled;
+        if filled == order.amount_in {
+            order.status = ORDER_STATUS_FILLED;
+        }
+        Orders::instance().set(order_id, order.clone());
+        let (amount0_out, amount1_out): (U256, U256) = if zero_for_one {
+            (0.into(), amount_out)
+        } else {
+            (amount_out, 0.into())
+        };
+        transfer(order.token_in, order.pair, amount_in);
+        let () = runtime::call_versioned_contract(
+            order.pair.into_hash().unwrap_or_revert().into(),
+            None,
+            "swap",
+            runtime_args! {
+                "amount0_out" => amount0_out,
+                "amount1_out" => amount1_out,
+                "to" => order.owner,
+                "data" => ""
+            },
+        );
+        let keeper: Key = self.get_caller();
+        transfer(order.token_in, keeper, tip);
+        self.emit(&LIMITORDEREvent::OrderExecuted {
+            order_id,
+            keeper,
+            amount_in,
+            amount_out,
+            tip,
+        });
+        amount_out
+    }
+
+    /// Closes an open or expired order of the caller and refunds what is left of its deposit,
+    /// unfilled amount and unpaid tip
+    fn cancel_order(&self, order_id: U256) -> U256 {
+        let mut order: Order = self.get_order(order_id);
+        if self.get_caller() != order.owner {
+            runtime::revert(Errors::LimitOrderForbidden);
+        }
+        if order.status != ORDER_STATUS_OPEN {
+            runtime::revert(Errors::LimitOrderNotOpen);
+        }
+        let refund: U256 = order
+            .amount_in
+            .checked_sub(order.filled)
+            .unwrap_or_revert_with(Errors::LimitOrderUnderFlow)
+            .checked_add(
+                order
+                    .tip
+                    .checked_sub(self.tip_share(&order, order.filled))
+                    .unwrap_or_revert_with(Errors::LimitOrderUnderFlow),
+            )
+            .unwrap_or_revert_with(Errors::LimitOrderOverFlow);
+        order.status = ORDER_STATUS_CANCELLED;
+        Orders::instance().set(order_id, order.clone());
+        transfer(order.token_in, order.owner, refund);
+        self.emit(&LIMITORDEREvent::OrderCancelled {
+            order_id,
+            owner: order.owner,
+            refund,
+        });
+        refund
+    }
+
+    fn get_order(&self, order_id: U256) -> Order {
+        Orders::instance()
+            .get(order_id)
+            .unwrap_or_revert_with(Errors::LimitOrderNotFound)
+    }
+
+    /// Status of the order, `ORDER_STATUS_EXPIRED` for an open order past its deadline
+    fn order_status(&self, order_id: U256) -> u8 {
+        let order: Order = self.get_order(order_id);
+        if order.status == ORDER_STATUS_OPEN && order.deadline < U256::from(block_timestamp()) {
+            return ORDER_STATUS_EXPIRED;
+        }
+        order.status
+    }
+
+    /// Part of the tip earned once `filled` of the order is swapped
+    fn tip_share(&self, order: &Order, filled: U256) -> U256 {
+        order
+            .tip
+            .checked_mul(filled)
+            .unwrap_or_revert_with(Errors::LimitOrderOverFlow)
+            / order.amount_in
+    }
+
+    fn emit(&self, limit_order_event: &LIMITORDEREvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", limit_order_event.type_name());
+        match limit_order_event {
+            LIMITORDEREvent::OrderPlaced {
+                order_id,
+                owner,
+                pair,
+                token_in,
+                amount_in,
+                amount_out_min,
+                tip,
+                deadline,
+            } => {
+                event.insert("order_id", order_id.to_string());
+                event.insert("owner", owner.to_string());
+                event.insert("pair", pair.to_string());
+                event.insert("token_in", token_in.to_string());
+                event.insert("amount_in", amount_in.to_string());
+                event.insert("amount_out_min", amount_out_min.to_string());
+                event.insert("tip", tip.to_string());
+                event.insert("deadline", deadline.to_string());
+            }
+            LIMITORDEREvent::OrderExecuted {
+                order_id,
+                keeper,
+                amount_in,
+                amount_out,
+                tip,
+            } => {
+                event.insert("order_id", order_id.to_string());
+                event.insert("keeper", keeper.to_string());
+                event.insert("amount_in", amount_in.to_string());
+                event.insert("amount_out", amount_out.to_string());
+                event.insert("tip", tip.to_string());
+            }
+            LIMITORDEREvent::OrderCancelled {
+                order_id,
+                owner,
+                refund,
+            } => {
+                event.insert("order_id", order_id.to_string());
+                event.insert("owner", owner.to_string());
+                event.insert("refund", refund.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
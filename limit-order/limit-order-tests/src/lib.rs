@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod limit_order_tests;
+
+#[cfg(test)]
+pub mod limit_order_instance;
@@ -0,0 +1,73 @@
+use tests_common::{account::AccountHash, deploys::deploy_limit_order, order::Order, *};
+
+pub struct LIMITORDERInstance(pub TestContract);
+
+impl LIMITORDERInstance {
+    pub fn new(env: &TestEnv, sender: AccountHash, time: u64) -> LIMITORDERInstance {
+        LIMITORDERInstance(deploy_limit_order(env, sender, time))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_order(
+        &self,
+        sender: AccountHash,
+        pair: Key,
+        token_in: Key,
+        amount_in: U256,
+        amount_out_min: U256,
+        tip: U256,
+        deadline: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "place_order",
+            runtime_args! {
+                "pair" => pair,
+                "token_in" => token_in,
+                "amount_in" => amount_in,
+                "amount_out_min" => amount_out_min,
+                "tip" => tip,
+                "deadline" => deadline,
+            },
+            time,
+        );
+    }
+
+    pub fn execute_order(&self, sender: AccountHash, order_id: U256, amount_in: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "execute_order",
+            runtime_args! {
+                "order_id" => order_id,
+                "amount_in" => amount_in,
+            },
+            time,
+        );
+    }
+
+    pub fn cancel_order(&self, sender: AccountHash, order_id: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "cancel_order",
+            runtime_args! {
+                "order_id" => order_id,
+            },
+            time,
+        );
+    }
+
+    pub fn order(&self, order_id: U256) -> Order {
+        self.0
+            .query_dictionary("orders", order_id.to_string())
+            .unwrap()
+    }
+
+    pub fn orders_count(&self) -> U256 {
+        self.0.query_named_key("orders_count".into())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::limit_order_instance::LIMITORDERInstance;
+use tests_common::{account::AccountHash, deploys::*, helpers::*, order::*, *};
+
+const ORDER_AMOUNT: u128 = 1_000_000_000;
+const TIP: u128 = 10_000_000;
+
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    LIMITORDERInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    deploy_with(|env, owner, callee_package_hash, factory_hash| {
+        deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+    })
+}
+
+/// Creates the pair `deploy_pair` deploys with liquidity and gives a user ORDER_AMOUNT plus TIP of
+/// its token0, approved to the limit order
+fn deploy_with(
+    deploy_pair: impl FnOnce(&TestEnv, AccountHash, Key, Key) -> TestContract,
+) -> (
+    TestEnv,
+    AccountHash,
+    LIMITORDERInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let (env, owner, _, pair, token_a, token_b) = deploy_pair_with_liquidity(deploy_pair);
+    let user = env.next_user();
+    let limit_order = LIMITORDERInstance::new(&env, owner, now());
+    token_a.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(user),
+            "amount" => U256::from(ORDER_AMOUNT + TIP)
+        },
+        now(),
+    );
+    token_a.call_contract(
+        user,
+        "approve",
+        runtime_args! {
+            "spender" => Address::Contract(limit_order.0.package_hash().into()),
+            "amount" => U256::from(ORDER_AMOUNT + TIP)
+        },
+        now(),
+    );
+    (env, user, limit_order, token_a, token_b, pair)
+}
+
+/// Places an order of the user selling ORDER_AMOUNT of token a for at least `amount_out_min`
+fn place_order(
+    user: AccountHash,
+    limit_order: &LIMITORDERInstance,
+    token_a: &TestContract,
+    pair: &TestContract,
+    amount_out_min: U256,
+) {
+    limit_order.place_order(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token_a.package_hash()),
+        ORDER_AMOUNT.into(),
+        amount_out_min,
+        TIP.into(),
+        deadline(),
+        now(),
+    );
+}
+
+fn balance(token: &TestContract, owner: AccountHash) -> U256 {
+    token.query(BALANCES, address_to_str(&Address::Account(owner)))
+}
+
+fn deadline() -> U256 {
+    (now() + 60_000).into()
+}
+
+#[test]
+fn test_place_order() {
+    let (_, user, limit_order, token_a, token_b, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 980_000_000.into());
+    assert_eq!(limit_order.orders_count(), 1.into());
+    let order: Order = limit_order.order(0.into());
+    assert_eq!(order.owner, Key::Account(user));
+    assert_eq!(order.token_out, Key::Hash(token_b.package_hash()));
+    assert_eq!(order.filled, 0.into());
+    assert_eq!(order.status, ORDER_STATUS_OPEN);
+    assert_eq!(balance(&token_a, user), 0.into());
+}
+
+#[test]
+fn test_execute_order() {
+    let (env, user, limit_order, token_a, token_b, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 980_000_000.into());
+    let keeper = env.next_user();
+    limit_order.execute_order(keeper, 0.into(), ORDER_AMOUNT.into(), now());
+    // the pair quotes 987,158,034 for 1,000,000,000, above the limit of 980,000,000
+    assert_eq!(balance(&token_b, user), 987_158_034.into());
+    assert_eq!(balance(&token_a, keeper), TIP.into());
+    let order: Order = limit_order.order(0.into());
+    assert_eq!(order.filled, ORDER_AMOUNT.into());
+    assert_eq!(order.status, ORDER_STATUS_FILLED);
+}
+
+#[test]
+fn test_execute_order_on_stable_pair() {
+    let (env, user, limit_order, token_a, token_b, pair) =
+        deploy_with(|env, owner, callee_package_hash, factory_hash| {
+            deploy_stable_pair(
+                env,
+                "PAIR",
+                owner,
+                callee_package_hash,
+                factory_hash,
+                100.into(),
+                now(),
+            )
+        });
+    // a limit the constant product quote of 987,158,034 would never reach
+    place_order(user, &limit_order, &token_a, &pair, 990_000_000.into());
+    limit_order.execute_order(env.next_user(), 0.into(), ORDER_AMOUNT.into(), now());
+    assert_eq!(balance(&token_b, user), 996_950_544.into());
+    assert_eq!(limit_order.order(0.into()).status, ORDER_STATUS_FILLED);
+}
+
+#[test]
+fn test_execute_order_partially_and_cancel() {
+    let (env, user, limit_order, token_a, token_b, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 980_000_000.into());
+    let keeper = env.next_user();
+    limit_order.execute_order(keeper, 0.into(), (ORDER_AMOUNT / 2).into(), now());
+    assert_eq!(balance(&token_b, user), 496_027_303.into());
+    assert_eq!(balance(&token_a, keeper), (TIP / 2).into());
+    let order: Order = limit_order.order(0.into());
+    assert_eq!(order.filled, (ORDER_AMOUNT / 2).into());
+    assert_eq!(order.status, ORDER_STATUS_OPEN);
+    limit_order.cancel_order(user, 0.into(), now());
+    // the unfilled half and the unpaid half of the tip
+    assert_eq!(balance(&token_a, user), ((ORDER_AMOUNT + TIP) / 2).into());
+    assert_eq!(limit_order.order(0.into()).status, ORDER_STATUS_CANCELLED);
+}
+
+#[test]
+#[should_panic] // As the pair pays less than the limit price
+fn test_execute_order_price_not_reached() {
+    let (env, user, limit_order, token_a, _, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 990_000_000.into());
+    limit_order.execute_order(env.next_user(), 0.into(), ORDER_AMOUNT.into(), now());
+}
+
+#[test]
+#[should_panic] // As the order is past its deadline
+fn test_execute_order_expired() {
+    let (env, user, limit_order, token_a, _, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 980_000_000.into());
+    limit_order.execute_order(
+        env.next_user(),
+        0.into(),
+        ORDER_AMOUNT.into(),
+        now() + 120_000,
+    );
+}
+
+#[test]
+#[should_panic] // As only the owner of an order can cancel it
+fn test_cancel_order_of_someone_else() {
+    let (env, user, limit_order, token_a, _, pair) = deploy();
+    place_order(user, &limit_order, &token_a, &pair, 980_000_000.into());
+    limit_order.cancel_order(env.next_user(), 0.into(), now());
+}
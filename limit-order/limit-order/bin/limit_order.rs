@@ -0,0 +1,279 @@
+#![no_main]
+
+use limit_order_crate::{
+    contract_api::{runtime, storage},
+    data::get_orders_count,
+    order::Order,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct LimitOrder(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for LimitOrder {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl LIMITORDER<OnChainContractStorage> for LimitOrder {}
+
+impl LimitOrder {
+    fn constructor(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        LIMITORDER::init(self, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    LimitOrder::default().constructor(contract_hash, package_hash);
+}
+
+/// This function is to open an order selling token_in through a pair once its price reaches the limit
+///
+/// # Parameters
+///
+/// * `pair` - A Key that holds the Hash of the pair the order is executed against
+///
+/// * `token_in` - A Key that holds the Hash of the token sold, one of the pair's tokens
+///
+/// * `amount_in` - A U256 that holds the amount of token_in sold, pulled from the caller with the tip, both have to be approved to the contract first
+///
+/// * `amount_out_min` - A U256 that holds the least amount of the other token for the whole amount_in, which sets the limit price
+///
+/// * `tip` - A U256 that holds the amount of token_in paid to keepers, pro rata to what they fill
+///
+/// * `deadline` - A U256 that holds the block time in milliseconds after which the order can't be executed
+///
+
+#[no_mangle]
+fn place_order() {
+    let pair: Key = runtime::get_named_arg("pair");
+    let token_in: Key = runtime::get_named_arg("token_in");
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let amount_out_min: U256 = runtime::get_named_arg("amount_out_min");
+    let tip: U256 = runtime::get_named_arg("tip");
+    let deadline: U256 = runtime::get_named_arg("deadline");
+    let ret: U256 =
+        LimitOrder::default().place_order(pair, token_in, amount_in, amount_out_min, tip, deadline);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to fill an order, in part or in full, once the pair pays at least its limit price
+///
+/// # Parameters
+///
+/// * `order_id` - A U256 that holds the id of the order
+///
+/// * `amount_in` - A U256 that holds the amount of the order's token_in to swap, at most what is left unfilled
+///
+
+#[no_mangle]
+fn execute_order() {
+    let order_id: U256 = runtime::get_named_arg("order_id");
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let ret: U256 = LimitOrder::default().execute_order(order_id, amount_in);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to cancel an order of the caller and refund what is left of its deposit
+///
+/// # Parameters
+///
+/// * `order_id` - A U256 that holds the id of the order
+///
+
+#[no_mangle]
+fn cancel_order() {
+    let order_id: U256 = runtime::get_named_arg("order_id");
+    let ret: U256 = LimitOrder::default().cancel_order(order_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return an order with its amounts, deadline and fill
+///
+/// # Parameters
+///
+/// * `order_id` - A U256 that holds the id of the order
+///
+
+#[no_mangle]
+fn get_order() {
+    let order_id: U256 = runtime::get_named_arg("order_id");
+    let ret: Order = LimitOrder::default().get_order(order_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the status of an order, 0 open, 1 filled, 2 cancelled or 3 expired
+///
+/// # Parameters
+///
+/// * `order_id` - A U256 that holds the id of the order
+///
+
+#[no_mangle]
+fn order_status() {
+    let order_id: U256 = runtime::get_named_arg("order_id");
+    let ret: u8 = LimitOrder::default().order_status(order_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the number of orders ever placed, the next order id
+///
+
+#[no_mangle]
+fn orders_count() {
+    runtime::ret(CLValue::from_t(get_orders_count()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "place_order",
+        vec![
+            Parameter::new("pair", Key::cl_type()),
+            Parameter::new("token_in", Key::cl_type()),
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("amount_out_min", U256::cl_type()),
+            Parameter::new("tip", U256::cl_type()),
+            Parameter::new("deadline", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute_order",
+        vec![
+            Parameter::new("order_id", U256::cl_type()),
+            Parameter::new("amount_in", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_order",
+        vec![Parameter::new("order_id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_order",
+        vec![Parameter::new("order_id", U256::cl_type())],
+        Order::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "order_status",
+        vec![Parameter::new("order_id", U256::cl_type())],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "orders_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
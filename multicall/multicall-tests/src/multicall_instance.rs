@@ -0,0 +1,20 @@
+use tests_common::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    keys::*,
+    multicall::Call,
+    runtime_args, *,
+};
+
+pub fn call(env: &TestEnv, sender: AccountHash, calls: Vec<Call>, time: u64) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-multicall.wasm",
+        "session-code-multicall",
+        sender,
+        runtime_args! {
+            CALLS => Bytes::from(calls.to_bytes().unwrap())
+        },
+        time,
+    )
+}
@@ -0,0 +1,100 @@
+use crate::multicall_instance::call;
+use tests_common::{
+    account::AccountHash, bytesrepr::Bytes, deploys::*, helpers::*, multicall::Call, *,
+};
+
+fn deploy() -> (TestEnv, AccountHash, TestContract) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let token = deploy_erc20(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        DECIMALS,
+        INIT_TOTAL_SUPPLY,
+        now(),
+    );
+    (env, owner, token)
+}
+
+#[test]
+fn test_multicall_batch() {
+    let (env, owner, token) = deploy();
+    let user = env.next_user();
+    let amount: U256 = 123_000_000_000u64.into();
+    let package_hash = Key::Hash(token.package_hash());
+    call(
+        &env,
+        owner,
+        vec![
+            Call {
+                package_hash,
+                entry_point: "mint".into(),
+                args: runtime_args! {
+                    "to" => Address::Account(owner),
+                    "amount" => amount
+                },
+                capture: None,
+            },
+            Call {
+                package_hash,
+                entry_point: "transfer".into(),
+                args: runtime_args! {
+                    "recipient" => Address::Account(user),
+                    "amount" => amount
+                },
+                capture: None,
+            },
+            Call {
+                package_hash,
+                entry_point: "balance_of".into(),
+                args: runtime_args! {
+                    "address" => Address::Account(user)
+                },
+                capture: Some("user_balance".into()),
+            },
+        ],
+        now(),
+    );
+    let ret: U256 = token.query(BALANCES, address_to_str(&Address::Account(user)));
+    assert_eq!(ret, amount);
+    let ret: Bytes = env.query_account_named_key(owner, &["user_balance".into()]);
+    let (ret, _): (U256, _) = bytesrepr::FromBytes::from_bytes(&ret).unwrap();
+    assert_eq!(ret, amount);
+}
+
+#[test]
+#[should_panic]
+fn test_multicall_reverts_whole_batch() {
+    let (env, owner, token) = deploy();
+    let user = env.next_user();
+    let amount: U256 = 123_000_000_000u64.into();
+    let package_hash = Key::Hash(token.package_hash());
+    call(
+        &env,
+        owner,
+        vec![
+            Call {
+                package_hash,
+                entry_point: "mint".into(),
+                args: runtime_args! {
+                    "to" => Address::Account(owner),
+                    "amount" => amount
+                },
+                capture: None,
+            },
+            Call {
+                package_hash,
+                entry_point: "transfer".into(),
+                args: runtime_args! {
+                    "recipient" => Address::Account(user),
+                    "amount" => INIT_TOTAL_SUPPLY + amount + 1
+                },
+                capture: None,
+            },
+        ],
+        now(),
+    );
+}
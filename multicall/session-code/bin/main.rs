@@ -0,0 +1,30 @@
+#![no_main]
+
+use common::{
+    bytesrepr::{self, Bytes},
+    contract_api::{runtime, storage},
+    errors::Errors,
+    keys::*,
+    multicall::{Call, RawBytes},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+
+#[no_mangle]
+fn call() {
+    let calls: Bytes = runtime::get_named_arg(CALLS);
+    let calls: Vec<Call> =
+        bytesrepr::deserialize(calls.into()).unwrap_or_revert_with(Errors::MulticallInvalidCalls);
+    // every call runs in this one deploy, so a revert in any of them reverts the whole batch
+    for call in calls {
+        let ret: RawBytes = runtime::call_versioned_contract(
+            call.package_hash.into_hash().unwrap_or_revert().into(),
+            None,
+            &call.entry_point,
+            call.args,
+        );
+        if let Some(name) = call.capture {
+            runtime::put_key(&name, storage::new_uref(Bytes::from(ret.0)).into());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+#![no_main]
+
+//! Test receiver for the pair `flash_loan`, it records the loan it was called back with and
+//! pays it back with the fee when `data` is `repay`
+
+use common::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+
+#[derive(Default)]
+struct FlashLoanReceiver(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for FlashLoanReceiver {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl FlashLoanReceiver {
+    fn on_flash_loan(&self, initiator: Key, token: Key, amount: U256, fee: U256, data: String) {
+        set_key("initiator", initiator);
+        set_key("amount", amount);
+        set_key("fee", fee);
+        if data == "repay" {
+            () = runtime::call_versioned_contract(
+                token.into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(self.get_caller()),
+                    "amount" => amount.checked_add(fee).unwrap_or_revert()
+                },
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn on_flash_loan() {
+    let initiator: Key = runtime::get_named_arg("initiator");
+    let token: Key = runtime::get_named_arg("token");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let fee: U256 = runtime::get_named_arg("fee");
+    let data: String = runtime::get_named_arg("data");
+    FlashLoanReceiver::default().on_flash_loan(initiator, token, amount, fee, data);
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "on_flash_loan",
+        vec![
+            Parameter::new("initiator", Key::cl_type()),
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("fee", U256::cl_type()),
+            Parameter::new("data", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let (package_hash, access_token) = storage::create_contract_package_at_hash();
+    let (contract_hash, _) =
+        storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+    runtime::put_key(
+        &format!("{}_package_hash", contract_name),
+        package_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_package_access_token", contract_name),
+        access_token.into(),
+    );
+}
@@ -1,9 +1,11 @@
 use common::{
     functions::{account_zero_address, zero_address},
     keys::*,
+    pair_info::PAIR_TYPE_CONSTANT_PRODUCT,
     unwrap_or_revert::UnwrapOrRevert,
     *,
 };
+use std::collections::BTreeMap;
 
 pub fn set_factory_hash(factory_hash: Key) {
     set_key(FACTORY, factory_hash);
@@ -85,14 +87,6 @@ pub fn get_k_last() -> U256 {
     get_key(K_LAST).unwrap_or_revert()
 }
 
-pub fn set_treasury_fee(treasury_fee: U256) {
-    set_key(TREASURY_FEE, treasury_fee);
-}
-
-pub fn get_treasury_fee() -> U256 {
-    get_key(TREASURY_FEE).unwrap_or_revert()
-}
-
 pub fn set_minimum_liquidity(minimum_liquidity: U256) {
     set_key(MINIMUM_LIQUIDITY, minimum_liquidity);
 }
@@ -160,3 +154,134 @@ pub fn set_owner(owner: Key) {
 pub fn get_owner() -> Key {
     get_key(OWNER).unwrap_or_else(account_zero_address)
 }
+
+/// Overwrites the LP token metadata the erc20 named keys were created with
+pub fn set_lp_metadata(name: String, symbol: String, decimals: u8) {
+    set_key(ERC20_NAME, name);
+    set_key(ERC20_SYMBOL, symbol);
+    set_key(ERC20_DECIMALS, decimals);
+}
+
+pub fn set_pair_type(pair_type: u8) {
+    set_key(PAIR_TYPE, pair_type);
+}
+
+pub fn get_pair_type() -> u8 {
+    get_key(PAIR_TYPE).unwrap_or(PAIR_TYPE_CONSTANT_PRODUCT)
+}
+
+pub fn set_amplification(amplification: U256) {
+    set_key(AMPLIFICATION, amplification);
+}
+
+pub fn get_amplification() -> U256 {
+    get_key(AMPLIFICATION).unwrap_or_default()
+}
+
+pub fn set_weight0(weight0: U256) {
+    set_key(WEIGHT0, weight0);
+}
+
+pub fn get_weight0() -> U256 {
+    get_key(WEIGHT0).unwrap_or_default()
+}
+
+pub fn set_checkpoints_enabled_at(checkpoints_enabled_at: u64) {
+    set_key(CHECKPOINTS_ENABLED_AT, checkpoints_enabled_at);
+}
+
+pub fn get_checkpoints_enabled_at() -> u64 {
+    get_key(CHECKPOINTS_ENABLED_AT).unwrap_or_default()
+}
+
+/// Balance checkpoints of an account by their index, each holding a block timestamp and the
+/// balance the account had before its first change at that timestamp
+pub struct BalanceCheckpoints {
+    dict: Dict,
+}
+
+impl BalanceCheckpoints {
+    pub fn instance() -> BalanceCheckpoints {
+        BalanceCheckpoints {
+            dict: Dict::instance(BALANCE_CHECKPOINTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(BALANCE_CHECKPOINTS_DICT)
+    }
+
+    pub fn get(&self, account: &Key, index: &u32) -> (u64, U256) {
+        self.dict.get_by_keys((account, index)).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key, index: &u32, checkpoint: (u64, U256)) {
+        self.dict.set_by_keys((account, index), checkpoint);
+    }
+}
+
+/// Number of balance checkpoints recorded for an account
+pub struct BalanceCheckpointCounts {
+    dict: Dict,
+}
+
+impl BalanceCheckpointCounts {
+    pub fn instance() -> BalanceCheckpointCounts {
+        BalanceCheckpointCounts {
+            dict: Dict::instance(BALANCE_CHECKPOINT_COUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(BALANCE_CHECKPOINT_COUNTS_DICT)
+    }
+
+    pub fn get(&self, account: &Key) -> u32 {
+        self.dict.get_by_key(account).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key, count: u32) {
+        self.dict.set_by_key(account, count);
+    }
+}
+
+/// Total supply checkpoints by their index, as a decimal string, laid out like the balance ones
+pub struct TotalSupplyCheckpoints {
+    dict: Dict,
+}
+
+impl TotalSupplyCheckpoints {
+    pub fn instance() -> TotalSupplyCheckpoints {
+        TotalSupplyCheckpoints {
+            dict: Dict::instance(TOTAL_SUPPLY_CHECKPOINTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOTAL_SUPPLY_CHECKPOINTS_DICT)
+    }
+
+    pub fn get(&self, index: &u32) -> (u64, U256) {
+        self.dict.get(&index.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, index: &u32, checkpoint: (u64, U256)) {
+        self.dict.set(&index.to_string(), checkpoint);
+    }
+}
+
+pub fn set_total_supply_checkpoint_count(count: u32) {
+    set_key(TOTAL_SUPPLY_CHECKPOINT_COUNT, count);
+}
+
+pub fn get_total_supply_checkpoint_count() -> u32 {
+    get_key(TOTAL_SUPPLY_CHECKPOINT_COUNT).unwrap_or_default()
+}
+
+pub fn set_last_flash_loan(last_flash_loan: BTreeMap<String, String>) {
+    set_key(LAST_FLASH_LOAN, last_flash_loan);
+}
+
+pub fn get_last_flash_loan() -> BTreeMap<String, String> {
+    get_key(LAST_FLASH_LOAN).unwrap_or_default()
+}
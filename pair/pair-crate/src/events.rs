@@ -29,6 +29,14 @@ pub enum PAIREvent {
         reserve1: U128,
         pair: Key,
     },
+    FlashLoan {
+        initiator: Key,
+        receiver: Key,
+        token: Key,
+        amount: U256,
+        fee: U256,
+        pair: Key,
+    },
 }
 
 impl PAIREvent {
@@ -62,6 +70,14 @@ impl PAIREvent {
                 reserve1: _,
                 pair: _,
             } => "sync",
+            PAIREvent::FlashLoan {
+                initiator: _,
+                receiver: _,
+                token: _,
+                amount: _,
+                fee: _,
+                pair: _,
+            } => "flash_loan",
         }
         .to_string()
     }
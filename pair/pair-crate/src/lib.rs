@@ -3,5 +3,8 @@ mod events;
 mod pair;
 
 pub use common::*;
-pub use pair::PAIR;
+pub use pair::{
+    DEFAULT_DECIMALS, DEFAULT_MINIMUM_LIQUIDITY, DEFAULT_NAME, DEFAULT_SYMBOL, PAIR,
+    PAIR_CONTRACT_VERSION,
+};
 pub use uniswap_erc20::*;
@@ -5,46 +5,133 @@ use crate::events::PAIREvent;
 use common::{
     contract_api::{runtime, storage},
     errors::Errors,
-    functions::account_zero_address,
+    functions::{account_zero_address, block_timestamp, zero_address},
+    math::{get_stable_d, get_weighted_log, get_weighted_mean},
+    pair_info::{PairInfo, PAIR_TYPE_CONSTANT_PRODUCT, PAIR_TYPE_STABLE, PAIR_TYPE_WEIGHTED},
+    signature::verify_signature,
     unwrap_or_revert::UnwrapOrRevert,
+    upgrade::{self, get_package_owner, init_contract_version, set_package_owner},
     *,
 };
-use cryptoxide::ed25519;
 use uniswap_erc20::{Address, ERC20};
 
+/// Version of the layout of the pair's state, bumped by every upgrade that needs a migration
+pub const PAIR_CONTRACT_VERSION: u32 = 1;
+
+/// Liquidity locked forever by the first mint of a pair installed without `minimum_liquidity`
+pub const DEFAULT_MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// LP token name of a pair installed without `name`, until the factory creates it
+pub const DEFAULT_NAME: &str = "Uniswap V2";
+/// LP token symbol of a pair installed without `symbol`, until the factory creates it
+pub const DEFAULT_SYMBOL: &str = "UNI-V2";
+/// LP token decimals of a pair installed without `decimals`, until the factory creates it
+pub const DEFAULT_DECIMALS: u8 = 9;
+
+/// Largest `minimum_liquidity` a pair accepts, beyond it the first mint would lock a real share
+const MAX_MINIMUM_LIQUIDITY: u64 = 1_000_000;
+
+/// Fee taken on every swap, in basis points
+const SWAP_FEE: u64 = 30;
+
+/// Largest amplification coefficient a stable pair accepts
+const MAX_AMPLIFICATION: u64 = 1_000_000;
+
+/// Bounds of the weight of token0 of a weighted pair, in basis points
+const MIN_WEIGHT: u64 = 100;
+const MAX_WEIGHT: u64 = 9_900;
+
 pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Storage> {
     #[allow(clippy::too_many_arguments)]
     fn init(
         &self,
-        reserve0: U128,
-        reserve1: U128,
-        block_timestamp_last: u64,
-        price0_cumulative_last: U256,
-        price1_cumulative_last: U256,
-        k_last: U256,
-        treasury_fee: U256,
         minimum_liquidity: U256,
         callee_package_hash: Key,
         factory_hash: Key,
-        lock: u64,
+        owner: Key,
+        pair_type: u8,
+        amplification: U256,
+        weight0: U256,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
     ) {
-        set_reserve0(reserve0);
-        set_reserve1(reserve1);
-        set_block_timestamp_last(block_timestamp_last);
-        set_price0_cumulative_last(price0_cumulative_last);
-        set_price1_cumulative_last(price1_cumulative_last);
-        set_k_last(k_last);
-        set_treasury_fee(treasury_fee);
+        match pair_type {
+            PAIR_TYPE_CONSTANT_PRODUCT | PAIR_TYPE_WEIGHTED => {
+                if !amplification.is_zero() {
+                    runtime::revert(Errors::UniswapV2CorePairInvalidAmplification);
+                }
+            }
+            PAIR_TYPE_STABLE => {
+                if amplification.is_zero() || amplification > MAX_AMPLIFICATION.into() {
+                    runtime::revert(Errors::UniswapV2CorePairInvalidAmplification);
+                }
+            }
+            _ => runtime::revert(Errors::UniswapV2CorePairInvalidPairType),
+        }
+        if pair_type == PAIR_TYPE_WEIGHTED {
+            if weight0 < MIN_WEIGHT.into() || weight0 > MAX_WEIGHT.into() {
+                runtime::revert(Errors::UniswapV2CorePairInvalidWeight);
+            }
+        } else if !weight0.is_zero() {
+            runtime::revert(Errors::UniswapV2CorePairInvalidWeight);
+        }
+        if minimum_liquidity.is_zero() || minimum_liquidity > MAX_MINIMUM_LIQUIDITY.into() {
+            runtime::revert(Errors::UniswapV2CorePairInvalidMinimumLiquidity);
+        }
+        if owner == zero_address() || owner == account_zero_address() {
+            runtime::revert(Errors::UniswapV2CorePairInvalidOwner);
+        }
+        // a new pair holds nothing and has seen no liquidity event
+        set_reserve0(0.into());
+        set_reserve1(0.into());
+        set_block_timestamp_last(0);
+        set_price0_cumulative_last(0.into());
+        set_price1_cumulative_last(0.into());
+        set_k_last(0.into());
         set_minimum_liquidity(minimum_liquidity);
         set_callee_package_hash(callee_package_hash);
         set_factory_hash(factory_hash);
-        set_lock(lock);
-        set_owner(self.get_caller());
+        set_lock(0);
+        set_pair_type(pair_type);
+        set_amplification(amplification);
+        set_weight0(weight0);
+        set_owner(owner);
+        #[cfg(not(feature = "unversioned"))]
+        init_contract_version(PAIR_CONTRACT_VERSION, self.get_caller());
         ERC20::init(self, contract_hash, package_hash);
     }
 
+    /// Brings the state written by an older version of the pair up to this one, called by the
+    /// package owner once the new version is added to the package
+    fn migrate(&self, from_version: u32) {
+        upgrade::migrate(
+            self.get_caller(),
+            from_version,
+            PAIR_CONTRACT_VERSION,
+            |version| match version {
+                // settings added since the first release are read with defaults, store them as
+                // named keys for off-chain readers
+                0 => {
+                    set_pair_type(get_pair_type());
+                    set_amplification(get_amplification());
+                    set_weight0(get_weight0());
+                    set_checkpoints_enabled_at(get_checkpoints_enabled_at());
+                }
+                _ => runtime::revert(Errors::UpgradeInvalidVersion),
+            },
+        );
+    }
+
+    /// Records the caller as the package owner of a pair installed before versioning, which kept
+    /// no record of its installer. The entry point is only open to the one-off group the installer
+    /// creates with the package access token while upgrading, so the caller is the installer
+    fn claim_package_owner(&self) {
+        if get_package_owner() != account_zero_address() {
+            runtime::revert(Errors::UpgradeNotPackageOwner);
+        }
+        set_package_owner(self.get_caller());
+    }
+
     fn pause(&self) {
         if is_paused() || self.get_caller() != get_owner() {
             runtime::revert(Errors::UniswapV2CoreCannotPause);
@@ -59,6 +146,14 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         unpause();
     }
 
+    /// Hands the owner role to `new_owner`, for example a timelock administered by governance
+    fn transfer_ownership(&self, new_owner: Key) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2CorePairForbidden3);
+        }
+        set_owner(new_owner);
+    }
+
     fn _is_paused(&self) {
         if is_paused() {
             //UniswapV2: Paused
@@ -144,7 +239,7 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
     }
 
     #[inline(always)]
-    fn swap(&self, amount0_out: U256, amount1_out: U256, to: Key, _data: String) {
+    fn swap(&self, amount0_out: U256, amount1_out: U256, to: Key, data: String) {
         if get_lock() != 0 {
             //UniswapV2: Locked
             runtime::revert(Errors::UniswapV2CorePairLocked3);
@@ -189,20 +284,25 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
                 },
             );
         }
-        // FLASH SWAPPER DISABLED : HALBORN ISSUE #19
-        // if !data.is_empty() {
-        //     () = runtime::call_versioned_contract(
-        //         to.into_hash().unwrap_or_revert().into(),
-        //         None,
-        //         "uniswap_v2_call",
-        //         runtime_args! {
-        //             "sender" => get_callee_package_hash(),
-        //             "amount0" => amount0_out,
-        //             "amount1" => amount1_out,
-        //             "data" => data
-        //         },
-        //     );
-        // }
+        // Halborn issue #19 (HAL-05): only the flash swapper the pair was installed with is called
+        // back, and it is told who called swap so it can refuse flash swaps it did not start.
+        // The lock keeps the callback from reentering swap, mint, burn, skim or sync.
+        if !data.is_empty() {
+            if to != get_callee_package_hash() {
+                runtime::revert(Errors::UniswapV2CorePairInvalidCallee);
+            }
+            () = runtime::call_versioned_contract(
+                to.into_hash().unwrap_or_revert().into(),
+                None,
+                "uniswap_v2_call",
+                runtime_args! {
+                    "sender" => self.get_caller(),
+                    "amount0" => amount0_out,
+                    "amount1" => amount1_out,
+                    "data" => data
+                },
+            );
+        }
         let balance0: U256 = runtime::call_versioned_contract(
             get_token0().into_hash().unwrap_or_revert().into(),
             None,
@@ -251,19 +351,7 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
                 .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow4),
         )
         .unwrap_or_revert_with(Errors::UniswapV2CorePairUnderFlow4);
-        let reserve0_conversion: U256 = U256::from(reserve0.as_u128());
-        let reserve1_conversion: U256 = U256::from(reserve1.as_u128());
-        let base: i32 = 1000;
-        let reserve_multiply: U256 = (base.pow(2)).into();
-        if (balance0_adjusted
-            .checked_mul(balance1_adjusted)
-            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow5))
-            < (reserve0_conversion
-                .checked_mul(reserve1_conversion)
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow6)
-                .checked_mul(reserve_multiply)
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow7))
-        {
+        if !self.invariant_holds(balance0_adjusted, balance1_adjusted, reserve0, reserve1) {
             //UniswapV2: K
             runtime::revert(Errors::UniswapV2CorePairInsufficientConvertedBalance);
         }
@@ -281,6 +369,239 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         set_lock(0);
     }
 
+    /// Checks the fee adjusted balances, scaled by 1000, against the reserves on the invariant of
+    /// the pair type
+    fn invariant_holds(
+        &self,
+        balance0_adjusted: U256,
+        balance1_adjusted: U256,
+        reserve0: U128,
+        reserve1: U128,
+    ) -> bool {
+        let reserve0_conversion: U256 = U256::from(reserve0.as_u128());
+        let reserve1_conversion: U256 = U256::from(reserve1.as_u128());
+        if get_pair_type() == PAIR_TYPE_WEIGHTED {
+            let amount_1000: U256 = 1000.into();
+            let weight0: U256 = get_weight0();
+            return get_weighted_log(balance0_adjusted, balance1_adjusted, weight0)
+                >= get_weighted_log(
+                    reserve0_conversion
+                        .checked_mul(amount_1000)
+                        .unwrap_or_revert_with(Errors::UniswapV2CorePairWeightedOverFlow1),
+                    reserve1_conversion
+                        .checked_mul(amount_1000)
+                        .unwrap_or_revert_with(Errors::UniswapV2CorePairWeightedOverFlow2),
+                    weight0,
+                );
+        }
+        if get_pair_type() == PAIR_TYPE_STABLE {
+            let amount_1000: U256 = 1000.into();
+            let amplification: U256 = get_amplification();
+            return get_stable_d(balance0_adjusted, balance1_adjusted, amplification)
+                >= get_stable_d(
+                    reserve0_conversion
+                        .checked_mul(amount_1000)
+                        .unwrap_or_revert_with(Errors::UniswapV2CorePairStableOverFlow1),
+                    reserve1_conversion
+                        .checked_mul(amount_1000)
+                        .unwrap_or_revert_with(Errors::UniswapV2CorePairStableOverFlow2),
+                    amplification,
+                );
+        }
+        let base: i32 = 1000;
+        let reserve_multiply: U256 = (base.pow(2)).into();
+        balance0_adjusted
+            .checked_mul(balance1_adjusted)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow5)
+            >= reserve0_conversion
+                .checked_mul(reserve1_conversion)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow6)
+                .checked_mul(reserve_multiply)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow7)
+    }
+
+    /// Root of the invariant of the pair type at `x` and `y`, which the LP supply tracks: sqrt(x * y)
+    /// for constant product pairs, half the StableSwap `D` for stable pairs (so a balanced pool
+    /// matches sqrt(x * y)) and the weighted geometric mean for weighted pairs
+    fn invariant_root(&self, x: U256, y: U256) -> U256 {
+        if get_pair_type() == PAIR_TYPE_WEIGHTED {
+            return get_weighted_mean(x, y, get_weight0());
+        }
+        if get_pair_type() == PAIR_TYPE_STABLE {
+            return get_stable_d(x, y, get_amplification()) / 2;
+        }
+        self.sqrt(
+            x.checked_mul(y)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow8),
+        )
+    }
+
+    /// Value stored in `k_last`, the square of `invariant_root`
+    fn invariant_k(&self, x: U256, y: U256) -> U256 {
+        if get_pair_type() != PAIR_TYPE_CONSTANT_PRODUCT {
+            let root: U256 = self.invariant_root(x, y);
+            return root
+                .checked_mul(root)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow15);
+        }
+        x.checked_mul(y)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow15)
+    }
+
+    /// This function is to get the largest amount of `token` that can be flash loaned
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - A Key that holds the Hash of token0 or token1, returns 0 for any other token
+    fn max_flash_loan(&self, token: Key) -> U256 {
+        let reserve: U128 = if token == get_token0() {
+            get_reserve0()
+        } else if token == get_token1() {
+            get_reserve1()
+        } else {
+            return 0.into();
+        };
+        // like swap, the reserve can never be emptied completely
+        U256::from(reserve.as_u128()).saturating_sub(1.into())
+    }
+
+    /// This function is to get the fee charged for flash loaning `amount` of `token`
+    ///
+    /// # Parameters
+    ///
+    /// * `token` - A Key that holds the Hash of token0 or token1
+    ///
+    /// * `amount` - A U256 that holds the amount to be loaned
+    fn flash_fee(&self, token: Key, amount: U256) -> U256 {
+        if token != get_token0() && token != get_token1() {
+            runtime::revert(Errors::UniswapV2CorePairUnsupportedToken);
+        }
+        // same 0.3% a flash swap pays on the amount it takes out
+        (amount
+            .checked_mul(3.into())
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow18)
+            / 997)
+            .checked_add(1.into())
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow7)
+    }
+
+    /// This function is to lend `amount` of `token` to `receiver` for the duration of its `on_flash_loan` call
+    ///
+    /// # Parameters
+    ///
+    /// * `receiver` - A Key that holds the package hash of the contract implementing `on_flash_loan`
+    ///
+    /// * `token` - A Key that holds the Hash of token0 or token1
+    ///
+    /// * `amount` - A U256 that holds the amount to be loaned
+    ///
+    /// * `data` - A String that is passed through to the receiver
+    ///
+    /// The receiver must transfer `amount` plus `flash_fee(token, amount)` back to the pair before
+    /// `on_flash_loan` returns. The fee is sent to the factory `fee_to` when it is set, otherwise it
+    /// stays in the reserves.
+    fn flash_loan(&self, receiver: Key, token: Key, amount: U256, data: String) {
+        if get_lock() != 0 {
+            //UniswapV2: Locked
+            runtime::revert(Errors::UniswapV2CorePairLocked4);
+        }
+        set_lock(1);
+        let fee: U256 = self.flash_fee(token, amount);
+        if amount <= 0.into() || amount > self.max_flash_loan(token) {
+            //UniswapV2: INSUFFICIENT_LIQUIDITY
+            runtime::revert(Errors::UniswapV2CorePairInsufficientLiquidity);
+        }
+        let token_package_hash: ContractPackageHash = token.into_hash().unwrap_or_revert().into();
+        let balance_before: U256 = runtime::call_versioned_contract(
+            token_package_hash,
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::Contract(get_package_hash())
+            },
+        );
+        () = runtime::call_versioned_contract(
+            token_package_hash,
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(receiver),
+                "amount" => amount
+            },
+        );
+        // Unlike the flash swap callback of Halborn issue #19 (HAL-05), the receiver gets nothing but
+        // the loan: the pair is locked, so it cannot reenter swap, mint, burn, skim, sync or
+        // flash_loan, and the reserves are only updated once the loan and the fee are back. Any
+        // contract can be the receiver, so it should only act on initiators it trusts.
+        () = runtime::call_versioned_contract(
+            receiver.into_hash().unwrap_or_revert().into(),
+            None,
+            "on_flash_loan",
+            runtime_args! {
+                "initiator" => self.get_caller(),
+                "token" => token,
+                "amount" => amount,
+                "fee" => fee,
+                "data" => data
+            },
+        );
+        // same balance check as swap, the loan plus fee must be back in the pair
+        let balance_after: U256 = runtime::call_versioned_contract(
+            token_package_hash,
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::Contract(get_package_hash())
+            },
+        );
+        if balance_after
+            < balance_before
+                .checked_add(fee)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow8)
+        {
+            runtime::revert(Errors::UniswapV2CorePairFlashLoanNotRepaid);
+        }
+        let fee_to: Key = self.fee_to();
+        if fee_to != account_zero_address() {
+            () = runtime::call_versioned_contract(
+                token_package_hash,
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(fee_to),
+                    "amount" => fee
+                },
+            );
+        }
+        let balance0: U256 = runtime::call_versioned_contract(
+            get_token0().into_hash().unwrap_or_revert().into(),
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::Contract(get_package_hash())
+            },
+        );
+        let balance1: U256 = runtime::call_versioned_contract(
+            get_token1().into_hash().unwrap_or_revert().into(),
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::Contract(get_package_hash())
+            },
+        );
+        let (reserve0, reserve1, _) = self.get_reserves();
+        self.update(balance0, balance1, reserve0, reserve1);
+        self.emit(&PAIREvent::FlashLoan {
+            initiator: self.get_caller(),
+            receiver,
+            token,
+            amount,
+            fee,
+            pair: Key::from(get_package_hash()),
+        });
+        set_lock(0);
+    }
+
     /// This function is to get signer and verify if it is equal
     /// to the signer public key or not.
     ///
@@ -301,48 +622,20 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         digest: [u8; 32],
         owner: Key,
     ) -> bool {
-        let public_key_without_spaces: String = public_key.split_whitespace().collect();
-        let public_key_string: Vec<&str> = public_key_without_spaces.split(',').collect();
-        let mut public_key_vec: Vec<u8> = Vec::new();
-        let mut public_counter: usize = 0;
-        while public_counter < 32 {
-            public_key_vec.push(public_key_string[public_counter].parse::<u8>().unwrap());
-            public_counter = public_counter
-                .checked_add(1)
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow1);
-        }
-        let signature_without_spaces: String = signature.split_whitespace().collect();
-        let signature_string: Vec<&str> = signature_without_spaces.split(',').collect();
-        let mut signature_vec: Vec<u8> = Vec::new();
-        let mut signature_counter: usize = 0;
-        while signature_counter < 64 {
-            signature_vec.push(signature_string[signature_counter].parse::<u8>().unwrap());
-            signature_counter = signature_counter
-                .checked_add(1)
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow2);
-        }
-        let result: bool = ed25519::verify(&digest, &public_key_vec, &signature_vec);
+        let result: bool = verify_signature(&public_key, &signature, &digest);
         let verify_key: String = format!("{}{}", "VERIFY", owner);
         set_key(&verify_key, result);
         result
     }
 
-    fn set_treasury_fee_percent(&self, treasury_fee: U256) {
-        if self.get_caller() != get_owner() {
-            runtime::revert(Errors::UniswapV2CorePairNotOwner);
-        }
-        if treasury_fee < 30.into() && treasury_fee > 3.into() {
-            set_treasury_fee(treasury_fee);
-        } else if treasury_fee >= 30.into() {
-            set_treasury_fee(30.into());
-        } else {
-            set_treasury_fee(3.into());
-        }
-    }
-
     #[inline(always)]
     #[allow(unused_assignments)]
     fn mint(&self, to: Key) -> U256 {
+        if get_lock() != 0 {
+            //UniswapV2: Locked
+            runtime::revert(Errors::UniswapV2CorePairLocked5);
+        }
+        set_lock(1);
         let (reserve0, reserve1, _block_timestamp_last) = self.get_reserves(); // gas savings
         let balance0: U256 = runtime::call_versioned_contract(
             get_token0().into_hash().unwrap_or_revert().into(),
@@ -371,13 +664,11 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         let mut liquidity: U256 = 0.into();
         if self.total_supply() == 0.into() {
             liquidity = self
-                .sqrt(
-                    amount0
-                        .checked_mul(amount1)
-                        .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow8),
-                )
+                .invariant_root(amount0, amount1)
                 .checked_sub(get_minimum_liquidity())
                 .unwrap_or_revert_with(Errors::UniswapV2CorePairUnderFlow7);
+            self.update_balance_checkpoints(&[Address::from(account_zero_address())]);
+            self.update_total_supply_checkpoint();
             ERC20::mint(
                 self,
                 Address::from(account_zero_address()),
@@ -399,12 +690,15 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
             //UniswapV2: INSUFFICIENT_LIQUIDITY_MINTED
             runtime::revert(Errors::UniswapV2CorePairInsufficientLiquidityMinted);
         }
+        self.update_balance_checkpoints(&[Address::from(to)]);
+        self.update_total_supply_checkpoint();
         ERC20::mint(self, Address::from(to), liquidity).unwrap_or_revert();
         self.update(balance0, balance1, reserve0, reserve1);
         if fee_on {
-            let k_last: U256 = U256::from(get_reserve0().as_u128())
-                .checked_mul(U256::from(get_reserve1().as_u128()))
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow11); // reserve0 and reserve1 are up-to-date
+            let k_last: U256 = self.invariant_k(
+                U256::from(get_reserve0().as_u128()),
+                U256::from(get_reserve1().as_u128()),
+            ); // reserve0 and reserve1 are up-to-date
             set_k_last(k_last);
         }
         set_liquidity(liquidity); // return liquidity
@@ -414,11 +708,17 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
             amount1,
             pair: Key::from(get_package_hash()),
         });
+        set_lock(0);
         liquidity // return liquidity
     }
     
     #[inline(always)]
     fn burn(&self, to: Key) -> (U256, U256) {
+        if get_lock() != 0 {
+            //UniswapV2: Locked
+            runtime::revert(Errors::UniswapV2CorePairLocked6);
+        }
+        set_lock(1);
         let (reserve0, reserve1, _block_timestamp_last) = self.get_reserves(); // gas savings
         let balance0: U256 = runtime::call_versioned_contract(
             get_token0().into_hash().unwrap_or_revert().into(),
@@ -450,6 +750,8 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
             //UniswapV2: INSUFFICIENT_LIQUIDITY_BURNED
             runtime::revert(Errors::UniswapV2CorePairInsufficientLiquidityBurned);
         }
+        self.update_balance_checkpoints(&[Address::Contract(get_package_hash())]);
+        self.update_total_supply_checkpoint();
         ERC20::burn(self, Address::Contract(get_package_hash()), liquidity).unwrap_or_revert();
         () = runtime::call_versioned_contract(
             get_token0().into_hash().unwrap_or_revert().into(),
@@ -487,9 +789,10 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         );
         self.update(balance0, balance1, reserve0, reserve1);
         if fee_on {
-            let k_last: U256 = U256::from(get_reserve0().as_u128())
-                .checked_mul(U256::from(get_reserve1().as_u128()))
-                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow14); // reserve0 and reserve1 are up-to-date
+            let k_last: U256 = self.invariant_k(
+                U256::from(get_reserve0().as_u128()),
+                U256::from(get_reserve1().as_u128()),
+            ); // reserve0 and reserve1 are up-to-date
             set_k_last(k_last);
         }
         set_amount0(amount0);
@@ -501,53 +804,25 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
             to,
             pair: Key::from(get_package_hash()),
         });
+        set_lock(0);
         (amount0, amount1)
     }
 
     #[inline(always)]
     // if fee is on, mint liquidity equivalent to 1/6th of the growth in sqrt(k)
     fn mint_fee(&self, reserve0: U128, reserve1: U128) -> bool {
-        let fee_to: Key = runtime::call_versioned_contract(
-            get_factory_hash().into_hash().unwrap_or_revert().into(),
-            None,
-            "fee_to",
-            runtime_args! {},
-        );
+        let fee_to: Key = self.fee_to();
         let mut fee_on: bool = false;
         if fee_to != account_zero_address() {
             fee_on = true;
         }
         let k_last: U256 = get_k_last(); // gas savings
-        let treasury_fee: U256 = get_treasury_fee();
         if fee_on {
-            if k_last != 0.into() {
-                let mul_val: U256 = U256::from(reserve0.as_u128())
-                    .checked_mul(U256::from(reserve1.as_u128()))
-                    .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow15);
-                let root_k: U256 = self.sqrt(mul_val);
-                let root_k_last: U256 = self.sqrt(k_last);
-                if root_k > root_k_last {
-                    let subtracted_root_k: U256 = root_k
-                        .checked_sub(root_k_last)
-                        .unwrap_or_revert_with(Errors::UniswapV2CorePairUnderFlow8);
-                    let numerator: U256 = self
-                        .total_supply()
-                        .checked_mul(subtracted_root_k)
-                        .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow16);
-                    let denominator: U256 = (root_k
-                        .checked_mul(treasury_fee)
-                        .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow17))
-                    .checked_add(root_k_last)
-                    .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow3);
-                    if denominator <= U256::from(0) {
-                        //UniswapV2: DENOMINATOR IS ZERO
-                        runtime::revert(Errors::UniswapV2CorePairDenominatorIsZero);
-                    }
-                    let liquidity: U256 = numerator / denominator;
-                    if liquidity > 0.into() {
-                        ERC20::mint(self, Address::from(fee_to), liquidity).unwrap_or_revert();
-                    }
-                }
+            let liquidity: U256 = self.protocol_fee_liquidity(reserve0, reserve1, k_last);
+            if liquidity > 0.into() {
+                self.update_balance_checkpoints(&[Address::from(fee_to)]);
+                self.update_total_supply_checkpoint();
+                ERC20::mint(self, Address::from(fee_to), liquidity).unwrap_or_revert();
             }
         } else if k_last != 0.into() {
             set_k_last(0.into());
@@ -555,6 +830,74 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         fee_on
     }
 
+    /// Liquidity that `mint_fee` would mint to `fee_to` right now
+    fn pending_protocol_fee(&self) -> U256 {
+        if self.fee_to() == account_zero_address() {
+            return 0.into();
+        }
+        let (reserve0, reserve1, _block_timestamp_last) = self.get_reserves();
+        self.protocol_fee_liquidity(reserve0, reserve1, get_k_last())
+    }
+
+    /// Liquidity worth the factory's protocol fee (in basis points) of the sqrt(k) growth since `k_last`
+    fn protocol_fee_liquidity(&self, reserve0: U128, reserve1: U128, k_last: U256) -> U256 {
+        if k_last == 0.into() {
+            return 0.into();
+        }
+        let root_k: U256 = self.invariant_root(
+            U256::from(reserve0.as_u128()),
+            U256::from(reserve1.as_u128()),
+        );
+        let root_k_last: U256 = self.sqrt(k_last);
+        if root_k <= root_k_last {
+            return 0.into();
+        }
+        let protocol_fee: U256 = runtime::call_versioned_contract(
+            get_factory_hash().into_hash().unwrap_or_revert().into(),
+            None,
+            "protocol_fee_of",
+            runtime_args! {
+                "pair" => Key::from(get_package_hash())
+            },
+        );
+        let subtracted_root_k: U256 = root_k
+            .checked_sub(root_k_last)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairUnderFlow8);
+        let numerator: U256 = self
+            .total_supply()
+            .checked_mul(subtracted_root_k)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow16)
+            .checked_mul(protocol_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow19);
+        // the share of the growth minted to fee_to: s * (rk - rkl) * fee / (rk * (1 - fee) + rkl * fee)
+        let lp_fee: U256 = U256::from(10_000)
+            .checked_sub(protocol_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairUnderFlow10);
+        let denominator: U256 = (root_k
+            .checked_mul(lp_fee)
+            .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow17))
+        .checked_add(
+            root_k_last
+                .checked_mul(protocol_fee)
+                .unwrap_or_revert_with(Errors::UniswapV2CorePairMultiplicationOverFlow20),
+        )
+        .unwrap_or_revert_with(Errors::UniswapV2CorePairOverFlow3);
+        if denominator <= U256::from(0) {
+            //UniswapV2: DENOMINATOR IS ZERO
+            runtime::revert(Errors::UniswapV2CorePairDenominatorIsZero);
+        }
+        numerator / denominator
+    }
+
+    fn fee_to(&self) -> Key {
+        runtime::call_versioned_contract(
+            get_factory_hash().into_hash().unwrap_or_revert().into(),
+            None,
+            "fee_to",
+            runtime_args! {},
+        )
+    }
+
     fn initialize(&self, token0: Key, token1: Key) {
         if self.get_caller() != get_factory_hash() {
             //(UniswapV2: FORBIDDEN)
@@ -562,6 +905,65 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         }
         set_token0(token0);
         set_token1(token1);
+        let (symbol0, decimals0) = self.token_metadata(token0);
+        let (symbol1, decimals1) = self.token_metadata(token1);
+        // the stable invariant prices one unit of either token the same
+        if get_pair_type() == PAIR_TYPE_STABLE && decimals0 != decimals1 {
+            runtime::revert(Errors::UniswapV2CorePairStableDecimalsMismatch);
+        }
+        // liquidity is sqrt(amount0 * amount1), so its decimals are the mean of both tokens'
+        let decimals: u8 = ((u16::from(decimals0) + u16::from(decimals1)) / 2) as u8;
+        set_lp_metadata(
+            format!("{}-{} LP", symbol0, symbol1),
+            format!("{}-{}-LP", symbol0, symbol1),
+            decimals,
+        );
+    }
+
+    fn token_metadata(&self, token: Key) -> (String, u8) {
+        let token_package_hash: ContractPackageHash = token.into_hash().unwrap_or_revert().into();
+        let symbol: String =
+            runtime::call_versioned_contract(token_package_hash, None, "symbol", runtime_args! {});
+        let decimals: u8 = runtime::call_versioned_contract(
+            token_package_hash,
+            None,
+            "decimals",
+            runtime_args! {},
+        );
+        (symbol, decimals)
+    }
+
+    fn pair_info(&self) -> PairInfo {
+        let token0: Key = get_token0();
+        let token1: Key = get_token1();
+        let (symbol0, decimals0) = self.token_metadata(token0);
+        let (symbol1, decimals1) = self.token_metadata(token1);
+        let (reserve0, reserve1, block_timestamp_last) = self.get_reserves();
+        let protocol_fee: U256 = runtime::call_versioned_contract(
+            get_factory_hash().into_hash().unwrap_or_revert().into(),
+            None,
+            "protocol_fee_of",
+            runtime_args! {
+                "pair" => Key::from(get_package_hash())
+            },
+        );
+        PairInfo {
+            token0,
+            token1,
+            symbol0,
+            symbol1,
+            decimals0,
+            decimals1,
+            reserve0,
+            reserve1,
+            block_timestamp_last,
+            swap_fee: SWAP_FEE.into(),
+            protocol_fee,
+            fee_to: self.fee_to(),
+            pair_type: get_pair_type(),
+            amplification: get_amplification(),
+            weight0: get_weight0(),
+        }
     }
 
     fn deinitialize(&self) {
@@ -573,6 +975,110 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
         remove_token1();
     }
 
+    /// Starts recording balance and total supply checkpoints of the LP token, from which
+    /// `balance_of_at` and `total_supply_at` answer for any time from now on
+    fn enable_checkpoints(&self) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::UniswapV2CorePairForbidden3);
+        }
+        if get_checkpoints_enabled_at() != 0 {
+            runtime::revert(Errors::UniswapV2CorePairCheckpointsEnabled);
+        }
+        BalanceCheckpoints::init();
+        BalanceCheckpointCounts::init();
+        TotalSupplyCheckpoints::init();
+        set_total_supply_checkpoint_count(0);
+        set_checkpoints_enabled_at(block_timestamp());
+    }
+
+    /// Records the balances of `accounts` as they are before their first change in this block,
+    /// must be called before they are changed
+    fn update_balance_checkpoints(&self, accounts: &[Address]) {
+        if get_checkpoints_enabled_at() == 0 {
+            return;
+        }
+        let checkpoints = BalanceCheckpoints::instance();
+        let counts = BalanceCheckpointCounts::instance();
+        let timestamp: u64 = block_timestamp();
+        for owner in accounts {
+            let account: Key = Key::from(*owner);
+            let count: u32 = counts.get(&account);
+            if count == 0 || checkpoints.get(&account, &(count - 1)).0 < timestamp {
+                checkpoints.set(&account, &count, (timestamp, self.balance_of(*owner)));
+                counts.set(&account, count + 1);
+            }
+        }
+    }
+
+    /// Records the total supply as it is before its first change in this block, must be called
+    /// before it is changed
+    fn update_total_supply_checkpoint(&self) {
+        if get_checkpoints_enabled_at() == 0 {
+            return;
+        }
+        let checkpoints = TotalSupplyCheckpoints::instance();
+        let timestamp: u64 = block_timestamp();
+        let count: u32 = get_total_supply_checkpoint_count();
+        if count == 0 || checkpoints.get(&(count - 1)).0 < timestamp {
+            checkpoints.set(&count, (timestamp, self.total_supply()));
+            set_total_supply_checkpoint_count(count + 1);
+        }
+    }
+
+    /// Balance of `owner` at the end of `timestamp`
+    fn balance_of_at(&self, owner: Address, timestamp: u64) -> U256 {
+        self.check_checkpoint_timestamp(timestamp);
+        let account: Key = Key::from(owner);
+        let checkpoints = BalanceCheckpoints::instance();
+        let count: u32 = BalanceCheckpointCounts::instance().get(&account);
+        self.find_checkpoint(count, timestamp, |index| checkpoints.get(&account, &index))
+            .unwrap_or_else(|| self.balance_of(owner))
+    }
+
+    /// Total supply at the end of `timestamp`
+    fn total_supply_at(&self, timestamp: u64) -> U256 {
+        self.check_checkpoint_timestamp(timestamp);
+        let checkpoints = TotalSupplyCheckpoints::instance();
+        let count: u32 = get_total_supply_checkpoint_count();
+        self.find_checkpoint(count, timestamp, |index| checkpoints.get(&index))
+            .unwrap_or_else(|| self.total_supply())
+    }
+
+    /// Only timestamps from enabling checkpoints up to the previous block are settled
+    fn check_checkpoint_timestamp(&self, timestamp: u64) {
+        let checkpoints_enabled_at: u64 = get_checkpoints_enabled_at();
+        if checkpoints_enabled_at == 0 {
+            runtime::revert(Errors::UniswapV2CorePairCheckpointsDisabled);
+        }
+        if timestamp < checkpoints_enabled_at || timestamp >= block_timestamp() {
+            runtime::revert(Errors::UniswapV2CorePairCheckpointInvalidTimestamp);
+        }
+    }
+
+    /// Binary searches the first of `count` checkpoints recorded after `timestamp`, whose value
+    /// is the one held at the end of `timestamp`. None if it has not changed since
+    fn find_checkpoint<F: Fn(u32) -> (u64, U256)>(
+        &self,
+        count: u32,
+        timestamp: u64,
+        checkpoint: F,
+    ) -> Option<U256> {
+        let (mut low, mut high): (u32, u32) = (0, count);
+        while low < high {
+            let mid: u32 = low + (high - low) / 2;
+            if checkpoint(mid).0 > timestamp {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if low == count {
+            None
+        } else {
+            Some(checkpoint(low).1)
+        }
+    }
+
     fn get_reserves(&self) -> (U128, U128, u64) {
         (get_reserve0(), get_reserve1(), get_block_timestamp_last())
     }
@@ -747,6 +1253,32 @@ pub trait PAIR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Stora
                 event.insert("pair", pair.to_string());
                 storage::new_uref(event);
             }
+            PAIREvent::FlashLoan {
+                initiator,
+                receiver,
+                token,
+                amount,
+                fee,
+                pair,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", get_package_hash().to_string());
+                event.insert("event_type", pair_event.type_name());
+                event.insert("initiator", initiator.to_string());
+                event.insert("receiver", receiver.to_string());
+                event.insert("token", token.to_string());
+                event.insert("amount", amount.to_string());
+                event.insert("fee", fee.to_string());
+                event.insert("pair", pair.to_string());
+                // also kept under a named key, so the latest loan can be read without an event indexer
+                set_last_flash_loan(
+                    event
+                        .iter()
+                        .map(|(key, value)| (key.to_string(), value.clone()))
+                        .collect(),
+                );
+                storage::new_uref(event);
+            }
         };
     }
 }
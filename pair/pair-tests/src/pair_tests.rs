@@ -1,4 +1,14 @@
-use tests_common::{account::AccountHash, deploys::*, helpers::*, *};
+use std::collections::BTreeMap;
+use tests_common::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    deploys::*,
+    helpers::*,
+    keys::CALLS,
+    multicall::Call,
+    pair_info::{PairInfo, PAIR_TYPE_CONSTANT_PRODUCT},
+    *,
+};
 
 fn deploy() -> (TestEnv, AccountHash, TestContract, TestContract) {
     let env = TestEnv::new();
@@ -28,7 +38,7 @@ fn deploy() -> (TestEnv, AccountHash, TestContract, TestContract) {
         &env,
         owner,
         Key::Hash(wcspr.package_hash()),
-        Key::Hash(dai.package_hash()),
+        vec![Key::Hash(dai.package_hash())],
         Key::Hash(factory_contract.package_hash()),
         now(),
     );
@@ -36,10 +46,6 @@ fn deploy() -> (TestEnv, AccountHash, TestContract, TestContract) {
         &env,
         "PAIR",
         owner,
-        NAME,
-        SYMBOL,
-        DECIMALS,
-        AMOUNT,
         Key::Hash(callee_contract.package_hash()),
         Key::Hash(factory_contract.package_hash()),
         now(),
@@ -100,27 +106,98 @@ fn initialize(
 #[test]
 fn test_pair_deploy() {
     let (_, _, token, _) = deploy();
+    // settings left out of the install take their defaults, the LP metadata until the factory
+    // creates the pair
+    assert_eq!("Uniswap V2", token.query_named_key::<String>("name".into()));
+    assert_eq!("UNI-V2", token.query_named_key::<String>("symbol".into()));
+    assert_eq!(9, token.query_named_key::<u8>("decimals".into()));
+    assert_eq!(
+        U256::zero(),
+        token.query_named_key::<U256>("total_supply".into())
+    );
+    assert_eq!(
+        U256::from(1_000),
+        token.query_named_key::<U256>("minimum_liquidity".into())
+    );
+    assert_eq!(0, token.query_named_key::<u8>("pair_type".into()));
+}
+
+/// Installs a pair with the optional settings in `config` on top of the required arguments
+fn deploy_with_config(env: &TestEnv, owner: AccountHash, config: RuntimeArgs) -> TestContract {
+    let mut args = runtime_args! {
+        "callee_package_hash" => Key::Account(owner),
+        "factory_hash" => Key::Account(owner)
+    };
+    for named_arg in config.named_args() {
+        args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+    }
+    TestContract::new(env, "pair-token.wasm", "PAIR", owner, args, now())
+}
+
+#[test]
+fn test_pair_deploy_with_config() {
+    let env = TestEnv::new();
+    let installer = env.next_user();
+    let owner = env.next_user();
+    let token = deploy_with_config(
+        &env,
+        installer,
+        runtime_args! {
+            "name" => NAME,
+            "symbol" => SYMBOL,
+            "decimals" => DECIMALS,
+            "minimum_liquidity" => U256::from(10_000),
+            "owner" => Key::Account(owner)
+        },
+    );
     assert_eq!(NAME, token.query_named_key::<String>("name".into()));
     assert_eq!(SYMBOL, token.query_named_key::<String>("symbol".into()));
     assert_eq!(DECIMALS, token.query_named_key::<u8>("decimals".into()));
-    assert_eq!(AMOUNT, token.query_named_key::<U256>("total_supply".into()));
+    assert_eq!(
+        U256::from(10_000),
+        token.query_named_key::<U256>("minimum_liquidity".into())
+    );
+    assert_eq!(
+        Key::Account(owner),
+        token.query_named_key::<Key>("owner".into())
+    );
+    // the installer keeps the right to migrate the package
+    assert_eq!(
+        Key::Account(installer),
+        token.query_named_key::<Key>("package_owner".into())
+    );
+}
+
+#[test]
+#[should_panic] // As a pair must lock some liquidity on its first mint
+fn test_pair_deploy_without_minimum_liquidity() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    deploy_with_config(
+        &env,
+        owner,
+        runtime_args! {
+            "minimum_liquidity" => U256::zero()
+        },
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_pair_paused() {
-    let (env, owner, token, _) = deploy();
+    let (env, owner, token) = deploy_constant_product();
+    let liquidity: U256 = AMOUNT - 1_000;
     token.call_contract(owner, "pause", runtime_args! {}, now());
     // test any pair call
     let to = env.next_user();
     let ret: U256 = token.query(BALANCES, address_to_str(&Address::Account(owner)));
-    assert_eq!(ret, AMOUNT);
+    assert_eq!(ret, liquidity);
     token.call_contract(
         owner,
         "transfer",
         runtime_args! {
             "recipient" => Address::Account(to),
-            "amount" => AMOUNT,
+            "amount" => liquidity,
         },
         now(),
     );
@@ -128,21 +205,22 @@ fn test_pair_paused() {
 
 #[test]
 fn test_pair_transfer() {
-    let (env, owner, token, _) = deploy();
+    let (env, owner, token) = deploy_constant_product();
+    let liquidity: U256 = AMOUNT - 1_000;
     let to = env.next_user();
     let ret: U256 = token.query(BALANCES, address_to_str(&Address::Account(owner)));
-    assert_eq!(ret, AMOUNT);
+    assert_eq!(ret, liquidity);
     token.call_contract(
         owner,
         "transfer",
         runtime_args! {
             "recipient" => Address::Account(to),
-            "amount" => AMOUNT,
+            "amount" => liquidity,
         },
         now(),
     );
     let ret: U256 = token.query(BALANCES, address_to_str(&Address::Account(to)));
-    assert_eq!(ret, AMOUNT);
+    assert_eq!(ret, liquidity);
     let ret: U256 = token.query(BALANCES, address_to_str(&Address::Account(owner)));
     assert_eq!(ret, 0.into());
 }
@@ -154,62 +232,6 @@ fn test_pair_initialize() {
     initialize(&env, owner, &token, &factory);
 }
 
-#[test]
-fn test_pair_set_treasury_fee_percent() {
-    let (_, owner, token, _) = deploy();
-    assert_eq!(token.query_named_key::<U256>(TREASURY_FEE.into()), 5.into());
-    token.call_contract(
-        owner,
-        "set_treasury_fee_percent",
-        runtime_args! {
-            TREASURY_FEE => U256::from(10)
-        },
-        now(),
-    );
-    assert_eq!(
-        token.query_named_key::<U256>(TREASURY_FEE.into()),
-        10.into()
-    );
-}
-
-#[test]
-#[should_panic]
-fn test_pair_set_treasury_fee_percent_cannot_be_more_than_30() {
-    let (_, owner, token, _) = deploy();
-    assert_eq!(token.query_named_key::<U256>(TREASURY_FEE.into()), 3.into());
-    token.call_contract(
-        owner,
-        "set_treasury_fee_percent",
-        runtime_args! {
-            TREASURY_FEE => U256::from(31)
-        },
-        now(),
-    );
-    assert_eq!(
-        token.query_named_key::<U256>(TREASURY_FEE.into()),
-        10.into()
-    );
-}
-
-#[test]
-#[should_panic]
-fn test_pair_set_treasury_fee_percent_cannot_be_less_than_3() {
-    let (_, owner, token, _) = deploy();
-    assert_eq!(token.query_named_key::<U256>(TREASURY_FEE.into()), 3.into());
-    token.call_contract(
-        owner,
-        "set_treasury_fee_percent",
-        runtime_args! {
-            TREASURY_FEE => U256::from(2)
-        },
-        now(),
-    );
-    assert_eq!(
-        token.query_named_key::<U256>(TREASURY_FEE.into()),
-        10.into()
-    );
-}
-
 #[test]
 #[should_panic] // As can be called by factory only
 fn test_pair_skim() {
@@ -395,3 +417,1055 @@ fn test_pair_swap() {
         200_000_000_000u64.into()
     );
 }
+
+#[test]
+#[should_panic] // As the token is neither token0 nor token1
+fn test_pair_flash_loan_unsupported_token() {
+    let (env, owner, token, _) = deploy();
+    let receiver = env.next_user();
+    let other = deploy_erc20(&env, "ERC20-3", owner, "Token2", "TK-2", 9, 0.into(), now());
+    token.call_contract(
+        owner,
+        "flash_loan",
+        runtime_args! {
+            "receiver" => Key::Account(receiver),
+            "token" => Key::Hash(other.package_hash()),
+            "amount" => U256::from(1_000_000_000u64),
+            "data" => ""
+        },
+        now(),
+    );
+}
+
+/// Returns the factory, a pair with AMOUNT of both tokens as liquidity, its token0 and a
+/// flash loan receiver that repays when the data is `repay`
+fn deploy_flash_loan() -> (
+    TestEnv,
+    AccountHash,
+    TestContract,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let (env, owner, factory, pair, token0, _) =
+        deploy_pair_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+            deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+        });
+    let receiver = TestContract::new(
+        &env,
+        "flash-loan-receiver.wasm",
+        "flash_loan_receiver",
+        owner,
+        runtime_args! {},
+        now(),
+    );
+    (env, owner, factory, pair, token0, receiver)
+}
+
+/// Lends 1,000,000,000 token0 to the receiver, which is given the 3,009,028 fee up front
+fn flash_loan(
+    owner: AccountHash,
+    pair: &TestContract,
+    token0: &TestContract,
+    receiver: &TestContract,
+    data: &str,
+) {
+    token0.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Contract(receiver.package_hash().into()),
+            "amount" => U256::from(3_009_028u64)
+        },
+        now(),
+    );
+    pair.call_contract(
+        owner,
+        "flash_loan",
+        runtime_args! {
+            "receiver" => Key::Hash(receiver.package_hash()),
+            "token" => Key::Hash(token0.package_hash()),
+            "amount" => U256::from(1_000_000_000u64),
+            "data" => data
+        },
+        now(),
+    );
+}
+
+#[test]
+fn test_pair_flash_loan() {
+    let (_, owner, _, pair, token0, receiver) = deploy_flash_loan();
+    flash_loan(owner, &pair, &token0, &receiver, "repay");
+    // the fee stays in the reserves while fee_to is not set
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve0".into()),
+        100_003_009_028u128.into()
+    );
+    assert_eq!(
+        token0.query::<U256>(
+            BALANCES,
+            address_to_str(&Address::Contract(receiver.package_hash().into()))
+        ),
+        0.into()
+    );
+    assert_eq!(
+        receiver.query_named_key::<Key>("initiator".into()),
+        Key::Account(owner)
+    );
+    assert_eq!(
+        receiver.query_named_key::<U256>("amount".into()),
+        1_000_000_000u64.into()
+    );
+    assert_eq!(
+        receiver.query_named_key::<U256>("fee".into()),
+        3_009_028u64.into()
+    );
+    let event: BTreeMap<String, String> = pair.query_named_key("last_flash_loan".into());
+    assert_eq!(event["event_type"], "flash_loan");
+    assert_eq!(event["initiator"], Key::Account(owner).to_string());
+    assert_eq!(
+        event["receiver"],
+        Key::Hash(receiver.package_hash()).to_string()
+    );
+    assert_eq!(event["token"], Key::Hash(token0.package_hash()).to_string());
+    assert_eq!(event["amount"], "1000000000");
+    assert_eq!(event["fee"], "3009028");
+    assert_eq!(event["pair"], Key::Hash(pair.package_hash()).to_string());
+}
+
+#[test]
+fn test_pair_flash_loan_fee_to() {
+    let (env, owner, factory, pair, token0, receiver) = deploy_flash_loan();
+    let fee_to = env.next_user();
+    factory.call_contract(
+        owner,
+        "set_fee_to",
+        runtime_args! {
+            "fee_to" => Key::Account(fee_to)
+        },
+        now(),
+    );
+    flash_loan(owner, &pair, &token0, &receiver, "repay");
+    assert_eq!(
+        token0.query::<U256>(BALANCES, address_to_str(&Address::Account(fee_to))),
+        3_009_028u64.into()
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve0".into()),
+        AMOUNT.as_u128().into()
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(109)")] // As the receiver keeps the loan
+fn test_pair_flash_loan_not_repaid() {
+    let (_, owner, _, pair, token0, receiver) = deploy_flash_loan();
+    flash_loan(owner, &pair, &token0, &receiver, "keep");
+}
+
+/// Reads the return of the view `entry_point` of the pair through the multicall session code, which
+/// keeps it under a named key of the owner
+fn view<T: FromBytes>(
+    env: &TestEnv,
+    owner: AccountHash,
+    pair: &TestContract,
+    entry_point: &str,
+) -> T {
+    let calls: Vec<Call> = vec![Call {
+        package_hash: Key::Hash(pair.package_hash()),
+        entry_point: entry_point.into(),
+        args: runtime_args! {},
+        capture: Some(entry_point.into()),
+    }];
+    TestContract::new(
+        env,
+        "session-code-multicall.wasm",
+        "session-code-multicall",
+        owner,
+        runtime_args! {
+            CALLS => Bytes::from(calls.to_bytes().unwrap())
+        },
+        now(),
+    );
+    let ret: Bytes = env.query_account_named_key(owner, &[entry_point.into()]);
+    let (ret, _): (T, _) = FromBytes::from_bytes(&ret).unwrap();
+    ret
+}
+
+fn pending_protocol_fee(env: &TestEnv, owner: AccountHash, pair: &TestContract) -> U256 {
+    view(env, owner, pair, "pending_protocol_fee")
+}
+
+/// Returns the factory, the pair `deploy_pair` deploys and its token0. The invariant of the pair
+/// grew through a swap of 1,000,000,000 token0 for `amount1_out` token1 since liquidity was last
+/// added with the factory `fee_to` set
+fn deploy_fee_on(
+    deploy_pair: impl FnOnce(&TestEnv, AccountHash, Key, Key) -> TestContract,
+    amount1_out: U256,
+) -> (
+    TestEnv,
+    AccountHash,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let (env, owner, factory, pair, token0, token1) = deploy_pair_with_liquidity(deploy_pair);
+    factory.call_contract(
+        owner,
+        "set_fee_to",
+        runtime_args! {
+            "fee_to" => Key::Account(env.next_user())
+        },
+        now(),
+    );
+    // adding liquidity while fee_to is set records k_last
+    for token in [&token0, &token1] {
+        token.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => U256::from(1_000_000_000u64)
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner)
+        },
+        now(),
+    );
+    token0.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Contract(pair.package_hash().into()),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(env.next_user()),
+            "data" => ""
+        },
+        now(),
+    );
+    (env, owner, factory, pair, token0)
+}
+
+#[test]
+fn test_pair_pending_protocol_fee_without_fee_to() {
+    let (env, owner, _, pair, _, _) =
+        deploy_pair_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+            deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+        });
+    assert_eq!(pending_protocol_fee(&env, owner, &pair), 0.into());
+}
+
+#[test]
+fn test_pair_pending_protocol_fee() {
+    let (env, owner, factory, pair, _) = deploy_fee_on(
+        |env, owner, callee_package_hash, factory_hash| {
+            deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+        },
+        987_254_527u64.into(),
+    );
+    // sqrt(k) grew from 101,000,000,000 to 101,001,485,326 on a supply of 101,000,000,000
+    // and the factory default takes 1667 bps of that growth
+    assert_eq!(pending_protocol_fee(&env, owner, &pair), 247_600.into());
+    factory.call_contract(
+        owner,
+        "set_pair_protocol_fee",
+        runtime_args! {
+            "pair" => Key::Hash(pair.package_hash()),
+            "protocol_fee" => Some(U256::from(5_000))
+        },
+        now(),
+    );
+    assert_eq!(pending_protocol_fee(&env, owner, &pair), 742_657.into());
+}
+
+#[test]
+fn test_pair_pending_protocol_fee_stable() {
+    let (env, owner, factory, pair, _) = deploy_fee_on(
+        |env, owner, callee_package_hash, factory_hash| {
+            deploy_stable_pair(
+                env,
+                "PAIR",
+                owner,
+                callee_package_hash,
+                factory_hash,
+                100.into(),
+                now(),
+            )
+        },
+        996_951_034u64.into(),
+    );
+    // D / 2 grew from 101,000,000,000 to 101,001,499,926 where sqrt(k) would have shrunk
+    assert_eq!(pending_protocol_fee(&env, owner, &pair), 250_034.into());
+    factory.call_contract(
+        owner,
+        "set_pair_protocol_fee",
+        runtime_args! {
+            "pair" => Key::Hash(pair.package_hash()),
+            "protocol_fee" => Some(U256::from(5_000))
+        },
+        now(),
+    );
+    assert_eq!(pending_protocol_fee(&env, owner, &pair), 749_957.into());
+}
+
+#[test]
+fn test_pair_info() {
+    let (env, owner, factory, pair, token0, token1) =
+        deploy_pair_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+            deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+        });
+    let fee_to: Key = Key::Account(env.next_user());
+    factory.call_contract(
+        owner,
+        "set_fee_to",
+        runtime_args! {
+            "fee_to" => fee_to
+        },
+        now(),
+    );
+    let info: PairInfo = view(&env, owner, &pair, "pair_info");
+    assert_eq!(info.token0, Key::Hash(token0.package_hash()));
+    assert_eq!(info.token1, Key::Hash(token1.package_hash()));
+    assert_eq!(
+        info.symbol0,
+        token0.query_named_key::<String>("symbol".into())
+    );
+    assert_eq!(
+        info.symbol1,
+        token1.query_named_key::<String>("symbol".into())
+    );
+    assert_eq!((info.decimals0, info.decimals1), (9, 9));
+    assert_eq!(info.reserve0, U128::from(AMOUNT.as_u128()));
+    assert_eq!(info.reserve1, U128::from(AMOUNT.as_u128()));
+    assert_eq!(
+        info.block_timestamp_last,
+        pair.query_named_key::<u64>("block_timestamp_last".into())
+    );
+    // 0.3% of the swaps, of which the factory default takes 1667 bps
+    assert_eq!(info.swap_fee, 30.into());
+    assert_eq!(info.protocol_fee, 1_667.into());
+    assert_eq!(info.fee_to, fee_to);
+    assert_eq!(info.pair_type, PAIR_TYPE_CONSTANT_PRODUCT);
+    assert_eq!(info.amplification, U256::zero());
+    assert_eq!(info.weight0, U256::zero());
+}
+
+/// Returns a pair of a plain token and a token that burns 1% of every transfer, created through the
+/// factory and holding AMOUNT of each as liquidity minted to the owner
+fn deploy_with_fee_on_transfer_token() -> (
+    TestEnv,
+    AccountHash,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let wcspr = deploy_wcspr(
+        &env,
+        "WCSPR",
+        owner,
+        WRAPPED_CSPR.into(),
+        "WCSPR".into(),
+        9,
+        0.into(),
+        now(),
+    );
+    let token = deploy_erc20(&env, "ERC20", owner, "Token", "TK", 9, 0.into(), now());
+    let fee_token = deploy_erc20_fee_on_transfer(
+        &env,
+        "ERC20-FOT",
+        owner,
+        "Fee Token",
+        "FOT",
+        9,
+        0.into(),
+        now(),
+    );
+    let callee_contract = deploy_flashswapper(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    let pair = deploy_pair(
+        &env,
+        "PAIR",
+        owner,
+        Key::Hash(callee_contract.package_hash()),
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(token.package_hash()),
+            "token_b" => Key::Hash(fee_token.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    // minting takes no fee
+    for contract in [&token, &fee_token] {
+        contract.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => AMOUNT
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner)
+        },
+        now(),
+    );
+    (env, owner, pair, token, fee_token)
+}
+
+#[test]
+fn test_pair_burn_with_fee_on_transfer_token() {
+    let (env, owner, pair, token, fee_token) = deploy_with_fee_on_transfer_token();
+    let user = env.next_user();
+    let liquidity: U256 = AMOUNT - 1_000;
+    pair.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Contract(pair.package_hash().into()),
+            "amount" => liquidity
+        },
+        now(),
+    );
+    pair.call_contract(
+        owner,
+        "burn",
+        runtime_args! {
+            "to" => Key::Account(user)
+        },
+        now(),
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(user))),
+        liquidity
+    );
+    // the fee comes out of what the user receives
+    assert_eq!(
+        fee_token.query::<U256>(BALANCES, address_to_str(&Address::Account(user))),
+        liquidity - liquidity / 100
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve0".into()),
+        1_000.into()
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve1".into()),
+        1_000.into()
+    );
+}
+
+#[test]
+fn test_pair_swap_with_fee_on_transfer_token() {
+    let (env, owner, pair, token, fee_token) = deploy_with_fee_on_transfer_token();
+    let user = env.next_user();
+    fee_token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(owner),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    fee_token.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Contract(pair.package_hash().into()),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    // priced on the 990,000,000 the pair received, not the 1,000,000,000 sent
+    let amount_out: U256 = 977_382_937u64.into();
+    let (amount0_out, amount1_out): (U256, U256) =
+        if pair.query_named_key::<Key>("token0".into()) == Key::Hash(token.package_hash()) {
+            (amount_out, 0.into())
+        } else {
+            (0.into(), amount_out)
+        };
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => amount0_out,
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(user))),
+        amount_out
+    );
+}
+
+#[test]
+#[should_panic] // As the output is priced on the amount sent rather than received
+fn test_pair_swap_with_fee_on_transfer_token_priced_on_amount_sent() {
+    let (env, owner, pair, token, fee_token) = deploy_with_fee_on_transfer_token();
+    let user = env.next_user();
+    fee_token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(owner),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    fee_token.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Contract(pair.package_hash().into()),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    let amount_out: U256 = 987_158_034u64.into();
+    let (amount0_out, amount1_out): (U256, U256) =
+        if pair.query_named_key::<Key>("token0".into()) == Key::Hash(token.package_hash()) {
+            (amount_out, 0.into())
+        } else {
+            (0.into(), amount_out)
+        };
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => amount0_out,
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+}
+
+/// Returns a stable pair created through the factory and holding AMOUNT of both tokens as
+/// liquidity minted to the owner
+fn deploy_stable(amplification: U256) -> (TestEnv, AccountHash, TestContract) {
+    deploy_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+        deploy_stable_pair(
+            env,
+            "PAIR",
+            owner,
+            callee_package_hash,
+            factory_hash,
+            amplification,
+            now(),
+        )
+    })
+}
+
+/// Returns a weighted pair created through the factory and holding AMOUNT of both tokens as
+/// liquidity minted to the owner
+fn deploy_weighted(weight0: U256) -> (TestEnv, AccountHash, TestContract) {
+    deploy_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+        deploy_weighted_pair(
+            env,
+            "PAIR",
+            owner,
+            callee_package_hash,
+            factory_hash,
+            weight0,
+            now(),
+        )
+    })
+}
+
+/// Creates the pair `deploy_pair` deploys through the factory, adds AMOUNT of both tokens as
+/// liquidity minted to the owner and sends 1,000,000,000 of token0 in to be swapped
+fn deploy_with_liquidity(
+    deploy_pair: impl FnOnce(&TestEnv, AccountHash, Key, Key) -> TestContract,
+) -> (TestEnv, AccountHash, TestContract) {
+    let (env, owner, _, pair, token0, _) = deploy_pair_with_liquidity(deploy_pair);
+    token0.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Contract(pair.package_hash().into()),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    (env, owner, pair)
+}
+
+#[test]
+fn test_pair_stable_swap() {
+    let (env, owner, pair) = deploy_stable(100.into());
+    assert_eq!(pair.query_named_key::<u8>("pair_type".into()), 1);
+    assert_eq!(
+        pair.query_named_key::<U256>("amplification".into()),
+        100.into()
+    );
+    let user = env.next_user();
+    // the constant product would only pay out 987,158,034
+    let amount1_out: U256 = 996_950_544u64.into();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve1".into()),
+        U128::from(100_000_000_000u64 - 996_950_544)
+    );
+}
+
+#[test]
+#[should_panic] // As the output is more than the stable invariant allows
+fn test_pair_stable_swap_above_invariant() {
+    let (env, owner, pair) = deploy_stable(100.into());
+    let user = env.next_user();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => U256::from(997_000_000u64),
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+}
+
+#[test]
+#[should_panic] // As a stable pair needs an amplification coefficient
+fn test_pair_stable_without_amplification() {
+    deploy_stable(0.into());
+}
+
+#[test]
+fn test_pair_weighted_swap() {
+    let (env, owner, pair) = deploy_weighted(8_000.into());
+    assert_eq!(pair.query_named_key::<u8>("pair_type".into()), 2);
+    assert_eq!(pair.query_named_key::<U256>("weight0".into()), 8_000.into());
+    // the first liquidity is the weighted geometric mean of the amounts, less the minimum liquidity
+    assert_eq!(
+        pair.query_named_key::<U256>("total_supply".into()),
+        U256::from(99_999_999_999u64)
+    );
+    let user = env.next_user();
+    // token0 weighs four times token1, so it buys about four times the constant product's 987,158,034
+    let amount1_out: U256 = 3_890_547_115u64.into();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve1".into()),
+        U128::from(100_000_000_000u64 - 3_890_547_115)
+    );
+}
+
+#[test]
+#[should_panic] // As the output is more than the weighted invariant allows
+fn test_pair_weighted_swap_above_invariant() {
+    let (env, owner, pair) = deploy_weighted(8_000.into());
+    let user = env.next_user();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => U256::from(3_900_000_000u64),
+            "to" => Key::Account(user),
+            "data" => ""
+        },
+        now(),
+    );
+}
+
+#[test]
+#[should_panic] // As a weight of 100% leaves token1 worthless
+fn test_pair_weighted_invalid_weight() {
+    deploy_weighted(10_000.into());
+}
+
+fn balance_checkpoint(token: &TestContract, account: AccountHash, index: u32) -> (u64, U256) {
+    token
+        .query_dictionary(
+            "balance_checkpoints",
+            key_and_index_to_str(&Key::Account(account), index),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_pair_checkpoints() {
+    let (env, owner, token) = deploy_constant_product();
+    // the liquidity minted to the owner, less what the first mint locks
+    let liquidity: U256 = AMOUNT - 1_000;
+    let to = env.next_user();
+    let time = now();
+    token.call_contract(owner, "enable_checkpoints", runtime_args! {}, time);
+    assert_eq!(
+        time,
+        token.query_named_key::<u64>("checkpoints_enabled_at".into())
+    );
+    let amount: U256 = liquidity / 4;
+    // two transfers in one block only record the balances before the first
+    for _ in 0..2 {
+        token.call_contract(
+            owner,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::Account(to),
+                "amount" => amount,
+            },
+            time + 1_000,
+        );
+    }
+    token.call_contract(
+        owner,
+        "transfer",
+        runtime_args! {
+            "recipient" => Address::Account(to),
+            "amount" => amount,
+        },
+        time + 2_000,
+    );
+    let count: u32 = token
+        .query_dictionary(
+            "balance_checkpoint_counts",
+            key_to_str(&Key::Account(owner)),
+        )
+        .unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(
+        balance_checkpoint(&token, owner, 0),
+        (time + 1_000, liquidity)
+    );
+    assert_eq!(
+        balance_checkpoint(&token, owner, 1),
+        (time + 2_000, liquidity - amount * 2)
+    );
+    assert_eq!(balance_checkpoint(&token, to, 0), (time + 1_000, 0.into()));
+    assert_eq!(
+        balance_checkpoint(&token, to, 1),
+        (time + 2_000, amount * 2)
+    );
+    // transfers leave the total supply unchanged
+    assert_eq!(
+        0,
+        token.query_named_key::<u32>("total_supply_checkpoint_count".into())
+    );
+}
+
+#[test]
+#[should_panic] // As only the owner can enable checkpoints
+fn test_pair_checkpoints_not_owner() {
+    let (env, _, token, _) = deploy();
+    token.call_contract(
+        env.next_user(),
+        "enable_checkpoints",
+        runtime_args! {},
+        now(),
+    );
+}
+
+fn deploy_constant_product() -> (TestEnv, AccountHash, TestContract) {
+    deploy_with_liquidity(|env, owner, callee_package_hash, factory_hash| {
+        deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+    })
+}
+
+#[test]
+fn test_pair_upgrade_keeps_reserves_and_balances() {
+    let (env, owner, pair) = deploy_constant_product();
+    assert_eq!(pair.query_named_key::<u32>("contract_version".into()), 1);
+    let reserve0: U128 = pair.query_named_key("reserve0".into());
+    let reserve1: U128 = pair.query_named_key("reserve1".into());
+    let balance: U256 = pair.query(BALANCES, address_to_str(&Address::Account(owner)));
+    // installing under the same name adds a version to the package
+    TestContract::new(
+        &env,
+        "pair-token.wasm",
+        "PAIR",
+        owner,
+        runtime_args! {},
+        now(),
+    );
+    assert_eq!(pair.query_named_key::<u32>("contract_version".into()), 1);
+    assert_eq!(pair.query_named_key::<U128>("reserve0".into()), reserve0);
+    assert_eq!(pair.query_named_key::<U128>("reserve1".into()), reserve1);
+    assert_eq!(
+        pair.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        balance
+    );
+    // the new version trades on the reserves of the old one
+    let amount1_out: U256 = 987_158_034u64.into();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => amount1_out,
+            "to" => Key::Account(env.next_user()),
+            "data" => ""
+        },
+        now(),
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve1".into()),
+        U128::from(reserve1.as_u128() - 987_158_034)
+    );
+}
+
+#[test]
+#[should_panic(expected = "DisabledContract")] // As the upgrade disables the previous version
+fn test_pair_upgrade_disables_old_version() {
+    let (env, owner, pair) = deploy_constant_product();
+    let old_contract_hash: ContractHash = pair.contract_hash().into();
+    TestContract::new(
+        &env,
+        "pair-token.wasm",
+        "PAIR",
+        owner,
+        runtime_args! {},
+        now(),
+    );
+    env.run(
+        owner,
+        DeploySource::ByContractHash {
+            hash: old_contract_hash,
+            method: "sync".into(),
+        },
+        runtime_args! {},
+        now(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(184)")] // As only the package owner can migrate
+fn test_pair_migrate_not_package_owner() {
+    let (env, _, pair) = deploy_constant_product();
+    pair.call_contract(
+        env.next_user(),
+        "migrate",
+        runtime_args! {
+            "from_version" => 0u32
+        },
+        now(),
+    );
+}
+
+/// Installs a pair as it was before versioning, with no contract version nor package owner, owned
+/// by an account other than its installer
+fn deploy_unversioned(
+    env: &TestEnv,
+    installer: AccountHash,
+    callee_package_hash: Key,
+    factory_hash: Key,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "pair-token-unversioned.wasm",
+        "PAIR",
+        installer,
+        runtime_args! {
+            "callee_package_hash" => callee_package_hash,
+            "factory_hash" => factory_hash,
+            "owner" => Key::Account(env.next_user())
+        },
+        now(),
+    )
+}
+
+#[test]
+fn test_pair_upgrade_unversioned() {
+    // liquidity added with fee_to set and a swap since, so k_last and the reserves are live
+    let (env, installer, _, pair, token0) = deploy_fee_on(
+        |env, installer, callee_package_hash, factory_hash| {
+            deploy_unversioned(env, installer, callee_package_hash, factory_hash)
+        },
+        987_254_527u64.into(),
+    );
+    let reserve0: U128 = pair.query_named_key("reserve0".into());
+    let reserve1: U128 = pair.query_named_key("reserve1".into());
+    let k_last: U256 = pair.query_named_key("k_last".into());
+    let total_supply: U256 = pair.query_named_key("total_supply".into());
+    let balance: U256 = pair.query(BALANCES, address_to_str(&Address::Account(installer)));
+    assert!(!k_last.is_zero());
+    TestContract::new(
+        &env,
+        "pair-token.wasm",
+        "PAIR",
+        installer,
+        runtime_args! {},
+        now(),
+    );
+    // migrated from version 0, with the upgrading installer rather than the owner as package owner
+    assert_eq!(pair.query_named_key::<u32>("contract_version".into()), 1);
+    assert_eq!(
+        pair.query_named_key::<Key>("package_owner".into()),
+        Key::Account(installer)
+    );
+    assert_eq!(pair.query_named_key::<U128>("reserve0".into()), reserve0);
+    assert_eq!(pair.query_named_key::<U128>("reserve1".into()), reserve1);
+    assert_eq!(pair.query_named_key::<U256>("k_last".into()), k_last);
+    assert_eq!(
+        pair.query_named_key::<U256>("total_supply".into()),
+        total_supply
+    );
+    assert_eq!(
+        pair.query::<U256>(BALANCES, address_to_str(&Address::Account(installer))),
+        balance
+    );
+    // the migrated pair trades on the reserves of the unversioned one
+    token0.call_contract(
+        installer,
+        "mint",
+        runtime_args! {
+            "to" => Address::Contract(pair.package_hash().into()),
+            "amount" => U256::from(1_000_000_000u64)
+        },
+        now(),
+    );
+    pair.call_contract(
+        installer,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => U256::from(977_566_005u64),
+            "to" => Key::Account(env.next_user()),
+            "data" => ""
+        },
+        now(),
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve0".into()),
+        U128::from(reserve0.as_u128() + 1_000_000_000)
+    );
+    assert_eq!(
+        pair.query_named_key::<U128>("reserve1".into()),
+        U128::from(reserve1.as_u128() - 977_566_005)
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(185)")] // As the upgrade already migrated the state from version 0
+fn test_pair_upgrade_unversioned_migrates_once() {
+    let env = TestEnv::new();
+    let installer = env.next_user();
+    let pair = deploy_unversioned(
+        &env,
+        installer,
+        Key::Account(installer),
+        Key::Account(installer),
+    );
+    TestContract::new(
+        &env,
+        "pair-token.wasm",
+        "PAIR",
+        installer,
+        runtime_args! {},
+        now(),
+    );
+    pair.call_contract(
+        installer,
+        "migrate",
+        runtime_args! {
+            "from_version" => 0u32
+        },
+        now(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "InvalidContext")] // As only the package_owner group can claim
+fn test_pair_claim_package_owner() {
+    let env = TestEnv::new();
+    let installer = env.next_user();
+    let pair = deploy_unversioned(
+        &env,
+        installer,
+        Key::Account(installer),
+        Key::Account(installer),
+    );
+    // no package owner is recorded before the upgrade, so only the group the installer creates
+    // while upgrading keeps anyone else from claiming it
+    pair.call_contract(
+        env.next_user(),
+        "claim_package_owner",
+        runtime_args! {},
+        now(),
+    );
+}
+
+#[test]
+#[should_panic] // As only the flash swapper the pair was installed with is called back
+fn test_pair_flash_swap_invalid_callee() {
+    let (env, owner, pair) = deploy_constant_product();
+    pair.call_contract(
+        owner,
+        "swap",
+        runtime_args! {
+            "amount0_out" => U256::zero(),
+            "amount1_out" => U256::from(987_158_034u64),
+            "to" => Key::Account(env.next_user()),
+            "data" => "flash"
+        },
+        now(),
+    );
+}
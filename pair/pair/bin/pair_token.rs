@@ -3,7 +3,10 @@
 use pair_crate::{
     contract_api::{runtime, storage},
     data::*,
+    functions::get_optional_named_arg,
+    pair_info::PAIR_TYPE_CONSTANT_PRODUCT,
     unwrap_or_revert::UnwrapOrRevert,
+    upgrade::get_contract_version,
     *,
 };
 use std::collections::BTreeSet;
@@ -14,33 +17,25 @@ impl Pair {
     #[allow(clippy::too_many_arguments)]
     fn constructor(
         &self,
-        reserve0: U128,
-        reserve1: U128,
-        block_timestamp_last: u64,
-        price0_cumulative_last: U256,
-        price1_cumulative_last: U256,
-        k_last: U256,
-        treasury_fee: U256,
         minimum_liquidity: U256,
         callee_package_hash: Key,
         factory_hash: Key,
-        lock: u64,
+        owner: Key,
+        pair_type: u8,
+        amplification: U256,
+        weight0: U256,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
     ) {
         PAIR::init(
             self,
-            reserve0,
-            reserve1,
-            block_timestamp_last,
-            price0_cumulative_last,
-            price1_cumulative_last,
-            k_last,
-            treasury_fee,
             minimum_liquidity,
             callee_package_hash,
             factory_hash,
-            lock,
+            owner,
+            pair_type,
+            amplification,
+            weight0,
             contract_hash,
             package_hash,
         );
@@ -57,31 +52,23 @@ impl ERC20<OnChainContractStorage> for Pair {}
 
 #[no_mangle]
 fn constructor() {
-    let reserve0: U128 = runtime::get_named_arg("reserve0");
-    let reserve1: U128 = runtime::get_named_arg("reserve1");
-    let block_timestamp_last: u64 = runtime::get_named_arg("block_timestamp_last");
-    let price0_cumulative_last: U256 = runtime::get_named_arg("price0_cumulative_last");
-    let price1_cumulative_last: U256 = runtime::get_named_arg("price1_cumulative_last");
-    let k_last: U256 = runtime::get_named_arg("k_last"); // reserve0 * reserve1, as of immediately after the most recent liquidity event
-    let treasury_fee: U256 = runtime::get_named_arg("treasury_fee");
     let minimum_liquidity: U256 = runtime::get_named_arg("minimum_liquidity");
     let callee_package_hash: Key = runtime::get_named_arg("callee_package_hash");
     let factory_hash: Key = runtime::get_named_arg("factory_hash");
-    let lock: u64 = runtime::get_named_arg("lock");
+    let owner: Key = runtime::get_named_arg("owner");
+    let pair_type: u8 = runtime::get_named_arg("pair_type");
+    let amplification: U256 = runtime::get_named_arg("amplification");
+    let weight0: U256 = runtime::get_named_arg("weight0");
     let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
     let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
     Pair::default().constructor(
-        reserve0,
-        reserve1,
-        block_timestamp_last,
-        price0_cumulative_last,
-        price1_cumulative_last,
-        k_last,
-        treasury_fee,
         minimum_liquidity,
         callee_package_hash,
         factory_hash,
-        lock,
+        owner,
+        pair_type,
+        amplification,
+        weight0,
         contract_hash,
         package_hash,
     );
@@ -99,6 +86,78 @@ fn unpause() {
     Pair::default().unpause();
 }
 
+/// This function is to hand the owner role to another account or contract, only the owner can call it
+///
+/// # Parameters
+///
+/// * `new_owner` - A Key that holds the account or package hash of the new owner
+///
+
+#[no_mangle]
+fn transfer_ownership() {
+    let new_owner: Key = runtime::get_named_arg("new_owner");
+    Pair::default().transfer_ownership(new_owner);
+}
+
+/// This function is to bring the state written by an older version of the pair up to this one, only the package owner can call it
+///
+/// # Parameters
+///
+/// * `from_version` - A u32 that holds the version the state is at, as returned by contract_version
+///
+
+#[no_mangle]
+fn migrate() {
+    let from_version: u32 = runtime::get_named_arg("from_version");
+    Pair::default().migrate(from_version);
+}
+
+/// This function is to record the installer as the package owner of a pair installed before versioning, only the installer can call it while upgrading
+#[no_mangle]
+fn claim_package_owner() {
+    Pair::default().claim_package_owner();
+}
+
+/// This function is to fetch the version of the code the state is written by, 0 for pairs installed before versioning
+#[no_mangle]
+fn contract_version() {
+    runtime::ret(CLValue::from_t(get_contract_version()).unwrap_or_revert());
+}
+
+/// This function is to start recording balance and total supply checkpoints of the LP token
+#[no_mangle]
+fn enable_checkpoints() {
+    Pair::default().enable_checkpoints();
+}
+
+/// This function is to fetch the block timestamp checkpoints were enabled at, 0 while they are not
+#[no_mangle]
+fn checkpoints_enabled_at() {
+    runtime::ret(CLValue::from_t(get_checkpoints_enabled_at()).unwrap_or_revert());
+}
+
+/// This function is to return the balance of an address at the end of a past block timestamp
+/// # Parameters
+/// * `address` - Address that holds the account address of the user
+/// * `timestamp` - A u64 that holds the block timestamp, no earlier than enabling checkpoints
+#[no_mangle]
+fn balance_of_at() {
+    let owner: Address = runtime::get_named_arg("address");
+    let timestamp: u64 = runtime::get_named_arg("timestamp");
+    runtime::ret(
+        CLValue::from_t(Pair::default().balance_of_at(owner, timestamp)).unwrap_or_revert(),
+    );
+}
+
+/// This function is to return the total supply at the end of a past block timestamp
+/// # Parameters
+/// * `timestamp` - A u64 that holds the block timestamp, no earlier than enabling checkpoints
+#[no_mangle]
+fn total_supply_at() {
+    let timestamp: u64 = runtime::get_named_arg("timestamp");
+    runtime::ret(CLValue::from_t(Pair::default().total_supply_at(timestamp)).unwrap_or_revert());
+}
+
 /// This function is to return the Name of contract
 #[no_mangle]
 fn name() {
@@ -200,6 +259,8 @@ fn transfer() {
     let recipient: Address = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
     Pair::default()._is_paused();
+    Pair::default()
+        .update_balance_checkpoints(&[Address::from(Pair::default().get_caller()), recipient]);
     Pair::default()
         .transfer(recipient, amount)
         .unwrap_or_revert();
@@ -222,6 +283,7 @@ fn transfer_from() {
     let recipient: Address = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
     Pair::default()._is_paused();
+    Pair::default().update_balance_checkpoints(&[owner, recipient]);
     Pair::default()
         .transfer_from(owner, recipient, amount)
         .unwrap_or_revert();
@@ -253,6 +315,46 @@ fn swap() {
     Pair::default().swap(amount0_out, amount1_out, to, data);
 }
 
+/// This function is to get the largest amount of a token that can be flash loaned
+/// # Parameters
+/// * `token` - A Key that holds the Hash of the token
+#[no_mangle]
+fn max_flash_loan() {
+    let token: Key = runtime::get_named_arg("token");
+    Pair::default()._is_paused();
+    let max_flash_loan: U256 = Pair::default().max_flash_loan(token);
+    runtime::ret(CLValue::from_t(max_flash_loan).unwrap_or_revert());
+}
+
+/// This function is to get the fee charged for a flash loan
+/// # Parameters
+/// * `token` - A Key that holds the Hash of the token
+/// * `amount` - A U256 that holds the amount to be loaned
+#[no_mangle]
+fn flash_fee() {
+    let token: Key = runtime::get_named_arg("token");
+    let amount: U256 = runtime::get_named_arg("amount");
+    Pair::default()._is_paused();
+    let flash_fee: U256 = Pair::default().flash_fee(token, amount);
+    runtime::ret(CLValue::from_t(flash_fee).unwrap_or_revert());
+}
+
+/// This function is to flash loan a token to a receiver, which must repay it plus fee in its `on_flash_loan` callback
+/// # Parameters
+/// * `receiver` - A Key that holds the package hash of the receiver contract
+/// * `token` - A Key that holds the Hash of the token
+/// * `amount` - A U256 that holds the amount to be loaned
+/// * `data` - A String that is passed through to the receiver
+#[no_mangle]
+fn flash_loan() {
+    let receiver: Key = runtime::get_named_arg("receiver");
+    let token: Key = runtime::get_named_arg("token");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let data: String = runtime::get_named_arg("data");
+    Pair::default()._is_paused();
+    Pair::default().flash_loan(receiver, token, amount, data);
+}
+
 /// This function is to mint token against the address that user provided
 /// # Parameters
 /// * `to` - A Key that holds the account address of the user
@@ -284,20 +386,34 @@ fn get_reserves() {
     runtime::ret(CLValue::from_t((reserve0, reserve1, block_timestamp_last)).unwrap_or_revert());
 }
 
-/// This function is to get a Treasury Fee
+/// This function is to get the liquidity that would be minted to the factory's fee_to right now
 #[no_mangle]
-fn treasury_fee() {
-    runtime::ret(CLValue::from_t(get_treasury_fee()).unwrap_or_revert());
+fn pending_protocol_fee() {
+    runtime::ret(CLValue::from_t(Pair::default().pending_protocol_fee()).unwrap_or_revert());
 }
 
-/// This function is to set a treasury_fee
-/// # Parameters
-/// * `treasury_fee` - A U256 that holds the value that is going to be a treasury_fee
+/// This function is to get the tokens with their symbols and decimals, the reserves and the fee settings in one struct
 #[no_mangle]
-fn set_treasury_fee_percent() {
-    let treasury_fee: U256 = runtime::get_named_arg("treasury_fee");
-    Pair::default()._is_paused();
-    Pair::default().set_treasury_fee_percent(treasury_fee);
+fn pair_info() {
+    runtime::ret(CLValue::from_t(Pair::default().pair_info()).unwrap_or_revert());
+}
+
+/// This function is to fetch the pair type, which tells the invariant the pair prices swaps on
+#[no_mangle]
+fn pair_type() {
+    runtime::ret(CLValue::from_t(get_pair_type()).unwrap_or_revert());
+}
+
+/// This function is to fetch the amplification coefficient of a stable pair
+#[no_mangle]
+fn amplification() {
+    runtime::ret(CLValue::from_t(get_amplification()).unwrap_or_revert());
+}
+
+/// This function is to fetch the weight of token0 of a weighted pair, in basis points
+#[no_mangle]
+fn weight0() {
+    runtime::ret(CLValue::from_t(get_weight0()).unwrap_or_revert());
 }
 
 /// This function is to fetch a Token0
@@ -335,17 +451,13 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "constructor",
         vec![
-            Parameter::new("reserve0", U128::cl_type()),
-            Parameter::new("reserve1", U128::cl_type()),
-            Parameter::new("block_timestamp_last", u64::cl_type()),
-            Parameter::new("price0_cumulative_last", U256::cl_type()),
-            Parameter::new("price1_cumulative_last", U256::cl_type()),
-            Parameter::new("k_last", U256::cl_type()),
-            Parameter::new("treasury_fee", U256::cl_type()),
             Parameter::new("minimum_liquidity", U256::cl_type()),
             Parameter::new("callee_package_hash", Key::cl_type()),
             Parameter::new("factory_hash", Key::cl_type()),
-            Parameter::new("lock", u64::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("pair_type", u8::cl_type()),
+            Parameter::new("amplification", U256::cl_type()),
+            Parameter::new("weight0", U256::cl_type()),
             Parameter::new("contract_hash", ContractHash::cl_type()),
             Parameter::new("package_hash", ContractPackageHash::cl_type()),
         ],
@@ -353,6 +465,65 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Groups(vec![Group::new("constructor")]),
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "migrate",
+        vec![Parameter::new("from_version", u32::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_package_owner",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("package_owner")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "contract_version",
+        vec![],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_ownership",
+        vec![Parameter::new("new_owner", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "enable_checkpoints",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "checkpoints_enabled_at",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of_at",
+        vec![
+            Parameter::new("address", Address::cl_type()),
+            Parameter::new("timestamp", u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_at",
+        vec![Parameter::new("timestamp", u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "pause",
         vec![],
@@ -489,6 +660,35 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_flash_loan",
+        vec![Parameter::new("token", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "flash_fee",
+        vec![
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "flash_loan",
+        vec![
+            Parameter::new("receiver", Key::cl_type()),
+            Parameter::new("token", Key::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("data", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "mint",
         vec![Parameter::new("to", Key::cl_type())],
@@ -515,16 +715,37 @@ fn get_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "treasury_fee",
+        "pending_protocol_fee",
         vec![],
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "set_treasury_fee_percent",
-        vec![Parameter::new("treasury_fee", U256::cl_type())],
-        <()>::cl_type(),
+        "pair_info",
+        vec![],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pair_type",
+        vec![],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "amplification",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "weight0",
+        vec![],
+        U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
@@ -569,12 +790,23 @@ fn call() {
 
     // If this is the first deployment
     if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
-        let name: String = runtime::get_named_arg("name");
-        let symbol: String = runtime::get_named_arg("symbol");
-        let decimals: u8 = runtime::get_named_arg("decimals");
-        let initial_supply: U256 = runtime::get_named_arg("initial_supply");
         let callee_package_hash: Key = runtime::get_named_arg("callee_package_hash");
         let factory_hash: Key = runtime::get_named_arg("factory_hash");
+        // The LP metadata is replaced with the tokens' once the factory creates the pair
+        let name: String =
+            get_optional_named_arg("name").unwrap_or_else(|| DEFAULT_NAME.to_string());
+        let symbol: String =
+            get_optional_named_arg("symbol").unwrap_or_else(|| DEFAULT_SYMBOL.to_string());
+        let decimals: u8 = get_optional_named_arg("decimals").unwrap_or(DEFAULT_DECIMALS);
+        // Optional settings, the pair validates them in its constructor
+        let minimum_liquidity: U256 = get_optional_named_arg("minimum_liquidity")
+            .unwrap_or_else(|| DEFAULT_MINIMUM_LIQUIDITY.into());
+        let owner: Key =
+            get_optional_named_arg("owner").unwrap_or_else(|| Key::from(runtime::get_caller()));
+        let pair_type: u8 =
+            get_optional_named_arg("pair_type").unwrap_or(PAIR_TYPE_CONSTANT_PRODUCT);
+        let amplification: U256 = get_optional_named_arg("amplification").unwrap_or_default();
+        let weight0: U256 = get_optional_named_arg("weight0").unwrap_or_default();
 
         // Build new package with initial a first version of the contract.
         let (package_hash, access_token) = storage::create_contract_package_at_hash();
@@ -582,34 +814,19 @@ fn call() {
             package_hash,
             get_entry_points(),
             Pair::default()
-                .named_keys(name, symbol, decimals, initial_supply)
+                .named_keys(name, symbol, decimals, U256::zero())
                 .unwrap_or_revert(),
         );
 
-        let base: i32 = 10;
-        let minimum_liquidity: U256 = (base.pow(3)).into();
-        let reserve0: U128 = 0.into();
-        let reserve1: U128 = 0.into();
-        let block_timestamp_last: u64 = 0;
-        let price0_cumulative_last: U256 = 0.into();
-        let price1_cumulative_last: U256 = 0.into();
-        let k_last: U256 = 0.into(); // reserve0 * reserve1, as of immediately after the most recent liquidity event
-        let treasury_fee: U256 = 5.into();
-        let lock: u64 = 0;
-
         // Prepare constructor args
         let constructor_args = runtime_args! {
-            "reserve0" => reserve0,
-            "reserve1" => reserve1,
-            "block_timestamp_last" => block_timestamp_last,
-            "price0_cumulative_last" => price0_cumulative_last,
-            "price1_cumulative_last" => price1_cumulative_last,
-            "k_last" => k_last,
-            "treasury_fee" => treasury_fee,
             "minimum_liquidity" => minimum_liquidity,
             "callee_package_hash" => callee_package_hash,
             "factory_hash" => factory_hash,
-            "lock" => lock,
+            "owner" => owner,
+            "pair_type" => pair_type,
+            "amplification" => amplification,
+            "weight0" => weight0,
             "contract_hash" => contract_hash,
             "package_hash" => package_hash
         };
@@ -662,6 +879,13 @@ fn call() {
                 .unwrap()
                 .into();
 
+        let old_contract_hash: ContractHash =
+            runtime::get_key(&format!("{}_contract_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap_or_revert()
+                .into();
+
         let (contract_hash, _): (ContractHash, _) =
             storage::add_contract_version(package_hash, get_entry_points(), Default::default());
 
@@ -674,5 +898,51 @@ fn call() {
             &format!("{}_contract_hash_wrapped", contract_name),
             storage::new_uref(contract_hash).into(),
         );
+
+        // The new version keeps the named keys of the previous one, migrate them if it changes
+        // their layout
+        let from_version: u32 = runtime::call_versioned_contract(
+            package_hash,
+            None,
+            "contract_version",
+            runtime_args! {},
+        );
+        if from_version == 0 {
+            // Pairs installed before versioning did not record their installer, prove it holds the
+            // access token through a group only it can create, for a single call
+            let package_owner_access: URef = storage::create_contract_user_group(
+                package_hash,
+                "package_owner",
+                1,
+                Default::default(),
+            )
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+            let () = runtime::call_versioned_contract(
+                package_hash,
+                None,
+                "claim_package_owner",
+                runtime_args! {},
+            );
+            let mut urefs = BTreeSet::new();
+            urefs.insert(package_owner_access);
+            storage::remove_contract_user_group_urefs(package_hash, "package_owner", urefs)
+                .unwrap_or_revert();
+        }
+        if from_version < PAIR_CONTRACT_VERSION {
+            let () = runtime::call_versioned_contract(
+                package_hash,
+                None,
+                "migrate",
+                runtime_args! {
+                    "from_version" => from_version
+                },
+            );
+        }
+
+        // The previous version shares the named keys, so it must not write them with its own
+        // logic once they are migrated
+        storage::disable_contract_version(package_hash, old_contract_hash).unwrap_or_revert();
     }
 }
@@ -0,0 +1,9 @@
+use common::{keys::*, unwrap_or_revert::UnwrapOrRevert, *};
+
+pub fn set_factory_hash(factory_hash: Key) {
+    set_key(FACTORY, factory_hash);
+}
+
+pub fn get_factory_hash() -> Key {
+    get_key(FACTORY).unwrap_or_revert()
+}
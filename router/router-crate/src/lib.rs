@@ -0,0 +1,5 @@
+pub mod data;
+mod router;
+
+pub use common::*;
+pub use router::ROUTER;
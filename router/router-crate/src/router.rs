@@ -0,0 +1,160 @@
+use crate::data::*;
+use common::{
+    contract_api::runtime,
+    errors::Errors,
+    functions::{block_timestamp, zero_address},
+    quote::get_pair_amount_out,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+
+pub trait ROUTER<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        factory_hash: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        set_factory_hash(factory_hash);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    /// Swaps `amount_in` of `path[0]` along `path` and sends the last token to `to`. Every hop
+    /// swaps what its pair actually received, so tokens that take a fee on transfer can be in the
+    /// path. The amount `to` actually received is checked against `amount_out_min`
+    fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        &self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<Key>,
+        to: Key,
+        deadline: U256,
+    ) {
+        self.ensure(deadline);
+        if path.len() < 2 {
+            runtime::revert(Errors::RouterInvalidPath);
+        }
+        self.transfer_from(
+            path[0],
+            self.get_caller(),
+            self.pair_for(path[0], path[1]),
+            amount_in,
+        );
+        let token_out: Key = path[path.len() - 1];
+        let balance_before: U256 = self.balance_of(token_out, to);
+        self.swap_supporting_fee_on_transfer(&path, to);
+        let amount_out: U256 = self
+            .balance_of(token_out, to)
+            .checked_sub(balance_before)
+            .unwrap_or_revert_with(Errors::RouterUnderFlow);
+        if amount_out < amount_out_min {
+            runtime::revert(Errors::RouterInsufficientOutputAmount);
+        }
+    }
+
+    /// Requires the first pair to already hold the input amount
+    fn swap_supporting_fee_on_transfer(&self, path: &[Key], to: Key) {
+        for i in 0..path.len() - 1 {
+            let (input, output) = (path[i], path[i + 1]);
+            let pair: Key = self.pair_for(input, output);
+            let pair_package_hash: ContractPackageHash = pair.into_hash().unwrap_or_revert().into();
+            let token0: Key = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "token0",
+                runtime_args! {},
+            );
+            let (reserve0, reserve1, _): (U128, U128, u64) = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "get_reserves",
+                runtime_args! {},
+            );
+            let (reserve_input, reserve_output) = if input == token0 {
+                (reserve0, reserve1)
+            } else {
+                (reserve1, reserve0)
+            };
+            let reserve_input: U256 = U256::from(reserve_input.as_u128());
+            let amount_input: U256 = self
+                .balance_of(input, pair)
+                .checked_sub(reserve_input)
+                .unwrap_or_revert_with(Errors::RouterUnderFlow);
+            let amount_output: U256 = get_pair_amount_out(
+                pair,
+                input == token0,
+                amount_input,
+                reserve_input,
+                U256::from(reserve_output.as_u128()),
+            );
+            let (amount0_out, amount1_out): (U256, U256) = if input == token0 {
+                (0.into(), amount_output)
+            } else {
+                (amount_output, 0.into())
+            };
+            let recipient: Key = if i < path.len() - 2 {
+                self.pair_for(output, path[i + 2])
+            } else {
+                to
+            };
+            let () = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "swap",
+                runtime_args! {
+                    "amount0_out" => amount0_out,
+                    "amount1_out" => amount1_out,
+                    "to" => recipient,
+                    "data" => ""
+                },
+            );
+        }
+    }
+
+    fn pair_for(&self, token_a: Key, token_b: Key) -> Key {
+        let pair: Key = runtime::call_versioned_contract(
+            get_factory_hash().into_hash().unwrap_or_revert().into(),
+            None,
+            "get_pair",
+            runtime_args! {
+                "token0" => token_a,
+                "token1" => token_b
+            },
+        );
+        if pair == zero_address() {
+            runtime::revert(Errors::RouterPairNotFound);
+        }
+        pair
+    }
+
+    fn ensure(&self, deadline: U256) {
+        if deadline < U256::from(block_timestamp()) {
+            runtime::revert(Errors::RouterExpired);
+        }
+    }
+
+    fn balance_of(&self, token: Key, owner: Key) -> U256 {
+        runtime::call_versioned_contract(
+            token.into_hash().unwrap_or_revert().into(),
+            None,
+            "balance_of",
+            runtime_args! {
+                "address" => Address::from(owner)
+            },
+        )
+    }
+
+    fn transfer_from(&self, token: Key, owner: Key, recipient: Key, amount: U256) {
+        let () = runtime::call_versioned_contract(
+            token.into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer_from",
+            runtime_args! {
+                "owner" => Address::from(owner),
+                "recipient" => Address::from(recipient),
+                "amount" => amount
+            },
+        );
+    }
+}
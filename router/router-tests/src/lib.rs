@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod router_tests;
+
+#[cfg(test)]
+pub mod router_instance;
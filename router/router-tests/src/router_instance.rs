@@ -0,0 +1,38 @@
+use tests_common::{account::AccountHash, deploys::deploy_router, *};
+
+pub struct ROUTERInstance(pub TestContract);
+
+impl ROUTERInstance {
+    pub fn new(env: &TestEnv, sender: AccountHash, factory: Key, time: u64) -> ROUTERInstance {
+        ROUTERInstance(deploy_router(env, sender, factory, time))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        &self,
+        sender: AccountHash,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: Vec<Key>,
+        to: Key,
+        deadline: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "swap_exact_tokens_for_tokens_supporting_fee_on_transfer",
+            runtime_args! {
+                "amount_in" => amount_in,
+                "amount_out_min" => amount_out_min,
+                "path" => path,
+                "to" => to,
+                "deadline" => deadline,
+            },
+            time,
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use crate::router_instance::ROUTERInstance;
+use tests_common::{account::AccountHash, deploys::*, helpers::*, *};
+
+const SWAP_AMOUNT: u64 = 1_000_000_000;
+
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    ROUTERInstance,
+    TestContract,
+    TestContract,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let user = env.next_user();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let wcspr = deploy_wcspr(
+        &env,
+        "WCSPR",
+        owner,
+        WRAPPED_CSPR.into(),
+        "WCSPR".into(),
+        9,
+        0.into(),
+        now(),
+    );
+    let token = deploy_erc20(&env, "ERC20", owner, "Token", "TK", 9, 0.into(), now());
+    let fee_token = deploy_erc20_fee_on_transfer(
+        &env,
+        "ERC20-FOT",
+        owner,
+        "Fee Token",
+        "FOT",
+        9,
+        0.into(),
+        now(),
+    );
+    let flash_swapper = deploy_flashswapper(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    let pair = deploy_pair(
+        &env,
+        "PAIR",
+        owner,
+        Key::Hash(flash_swapper.package_hash()),
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(token.package_hash()),
+            "token_b" => Key::Hash(fee_token.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    let router = ROUTERInstance::new(&env, owner, Key::Hash(factory.package_hash()), now());
+    for contract in [&token, &fee_token] {
+        // minting takes no fee
+        contract.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Contract(pair.package_hash().into()),
+                "amount" => AMOUNT
+            },
+            now(),
+        );
+        contract.call_contract(
+            owner,
+            "mint",
+            runtime_args! {
+                "to" => Address::Account(user),
+                "amount" => U256::from(SWAP_AMOUNT)
+            },
+            now(),
+        );
+        contract.call_contract(
+            user,
+            "approve",
+            runtime_args! {
+                "spender" => Address::Contract(router.0.package_hash().into()),
+                "amount" => U256::from(SWAP_AMOUNT)
+            },
+            now(),
+        );
+    }
+    pair.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner)
+        },
+        now(),
+    );
+    (env, user, router, token, fee_token)
+}
+
+fn balance(token: &TestContract, owner: AccountHash) -> U256 {
+    token.query(BALANCES, address_to_str(&Address::Account(owner)))
+}
+
+fn deadline() -> U256 {
+    (now() + 60_000).into()
+}
+
+#[test]
+fn test_router_swap_fee_on_transfer_token_in() {
+    let (_, user, router, token, fee_token) = deploy();
+    // priced on the 990,000,000 the pair received, not the 1,000,000,000 sent
+    router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        user,
+        SWAP_AMOUNT.into(),
+        977_382_937u64.into(),
+        vec![
+            Key::Hash(fee_token.package_hash()),
+            Key::Hash(token.package_hash()),
+        ],
+        Key::Account(user),
+        deadline(),
+        now(),
+    );
+    assert_eq!(balance(&fee_token, user), 0.into());
+    assert_eq!(
+        balance(&token, user),
+        U256::from(SWAP_AMOUNT) + 977_382_937u64
+    );
+}
+
+#[test]
+fn test_router_swap_fee_on_transfer_token_out() {
+    let (_, user, router, token, fee_token) = deploy();
+    // 987,158,034 leave the pair and 1% of it is burned on the way to the user
+    router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        user,
+        SWAP_AMOUNT.into(),
+        977_286_454u64.into(),
+        vec![
+            Key::Hash(token.package_hash()),
+            Key::Hash(fee_token.package_hash()),
+        ],
+        Key::Account(user),
+        deadline(),
+        now(),
+    );
+    assert_eq!(balance(&token, user), 0.into());
+    assert_eq!(
+        balance(&fee_token, user),
+        U256::from(SWAP_AMOUNT) + 977_286_454u64
+    );
+}
+
+#[test]
+#[should_panic] // As the user receives less than the pair sends
+fn test_router_swap_fee_on_transfer_token_out_below_min() {
+    let (_, user, router, token, fee_token) = deploy();
+    router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        user,
+        SWAP_AMOUNT.into(),
+        977_286_455u64.into(),
+        vec![
+            Key::Hash(token.package_hash()),
+            Key::Hash(fee_token.package_hash()),
+        ],
+        Key::Account(user),
+        deadline(),
+        now(),
+    );
+}
+
+#[test]
+#[should_panic] // As the deadline has passed
+fn test_router_swap_expired() {
+    let (_, user, router, token, fee_token) = deploy();
+    router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        user,
+        SWAP_AMOUNT.into(),
+        0.into(),
+        vec![
+            Key::Hash(token.package_hash()),
+            Key::Hash(fee_token.package_hash()),
+        ],
+        Key::Account(user),
+        0.into(),
+        now(),
+    );
+}
@@ -0,0 +1,181 @@
+#![no_main]
+
+use router_crate::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct Router(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for Router {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl ROUTER<OnChainContractStorage> for Router {}
+
+impl Router {
+    fn constructor(
+        &self,
+        factory_hash: Key,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        ROUTER::init(self, factory_hash, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let factory_hash: Key = runtime::get_named_arg("factory_hash");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Router::default().constructor(factory_hash, contract_hash, package_hash);
+}
+
+/// This function is to swap an exact amount of tokens along a path of pairs, measuring what every pair and the recipient actually receive so tokens that take a fee on transfer are supported
+///
+/// # Parameters
+///
+/// * `amount_in` - A U256 that holds the amount of path[0] pulled from the caller, it has to be approved to the router first
+///
+/// * `amount_out_min` - A U256 that holds the least amount of the last token of the path `to` has to receive
+///
+/// * `path` - A list of Keys that holds the Hashes of the tokens to swap through, there has to be a pair for every two consecutive tokens
+///
+/// * `to` - A Key that holds the account address or contract hash of the recipient
+///
+/// * `deadline` - A U256 that holds the block time in milliseconds after which the swap reverts
+///
+
+#[no_mangle]
+fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer() {
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let amount_out_min: U256 = runtime::get_named_arg("amount_out_min");
+    let path: Vec<Key> = runtime::get_named_arg("path");
+    let to: Key = runtime::get_named_arg("to");
+    let deadline: U256 = runtime::get_named_arg("deadline");
+    Router::default().swap_exact_tokens_for_tokens_supporting_fee_on_transfer(
+        amount_in,
+        amount_out_min,
+        path,
+        to,
+        deadline,
+    );
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("factory_hash", Key::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "swap_exact_tokens_for_tokens_supporting_fee_on_transfer",
+        vec![
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("amount_out_min", U256::cl_type()),
+            Parameter::new("path", CLType::List(Box::new(Key::cl_type()))),
+            Parameter::new("to", Key::cl_type()),
+            Parameter::new("deadline", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        let factory_hash: Key = runtime::get_named_arg("factory_hash");
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "factory_hash" => factory_hash,
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use common::{
+    functions::{account_zero_address, zero_address},
+    keys::*,
+    staking_pool::StakingPool,
+    *,
+};
+
+/// Pools by their id, as a decimal string
+pub struct Pools {
+    dict: Dict,
+}
+
+impl Pools {
+    pub fn instance() -> Pools {
+        Pools {
+            dict: Dict::instance(POOLS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(POOLS_DICT)
+    }
+
+    pub fn get(&self, pool_id: U256) -> Option<StakingPool> {
+        self.dict.get(&pool_id.to_string())
+    }
+
+    pub fn set(&self, pool_id: U256, pool: StakingPool) {
+        self.dict.set(&pool_id.to_string(), pool);
+    }
+}
+
+/// Amount staked by an account in a pool
+pub struct Stakes {
+    dict: Dict,
+}
+
+impl Stakes {
+    pub fn instance() -> Stakes {
+        Stakes {
+            dict: Dict::instance(STAKES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(STAKES_DICT)
+    }
+
+    pub fn get(&self, pool_id: &U256, account: &Key) -> U256 {
+        self.dict
+            .get_by_keys((pool_id, account))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, pool_id: &U256, account: &Key, value: U256) {
+        self.dict.set_by_keys((pool_id, account), value);
+    }
+}
+
+/// Reward per token of a pool an account was last paid up to
+pub struct UserRewardPerTokenPaid {
+    dict: Dict,
+}
+
+impl UserRewardPerTokenPaid {
+    pub fn instance() -> UserRewardPerTokenPaid {
+        UserRewardPerTokenPaid {
+            dict: Dict::instance(USER_REWARD_PER_TOKEN_PAID_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(USER_REWARD_PER_TOKEN_PAID_DICT)
+    }
+
+    pub fn get(&self, pool_id: &U256, account: &Key) -> U256 {
+        self.dict
+            .get_by_keys((pool_id, account))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, pool_id: &U256, account: &Key, value: U256) {
+        self.dict.set_by_keys((pool_id, account), value);
+    }
+}
+
+/// Rewards of a pool accrued to an account and not paid yet
+pub struct Rewards {
+    dict: Dict,
+}
+
+impl Rewards {
+    pub fn instance() -> Rewards {
+        Rewards {
+            dict: Dict::instance(REWARDS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(REWARDS_DICT)
+    }
+
+    pub fn get(&self, pool_id: &U256, account: &Key) -> U256 {
+        self.dict
+            .get_by_keys((pool_id, account))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, pool_id: &U256, account: &Key, value: U256) {
+        self.dict.set_by_keys((pool_id, account), value);
+    }
+}
+
+pub fn set_owner(owner: Key) {
+    set_key(OWNER, owner);
+}
+
+pub fn get_owner() -> Key {
+    get_key(OWNER).unwrap_or_else(account_zero_address)
+}
+
+pub fn set_rewards_token(rewards_token: Key) {
+    set_key(REWARDS_TOKEN, rewards_token);
+}
+
+pub fn get_rewards_token() -> Key {
+    get_key(REWARDS_TOKEN).unwrap_or_else(zero_address)
+}
+
+pub fn set_rewards_duration(rewards_duration: U256) {
+    set_key(REWARDS_DURATION, rewards_duration);
+}
+
+pub fn get_rewards_duration() -> U256 {
+    get_key(REWARDS_DURATION).unwrap_or_default()
+}
+
+pub fn set_reward_rate(reward_rate: U256) {
+    set_key(REWARD_RATE, reward_rate);
+}
+
+pub fn get_reward_rate() -> U256 {
+    get_key(REWARD_RATE).unwrap_or_default()
+}
+
+pub fn set_period_finish(period_finish: U256) {
+    set_key(PERIOD_FINISH, period_finish);
+}
+
+pub fn get_period_finish() -> U256 {
+    get_key(PERIOD_FINISH).unwrap_or_default()
+}
+
+pub fn set_total_alloc_point(total_alloc_point: U256) {
+    set_key(TOTAL_ALLOC_POINT, total_alloc_point);
+}
+
+pub fn get_total_alloc_point() -> U256 {
+    get_key(TOTAL_ALLOC_POINT).unwrap_or_default()
+}
+
+pub fn set_pools_count(pools_count: U256) {
+    set_key(POOLS_COUNT, pools_count);
+}
+
+pub fn get_pools_count() -> U256 {
+    get_key(POOLS_COUNT).unwrap_or_default()
+}
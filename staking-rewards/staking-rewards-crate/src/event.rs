@@ -0,0 +1,70 @@
+use common::*;
+
+pub enum STAKINGREWARDSEvent {
+    PoolAdded {
+        pool_id: U256,
+        lp_token: Key,
+        alloc_point: U256,
+    },
+    PoolSet {
+        pool_id: U256,
+        alloc_point: U256,
+    },
+    RewardAdded {
+        reward: U256,
+        reward_rate: U256,
+        period_finish: U256,
+    },
+    Staked {
+        user: Key,
+        pool_id: U256,
+        amount: U256,
+    },
+    Withdrawn {
+        user: Key,
+        pool_id: U256,
+        amount: U256,
+    },
+    RewardPaid {
+        user: Key,
+        pool_id: U256,
+        reward: U256,
+    },
+}
+
+impl STAKINGREWARDSEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            STAKINGREWARDSEvent::PoolAdded {
+                pool_id: _,
+                lp_token: _,
+                alloc_point: _,
+            } => "pool_added",
+            STAKINGREWARDSEvent::PoolSet {
+                pool_id: _,
+                alloc_point: _,
+            } => "pool_set",
+            STAKINGREWARDSEvent::RewardAdded {
+                reward: _,
+                reward_rate: _,
+                period_finish: _,
+            } => "reward_added",
+            STAKINGREWARDSEvent::Staked {
+                user: _,
+                pool_id: _,
+                amount: _,
+            } => "staked",
+            STAKINGREWARDSEvent::Withdrawn {
+                user: _,
+                pool_id: _,
+                amount: _,
+            } => "withdrawn",
+            STAKINGREWARDSEvent::RewardPaid {
+                user: _,
+                pool_id: _,
+                reward: _,
+            } => "reward_paid",
+        }
+        .to_string()
+    }
+}
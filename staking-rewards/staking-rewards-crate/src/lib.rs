@@ -0,0 +1,6 @@
+pub mod data;
+pub mod event;
+mod staking_rewards;
+
+pub use common::*;
+pub use staking_rewards::STAKINGREWARDS;
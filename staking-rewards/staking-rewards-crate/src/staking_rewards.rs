@@ -0,0 +1,420 @@
+use crate::{data::*, event::STAKINGREWARDSEvent};
+use common::{
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::block_timestamp,
+    staking_pool::StakingPool,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+/// Scale of `reward_per_token`
+const PRECISION: u128 = 1_000_000_000_000_000_000;
+
+pub trait STAKINGREWARDS<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        rewards_token: Key,
+        rewards_duration: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        if rewards_duration.is_zero() {
+            runtime::revert(Errors::StakingRewardsInvalidDuration);
+        }
+        set_owner(self.get_caller());
+        set_rewards_token(rewards_token);
+        set_rewards_duration(rewards_duration);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+        Pools::init();
+        Stakes::init();
+        UserRewardPerTokenPaid::init();
+        Rewards::init();
+    }
+
+    fn only_owner(&self) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(Errors::StakingRewardsNotOwner);
+        }
+    }
+
+    /// Registers a pool staking `lp_token` with `alloc_point` shares of the reward rate. The
+    /// rewards token can't be staked, as stakes would be paid out as rewards, and an LP token gets
+    /// one pool only, so its `alloc_point` is all it earns
+    fn add_pool(&self, lp_token: Key, alloc_point: U256) -> U256 {
+        self.only_owner();
+        if lp_token == get_rewards_token() {
+            runtime::revert(Errors::StakingRewardsInvalidLpToken);
+        }
+        let pool_id: U256 = get_pools_count();
+        for id in 0..pool_id.as_u64() {
+            if self.get_pool(id.into()).lp_token == lp_token {
+                runtime::revert(Errors::StakingRewardsPoolExists);
+            }
+        }
+        self.mass_update_pools();
+        set_pools_count(
+            pool_id
+                .checked_add(1.into())
+                .unwrap_or_revert_with(Errors::StakingRewardsOverFlow),
+        );
+        set_total_alloc_point(
+            get_total_alloc_point()
+                .checked_add(alloc_point)
+                .unwrap_or_revert_with(Errors::StakingRewardsOverFlow),
+        );
+        Pools::instance().set(
+            pool_id,
+            StakingPool {
+                lp_token,
+                alloc_point,
+                total_supply: 0.into(),
+                reward_per_token_stored: 0.into(),
+                last_update_time: self.now(),
+            },
+        );
+        self.emit(&STAKINGREWARDSEvent::PoolAdded {
+            pool_id,
+            lp_token,
+            alloc_point,
+        });
+        pool_id
+    }
+
+    /// Changes the shares of the reward rate of a pool, settling every pool at the old shares first
+    fn set_pool(&self, pool_id: U256, alloc_point: U256) {
+        self.only_owner();
+        self.mass_update_pools();
+        let mut pool: StakingPool = self.get_pool(pool_id);
+        set_total_alloc_point(
+            get_total_alloc_point()
+                .checked_sub(pool.alloc_point)
+                .unwrap_or_revert_with(Errors::StakingRewardsUnderFlow)
+                .checked_add(alloc_point)
+                .unwrap_or_revert_with(Errors::StakingRewardsOverFlow),
+        );
+        pool.alloc_point = alloc_point;
+        Pools::instance().set(pool_id, pool);
+        self.emit(&STAKINGREWARDSEvent::PoolSet {
+            pool_id,
+            alloc_point,
+        });
+    }
+
+    /// Pulls `reward` of the rewards token from the owner and spreads it, with what is left of the
+    /// running period, over a new period of `rewards_duration` seconds
+    fn notify_reward_amount(&self, reward: U256) {
+        self.only_owner();
+        self.mass_update_pools();
+        let now: U256 = self.now();
+        let rewards_duration: U256 = get_rewards_duration();
+        let period_finish: U256 = get_period_finish();
+        let mut total: U256 = reward;
+        if now < period_finish {
+            let leftover: U256 = (period_finish - now)
+                .checked_mul(get_reward_rate())
+                .unwrap_or_revert_with(Errors::StakingRewardsOverFlow);
+            total = total
+                .checked_add(leftover)
+                .unwrap_or_revert_with(Errors::StakingRewardsOverFlow);
+        }
+        let reward_rate: U256 = total / rewards_duration;
+        if reward_rate.is_zero() {
+            runtime::revert(Errors::StakingRewardsInvalidAmount);
+        }
+        self.transfer_from(
+            get_rewards_token(),
+            self.get_caller(),
+            Key::from(get_package_hash()),
+            reward,
+        );
+        let period_finish: U256 = now
+            .checked_add(rewards_duration)
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow);
+        set_reward_rate(reward_rate);
+        set_period_finish(period_finish);
+        // the new rate only applies from now on, even to pools last updated before the old period ended
+        for pool_id in 0..get_pools_count().as_u64() {
+            let mut pool: StakingPool = self.get_pool(pool_id.into());
+            pool.last_update_time = now;
+            Pools::instance().set(pool_id.into(), pool);
+        }
+        self.emit(&STAKINGREWARDSEvent::RewardAdded {
+            reward,
+            reward_rate,
+            period_finish,
+        });
+    }
+
+    /// Sets the length of the next reward periods, once the running one has finished
+    fn set_rewards_duration(&self, rewards_duration: U256) {
+        self.only_owner();
+        if self.now() <= get_period_finish() {
+            runtime::revert(Errors::StakingRewardsPeriodNotFinished);
+        }
+        if rewards_duration.is_zero() {
+            runtime::revert(Errors::StakingRewardsInvalidDuration);
+        }
+        set_rewards_duration(rewards_duration);
+    }
+
+    /// Pulls `amount` of the pool's LP token from the caller and stakes it
+    fn stake(&self, pool_id: U256, amount: U256) {
+        if amount.is_zero() {
+            runtime::revert(Errors::StakingRewardsInvalidAmount);
+        }
+        let user: Key = self.get_caller();
+        let mut pool: StakingPool = self.update_reward(pool_id, user);
+        pool.total_supply = pool
+            .total_supply
+            .checked_add(amount)
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow);
+        let stake: U256 = Stakes::instance()
+            .get(&pool_id, &user)
+            .checked_add(amount)
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow);
+        Stakes::instance().set(&pool_id, &user, stake);
+        Pools::instance().set(pool_id, pool.clone());
+        self.transfer_from(pool.lp_token, user, Key::from(get_package_hash()), amount);
+        self.emit(&STAKINGREWARDSEvent::Staked {
+            user,
+            pool_id,
+            amount,
+        });
+    }
+
+    /// Sends `amount` of the caller's stake in the pool back to the caller
+    fn withdraw(&self, pool_id: U256, amount: U256) {
+        if amount.is_zero() {
+            runtime::revert(Errors::StakingRewardsInvalidAmount);
+        }
+        let user: Key = self.get_caller();
+        let mut pool: StakingPool = self.update_reward(pool_id, user);
+        let stake: U256 = Stakes::instance().get(&pool_id, &user);
+        if amount > stake {
+            runtime::revert(Errors::StakingRewardsInsufficientBalance);
+        }
+        pool.total_supply = pool
+            .total_supply
+            .checked_sub(amount)
+            .unwrap_or_revert_with(Errors::StakingRewardsUnderFlow);
+        Stakes::instance().set(&pool_id, &user, stake - amount);
+        Pools::instance().set(pool_id, pool.clone());
+        self.transfer(pool.lp_token, user, amount);
+        self.emit(&STAKINGREWARDSEvent::Withdrawn {
+            user,
+            pool_id,
+            amount,
+        });
+    }
+
+    /// Sends the caller the rewards accrued on its stake in the pool
+    fn get_reward(&self, pool_id: U256) -> U256 {
+        let user: Key = self.get_caller();
+        self.update_reward(pool_id, user);
+        let reward: U256 = Rewards::instance().get(&pool_id, &user);
+        if !reward.is_zero() {
+            Rewards::instance().set(&pool_id, &user, 0.into());
+            self.transfer(get_rewards_token(), user, reward);
+            self.emit(&STAKINGREWARDSEvent::RewardPaid {
+                user,
+                pool_id,
+                reward,
+            });
+        }
+        reward
+    }
+
+    /// Withdraws the caller's whole stake in the pool and sends its rewards
+    fn exit(&self, pool_id: U256) -> U256 {
+        let stake: U256 = Stakes::instance().get(&pool_id, &self.get_caller());
+        if !stake.is_zero() {
+            self.withdraw(pool_id, stake);
+        }
+        self.get_reward(pool_id)
+    }
+
+    fn get_pool(&self, pool_id: U256) -> StakingPool {
+        Pools::instance()
+            .get(pool_id)
+            .unwrap_or_revert_with(Errors::StakingRewardsPoolNotFound)
+    }
+
+    fn balance_of(&self, pool_id: U256, account: Key) -> U256 {
+        Stakes::instance().get(&pool_id, &account)
+    }
+
+    /// Block time in seconds
+    fn now(&self) -> U256 {
+        U256::from(block_timestamp() / 1000)
+    }
+
+    /// The current block time, or the end of the reward period if it is over
+    fn last_time_reward_applicable(&self) -> U256 {
+        self.now().min(get_period_finish())
+    }
+
+    /// Rewards accrued per staked token of the pool since it was added, scaled by 10^18
+    fn reward_per_token(&self, pool_id: U256) -> U256 {
+        let pool: StakingPool = self.get_pool(pool_id);
+        self.pool_reward_per_token(&pool)
+    }
+
+    fn pool_reward_per_token(&self, pool: &StakingPool) -> U256 {
+        let total_alloc_point: U256 = get_total_alloc_point();
+        if pool.total_supply.is_zero() || total_alloc_point.is_zero() {
+            return pool.reward_per_token_stored;
+        }
+        let elapsed: U256 = self
+            .last_time_reward_applicable()
+            .saturating_sub(pool.last_update_time);
+        pool.reward_per_token_stored
+            .checked_add(
+                elapsed
+                    .checked_mul(get_reward_rate())
+                    .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+                    .checked_mul(pool.alloc_point)
+                    .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+                    .checked_mul(PRECISION.into())
+                    .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+                    / total_alloc_point
+                    / pool.total_supply,
+            )
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+    }
+
+    /// Rewards of the pool accrued to `account` and not paid yet
+    fn earned(&self, pool_id: U256, account: Key) -> U256 {
+        let pool: StakingPool = self.get_pool(pool_id);
+        self.account_earned(pool_id, self.pool_reward_per_token(&pool), account)
+    }
+
+    fn account_earned(&self, pool_id: U256, reward_per_token: U256, account: Key) -> U256 {
+        let accrued: U256 = Stakes::instance()
+            .get(&pool_id, &account)
+            .checked_mul(
+                reward_per_token
+                    .checked_sub(UserRewardPerTokenPaid::instance().get(&pool_id, &account))
+                    .unwrap_or_revert_with(Errors::StakingRewardsUnderFlow),
+            )
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+            / PRECISION;
+        accrued
+            .checked_add(Rewards::instance().get(&pool_id, &account))
+            .unwrap_or_revert_with(Errors::StakingRewardsOverFlow)
+    }
+
+    /// Brings the stored reward per token of the pool up to now and returns the pool
+    fn update_pool(&self, pool_id: U256) -> StakingPool {
+        let mut pool: StakingPool = self.get_pool(pool_id);
+        pool.reward_per_token_stored = self.pool_reward_per_token(&pool);
+        pool.last_update_time = self
+            .last_time_reward_applicable()
+            .max(pool.last_update_time);
+        Pools::instance().set(pool_id, pool.clone());
+        pool
+    }
+
+    fn mass_update_pools(&self) {
+        for pool_id in 0..get_pools_count().as_u64() {
+            self.update_pool(pool_id.into());
+        }
+    }
+
+    /// Updates the pool and settles the rewards of `account` in it, returns the pool
+    fn update_reward(&self, pool_id: U256, account: Key) -> StakingPool {
+        let pool: StakingPool = self.update_pool(pool_id);
+        let earned: U256 = self.account_earned(pool_id, pool.reward_per_token_stored, account);
+        Rewards::instance().set(&pool_id, &account, earned);
+        UserRewardPerTokenPaid::instance().set(&pool_id, &account, pool.reward_per_token_stored);
+        pool
+    }
+
+    fn transfer(&self, token: Key, recipient: Key, amount: U256) {
+        let () = runtime::call_versioned_contract(
+            token.into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer",
+            runtime_args! {
+                "recipient" => Address::from(recipient),
+                "amount" => amount
+            },
+        );
+    }
+
+    fn transfer_from(&self, token: Key, owner: Key, recipient: Key, amount: U256) {
+        let () = runtime::call_versioned_contract(
+            token.into_hash().unwrap_or_revert().into(),
+            None,
+            "transfer_from",
+            runtime_args! {
+                "owner" => Address::from(owner),
+                "recipient" => Address::from(recipient),
+                "amount" => amount
+            },
+        );
+    }
+
+    fn emit(&self, staking_rewards_event: &STAKINGREWARDSEvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", staking_rewards_event.type_name());
+        match staking_rewards_event {
+            STAKINGREWARDSEvent::PoolAdded {
+                pool_id,
+                lp_token,
+                alloc_point,
+            } => {
+                event.insert("pool_id", pool_id.to_string());
+                event.insert("lp_token", lp_token.to_string());
+                event.insert("alloc_point", alloc_point.to_string());
+            }
+            STAKINGREWARDSEvent::PoolSet {
+                pool_id,
+                alloc_point,
+            } => {
+                event.insert("pool_id", pool_id.to_string());
+                event.insert("alloc_point", alloc_point.to_string());
+            }
+            STAKINGREWARDSEvent::RewardAdded {
+                reward,
+                reward_rate,
+                period_finish,
+            } => {
+                event.insert("reward", reward.to_string());
+                event.insert("reward_rate", reward_rate.to_string());
+                event.insert("period_finish", period_finish.to_string());
+            }
+            STAKINGREWARDSEvent::Staked {
+                user,
+                pool_id,
+                amount,
+            } => {
+                event.insert("user", user.to_string());
+                event.insert("pool_id", pool_id.to_string());
+                event.insert("amount", amount.to_string());
+            }
+            STAKINGREWARDSEvent::Withdrawn {
+                user,
+                pool_id,
+                amount,
+            } => {
+                event.insert("user", user.to_string());
+                event.insert("pool_id", pool_id.to_string());
+                event.insert("amount", amount.to_string());
+            }
+            STAKINGREWARDSEvent::RewardPaid {
+                user,
+                pool_id,
+                reward,
+            } => {
+                event.insert("user", user.to_string());
+                event.insert("pool_id", pool_id.to_string());
+                event.insert("reward", reward.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod staking_rewards_tests;
+
+#[cfg(test)]
+pub mod staking_rewards_instance;
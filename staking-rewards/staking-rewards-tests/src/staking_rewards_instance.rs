@@ -0,0 +1,102 @@
+use tests_common::{
+    account::AccountHash, deploys::deploy_staking_rewards, staking_pool::StakingPool, *,
+};
+
+pub struct STAKINGREWARDSInstance(pub TestContract);
+
+impl STAKINGREWARDSInstance {
+    pub fn new(
+        env: &TestEnv,
+        sender: AccountHash,
+        rewards_token: Key,
+        rewards_duration: U256,
+        time: u64,
+    ) -> STAKINGREWARDSInstance {
+        STAKINGREWARDSInstance(deploy_staking_rewards(
+            env,
+            sender,
+            rewards_token,
+            rewards_duration,
+            time,
+        ))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    pub fn add_pool(&self, sender: AccountHash, lp_token: Key, alloc_point: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "add_pool",
+            runtime_args! {
+                "lp_token" => lp_token,
+                "alloc_point" => alloc_point,
+            },
+            time,
+        );
+    }
+
+    pub fn notify_reward_amount(&self, sender: AccountHash, reward: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "notify_reward_amount",
+            runtime_args! {
+                "reward" => reward,
+            },
+            time,
+        );
+    }
+
+    pub fn stake(&self, sender: AccountHash, pool_id: U256, amount: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "stake",
+            runtime_args! {
+                "pool_id" => pool_id,
+                "amount" => amount,
+            },
+            time,
+        );
+    }
+
+    pub fn withdraw(&self, sender: AccountHash, pool_id: U256, amount: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "withdraw",
+            runtime_args! {
+                "pool_id" => pool_id,
+                "amount" => amount,
+            },
+            time,
+        );
+    }
+
+    pub fn get_reward(&self, sender: AccountHash, pool_id: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "get_reward",
+            runtime_args! {
+                "pool_id" => pool_id,
+            },
+            time,
+        );
+    }
+
+    pub fn exit(&self, sender: AccountHash, pool_id: U256, time: u64) {
+        self.0.call_contract(
+            sender,
+            "exit",
+            runtime_args! {
+                "pool_id" => pool_id,
+            },
+            time,
+        );
+    }
+
+    pub fn pool(&self, pool_id: U256) -> StakingPool {
+        self.0
+            .query_dictionary("pools", pool_id.to_string())
+            .unwrap()
+    }
+}
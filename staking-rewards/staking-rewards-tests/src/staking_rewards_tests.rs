@@ -0,0 +1,217 @@
+use crate::staking_rewards_instance::STAKINGREWARDSInstance;
+use tests_common::{account::AccountHash, deploys::*, helpers::*, *};
+
+/// Length of a reward period in seconds
+const DURATION: u64 = 1_000;
+const REWARD: u128 = 1_000_000_000;
+const STAKE: u128 = 1_000_000_000;
+
+/// Returns the staking contract with a funded reward period starting at `time`, its rewards token
+/// and two LP tokens. Any ERC20 stands in for the LP token of a pair, as staking only transfers it
+fn deploy(
+    time: u64,
+) -> (
+    TestEnv,
+    AccountHash,
+    STAKINGREWARDSInstance,
+    TestContract,
+    Vec<TestContract>,
+) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let rewards_token =
+        deploy_erc20_secure(&env, "REWARDS", owner, "Rewards", "RWD", 9, 0.into(), time);
+    let lp_tokens: Vec<TestContract> = (0..2)
+        .map(|i| {
+            deploy_erc20(
+                &env,
+                &format!("LP-{}", i),
+                owner,
+                "Liquidity",
+                "LP",
+                9,
+                0.into(),
+                time,
+            )
+        })
+        .collect();
+    let staking = STAKINGREWARDSInstance::new(
+        &env,
+        owner,
+        Key::Hash(rewards_token.package_hash()),
+        DURATION.into(),
+        time,
+    );
+    rewards_token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(owner),
+            "amount" => U256::from(REWARD)
+        },
+        time,
+    );
+    approve(&rewards_token, owner, &staking, REWARD.into(), time);
+    (env, owner, staking, rewards_token, lp_tokens)
+}
+
+fn approve(
+    token: &TestContract,
+    owner: AccountHash,
+    staking: &STAKINGREWARDSInstance,
+    amount: U256,
+    time: u64,
+) {
+    token.call_contract(
+        owner,
+        "approve",
+        runtime_args! {
+            "spender" => Address::Contract(staking.0.package_hash().into()),
+            "amount" => amount
+        },
+        time,
+    );
+}
+
+/// Mints STAKE of `lp_token` to a new user and stakes it in the pool
+fn stake(
+    env: &TestEnv,
+    owner: AccountHash,
+    staking: &STAKINGREWARDSInstance,
+    lp_token: &TestContract,
+    pool_id: U256,
+    time: u64,
+) -> AccountHash {
+    let user = env.next_user();
+    lp_token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(user),
+            "amount" => U256::from(STAKE)
+        },
+        time,
+    );
+    approve(lp_token, user, staking, STAKE.into(), time);
+    staking.stake(user, pool_id, STAKE.into(), time);
+    user
+}
+
+fn balance(token: &TestContract, owner: AccountHash) -> U256 {
+    token.query(BALANCES, address_to_str(&Address::Account(owner)))
+}
+
+#[test]
+fn test_stake_and_get_reward() {
+    let time = now();
+    let (env, owner, staking, rewards_token, lp_tokens) = deploy(time);
+    staking.add_pool(
+        owner,
+        Key::Hash(lp_tokens[0].package_hash()),
+        100.into(),
+        time,
+    );
+    staking.notify_reward_amount(owner, REWARD.into(), time);
+    let user = stake(&env, owner, &staking, &lp_tokens[0], 0.into(), time);
+    assert_eq!(staking.pool(0.into()).total_supply, STAKE.into());
+    assert_eq!(balance(&lp_tokens[0], user), 0.into());
+    // half the period at 1,000,000 per second
+    staking.get_reward(user, 0.into(), time + DURATION * 1_000 / 2);
+    assert_eq!(balance(&rewards_token, user), (REWARD / 2).into());
+}
+
+#[test]
+fn test_rewards_split_by_alloc_point() {
+    let time = now();
+    let (env, owner, staking, rewards_token, lp_tokens) = deploy(time);
+    staking.add_pool(
+        owner,
+        Key::Hash(lp_tokens[0].package_hash()),
+        100.into(),
+        time,
+    );
+    staking.add_pool(
+        owner,
+        Key::Hash(lp_tokens[1].package_hash()),
+        300.into(),
+        time,
+    );
+    staking.notify_reward_amount(owner, REWARD.into(), time);
+    let user0 = stake(&env, owner, &staking, &lp_tokens[0], 0.into(), time);
+    let user1 = stake(&env, owner, &staking, &lp_tokens[1], 1.into(), time);
+    // well past the end of the period, nothing accrues after it
+    let later = time + DURATION * 2_000;
+    staking.get_reward(user0, 0.into(), later);
+    staking.get_reward(user1, 1.into(), later);
+    assert_eq!(balance(&rewards_token, user0), (REWARD / 4).into());
+    assert_eq!(balance(&rewards_token, user1), (REWARD * 3 / 4).into());
+}
+
+#[test]
+fn test_exit() {
+    let time = now();
+    let (env, owner, staking, rewards_token, lp_tokens) = deploy(time);
+    staking.add_pool(
+        owner,
+        Key::Hash(lp_tokens[0].package_hash()),
+        100.into(),
+        time,
+    );
+    staking.notify_reward_amount(owner, REWARD.into(), time);
+    let user = stake(&env, owner, &staking, &lp_tokens[0], 0.into(), time);
+    staking.exit(user, 0.into(), time + DURATION * 1_000);
+    assert_eq!(balance(&lp_tokens[0], user), STAKE.into());
+    assert_eq!(balance(&rewards_token, user), REWARD.into());
+    assert_eq!(staking.pool(0.into()).total_supply, 0.into());
+}
+
+#[test]
+#[should_panic] // As the user withdraws more than staked
+fn test_withdraw_more_than_staked() {
+    let time = now();
+    let (env, owner, staking, _, lp_tokens) = deploy(time);
+    staking.add_pool(
+        owner,
+        Key::Hash(lp_tokens[0].package_hash()),
+        100.into(),
+        time,
+    );
+    let user = stake(&env, owner, &staking, &lp_tokens[0], 0.into(), time);
+    staking.withdraw(user, 0.into(), (STAKE + 1).into(), time);
+}
+
+#[test]
+#[should_panic] // As only the owner funds reward periods
+fn test_notify_reward_amount_not_owner() {
+    let time = now();
+    let (env, _, staking, _, _) = deploy(time);
+    staking.notify_reward_amount(env.next_user(), REWARD.into(), time);
+}
+
+#[test]
+#[should_panic(expected = "User(192)")] // As stakes of the rewards token would be paid out as rewards
+fn test_add_pool_of_rewards_token() {
+    let time = now();
+    let (_, owner, staking, rewards_token, _) = deploy(time);
+    staking.add_pool(
+        owner,
+        Key::Hash(rewards_token.package_hash()),
+        100.into(),
+        time,
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(193)")] // As the LP token already has a pool
+fn test_add_pool_twice() {
+    let time = now();
+    let (_, owner, staking, _, lp_tokens) = deploy(time);
+    for _ in 0..2 {
+        staking.add_pool(
+            owner,
+            Key::Hash(lp_tokens[0].package_hash()),
+            100.into(),
+            time,
+        );
+    }
+}
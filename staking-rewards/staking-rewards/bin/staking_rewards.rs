@@ -0,0 +1,545 @@
+#![no_main]
+
+use staking_rewards_crate::{
+    contract_api::{runtime, storage},
+    data::*,
+    staking_pool::StakingPool,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct StakingRewards(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for StakingRewards {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl STAKINGREWARDS<OnChainContractStorage> for StakingRewards {}
+
+impl StakingRewards {
+    fn constructor(
+        &self,
+        rewards_token: Key,
+        rewards_duration: U256,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        STAKINGREWARDS::init(
+            self,
+            rewards_token,
+            rewards_duration,
+            contract_hash,
+            package_hash,
+        );
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let rewards_token: Key = runtime::get_named_arg("rewards_token");
+    let rewards_duration: U256 = runtime::get_named_arg("rewards_duration");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    StakingRewards::default().constructor(
+        rewards_token,
+        rewards_duration,
+        contract_hash,
+        package_hash,
+    );
+}
+
+/// This function is to register a pool staking an LP token, returns the pool id
+///
+/// # Parameters
+///
+/// * `lp_token` - A Key that holds the Hash of the LP token staked in the pool
+///
+/// * `alloc_point` - A U256 that holds the shares of the reward rate the pool gets, out of the sum over all pools
+///
+
+#[no_mangle]
+fn add_pool() {
+    let lp_token: Key = runtime::get_named_arg("lp_token");
+    let alloc_point: U256 = runtime::get_named_arg("alloc_point");
+    let ret: U256 = StakingRewards::default().add_pool(lp_token, alloc_point);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to change the shares of the reward rate of a pool
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+/// * `alloc_point` - A U256 that holds the new shares of the reward rate of the pool
+///
+
+#[no_mangle]
+fn set_pool() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let alloc_point: U256 = runtime::get_named_arg("alloc_point");
+    StakingRewards::default().set_pool(pool_id, alloc_point);
+}
+
+/// This function is to fund a new reward period, the reward is pulled from the owner, who has to approve the contract on the rewards token first
+///
+/// # Parameters
+///
+/// * `reward` - A U256 that holds the amount of rewards token spread over the period, with what is left of the running one
+///
+
+#[no_mangle]
+fn notify_reward_amount() {
+    let reward: U256 = runtime::get_named_arg("reward");
+    StakingRewards::default().notify_reward_amount(reward);
+}
+
+/// This function is to set the length in seconds of the next reward periods, once the running one has finished
+///
+/// # Parameters
+///
+/// * `rewards_duration` - A U256 that holds the length of a reward period in seconds
+///
+
+#[no_mangle]
+fn set_rewards_duration() {
+    let rewards_duration: U256 = runtime::get_named_arg("rewards_duration");
+    StakingRewards::default().set_rewards_duration(rewards_duration);
+}
+
+/// This function is to stake LP tokens in a pool, they are pulled from the caller who has to approve the contract on the LP token first
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+/// * `amount` - A U256 that holds the amount of LP tokens staked
+///
+
+#[no_mangle]
+fn stake() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    StakingRewards::default().stake(pool_id, amount);
+}
+
+/// This function is to withdraw staked LP tokens from a pool
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+/// * `amount` - A U256 that holds the amount of LP tokens withdrawn
+///
+
+#[no_mangle]
+fn withdraw() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    StakingRewards::default().withdraw(pool_id, amount);
+}
+
+/// This function is to send the caller the rewards accrued on its stake in a pool
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+
+#[no_mangle]
+fn get_reward() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let ret: U256 = StakingRewards::default().get_reward(pool_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to withdraw the caller's whole stake in a pool and send its rewards
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+
+#[no_mangle]
+fn exit() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let ret: U256 = StakingRewards::default().exit(pool_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return a pool with its LP token, shares, staked supply and accrued reward per token
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+
+#[no_mangle]
+fn get_pool() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let ret: StakingPool = StakingRewards::default().get_pool(pool_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the amount an account staked in a pool
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+/// * `account` - A Key that holds the account or contract that staked
+///
+
+#[no_mangle]
+fn balance_of() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let account: Key = runtime::get_named_arg("account");
+    let ret: U256 = StakingRewards::default().balance_of(pool_id, account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the rewards of a pool accrued to an account and not paid yet
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+/// * `account` - A Key that holds the account or contract that staked
+///
+
+#[no_mangle]
+fn earned() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let account: Key = runtime::get_named_arg("account");
+    let ret: U256 = StakingRewards::default().earned(pool_id, account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the rewards accrued per staked token of a pool, scaled by 10^18
+///
+/// # Parameters
+///
+/// * `pool_id` - A U256 that holds the id of the pool
+///
+
+#[no_mangle]
+fn reward_per_token() {
+    let pool_id: U256 = runtime::get_named_arg("pool_id");
+    let ret: U256 = StakingRewards::default().reward_per_token(pool_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the current block time in seconds, or the end of the reward period if it is over
+///
+
+#[no_mangle]
+fn last_time_reward_applicable() {
+    let ret: U256 = StakingRewards::default().last_time_reward_applicable();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to return the token rewards are paid in
+///
+
+#[no_mangle]
+fn rewards_token() {
+    runtime::ret(CLValue::from_t(get_rewards_token()).unwrap_or_revert());
+}
+
+/// This function is to return the length of a reward period in seconds
+///
+
+#[no_mangle]
+fn rewards_duration() {
+    runtime::ret(CLValue::from_t(get_rewards_duration()).unwrap_or_revert());
+}
+
+/// This function is to return the rewards paid per second over all pools
+///
+
+#[no_mangle]
+fn reward_rate() {
+    runtime::ret(CLValue::from_t(get_reward_rate()).unwrap_or_revert());
+}
+
+/// This function is to return the block time in seconds the running reward period ends at
+///
+
+#[no_mangle]
+fn period_finish() {
+    runtime::ret(CLValue::from_t(get_period_finish()).unwrap_or_revert());
+}
+
+/// This function is to return the sum of the shares of the reward rate of all pools
+///
+
+#[no_mangle]
+fn total_alloc_point() {
+    runtime::ret(CLValue::from_t(get_total_alloc_point()).unwrap_or_revert());
+}
+
+/// This function is to return the number of pools, the next pool id
+///
+
+#[no_mangle]
+fn pools_count() {
+    runtime::ret(CLValue::from_t(get_pools_count()).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("rewards_token", Key::cl_type()),
+            Parameter::new("rewards_duration", U256::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "add_pool",
+        vec![
+            Parameter::new("lp_token", Key::cl_type()),
+            Parameter::new("alloc_point", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_pool",
+        vec![
+            Parameter::new("pool_id", U256::cl_type()),
+            Parameter::new("alloc_point", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "notify_reward_amount",
+        vec![Parameter::new("reward", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_rewards_duration",
+        vec![Parameter::new("rewards_duration", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "stake",
+        vec![
+            Parameter::new("pool_id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw",
+        vec![
+            Parameter::new("pool_id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_reward",
+        vec![Parameter::new("pool_id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "exit",
+        vec![Parameter::new("pool_id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_pool",
+        vec![Parameter::new("pool_id", U256::cl_type())],
+        StakingPool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![
+            Parameter::new("pool_id", U256::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "earned",
+        vec![
+            Parameter::new("pool_id", U256::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reward_per_token",
+        vec![Parameter::new("pool_id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "last_time_reward_applicable",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rewards_token",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rewards_duration",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reward_rate",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "period_finish",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_alloc_point",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pools_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let rewards_token: Key = runtime::get_named_arg("rewards_token");
+        let rewards_duration: U256 = runtime::get_named_arg("rewards_duration");
+
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "rewards_token" => rewards_token,
+            "rewards_duration" => rewards_duration,
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
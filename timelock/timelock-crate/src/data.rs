@@ -0,0 +1,58 @@
+use common::{functions::zero_address, keys::*, *};
+
+pub fn set_admin(admin: Key) {
+    set_key(ADMIN, admin);
+}
+
+pub fn get_admin() -> Key {
+    get_key(ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_pending_admin(pending_admin: Key) {
+    set_key(PENDING_ADMIN, pending_admin);
+}
+
+pub fn get_pending_admin() -> Key {
+    get_key(PENDING_ADMIN).unwrap_or_else(zero_address)
+}
+
+pub fn set_admin_initialized(admin_initialized: bool) {
+    set_key(ADMIN_INITIALIZED, admin_initialized);
+}
+
+pub fn get_admin_initialized() -> bool {
+    get_key(ADMIN_INITIALIZED).unwrap_or_default()
+}
+
+pub fn set_delay(delay: u64) {
+    set_key(DELAY, delay);
+}
+
+pub fn get_delay() -> u64 {
+    get_key(DELAY).unwrap_or_default()
+}
+
+/// Whether a transaction is queued, by its hash
+pub struct QueuedTransactions {
+    dict: Dict,
+}
+
+impl QueuedTransactions {
+    pub fn instance() -> QueuedTransactions {
+        QueuedTransactions {
+            dict: Dict::instance(QUEUED_TRANSACTIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(QUEUED_TRANSACTIONS_DICT)
+    }
+
+    pub fn get(&self, tx_hash: &Key) -> bool {
+        self.dict.get_by_key(tx_hash).unwrap_or_default()
+    }
+
+    pub fn set(&self, tx_hash: &Key, queued: bool) {
+        self.dict.set_by_key(tx_hash, queued);
+    }
+}
@@ -0,0 +1,60 @@
+use common::*;
+
+pub enum TIMELOCKEvent {
+    NewAdmin {
+        admin: Key,
+    },
+    NewPendingAdmin {
+        pending_admin: Key,
+    },
+    NewDelay {
+        delay: u64,
+    },
+    QueueTransaction {
+        tx_hash: Key,
+        target: Key,
+        entry_point: String,
+        eta: u64,
+    },
+    CancelTransaction {
+        tx_hash: Key,
+        target: Key,
+        entry_point: String,
+        eta: u64,
+    },
+    ExecuteTransaction {
+        tx_hash: Key,
+        target: Key,
+        entry_point: String,
+        eta: u64,
+    },
+}
+
+impl TIMELOCKEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            TIMELOCKEvent::NewAdmin { admin: _ } => "new_admin",
+            TIMELOCKEvent::NewPendingAdmin { pending_admin: _ } => "new_pending_admin",
+            TIMELOCKEvent::NewDelay { delay: _ } => "new_delay",
+            TIMELOCKEvent::QueueTransaction {
+                tx_hash: _,
+                target: _,
+                entry_point: _,
+                eta: _,
+            } => "queue_transaction",
+            TIMELOCKEvent::CancelTransaction {
+                tx_hash: _,
+                target: _,
+                entry_point: _,
+                eta: _,
+            } => "cancel_transaction",
+            TIMELOCKEvent::ExecuteTransaction {
+                tx_hash: _,
+                target: _,
+                entry_point: _,
+                eta: _,
+            } => "execute_transaction",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod data;
+pub mod event;
+mod timelock;
+
+pub use common::*;
+pub use timelock::{GRACE_PERIOD, MAXIMUM_DELAY, MINIMUM_DELAY, TIMELOCK};
@@ -0,0 +1,212 @@
+use crate::{data::*, event::TIMELOCKEvent};
+use common::{
+    bytesrepr::{self, Bytes, ToBytes},
+    contract_api::{runtime, storage},
+    errors::Errors,
+    functions::{block_timestamp, zero_address},
+    multicall::RawBytes,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+/// Time after its eta a queued transaction can still be executed, 14 days in milliseconds
+pub const GRACE_PERIOD: u64 = 1_209_600_000;
+/// Bounds of the delay between queueing and executing a transaction, 2 and 30 days in milliseconds
+pub const MINIMUM_DELAY: u64 = 172_800_000;
+pub const MAXIMUM_DELAY: u64 = 2_592_000_000;
+
+pub trait TIMELOCK<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(
+        &self,
+        admin: Key,
+        delay: u64,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        self.check_delay(delay);
+        set_admin(admin);
+        set_delay(delay);
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+        QueuedTransactions::init();
+    }
+
+    fn only_admin(&self) {
+        if self.get_caller() != get_admin() {
+            runtime::revert(Errors::TimelockNotAdmin);
+        }
+    }
+
+    /// The settings of the timelock only change through transactions it executes itself
+    fn only_timelock(&self) {
+        if self.get_caller() != Key::from(get_package_hash()) {
+            runtime::revert(Errors::TimelockNotTimelock);
+        }
+    }
+
+    fn check_delay(&self, delay: u64) {
+        if !(MINIMUM_DELAY..=MAXIMUM_DELAY).contains(&delay) {
+            runtime::revert(Errors::TimelockInvalidDelay);
+        }
+    }
+
+    fn set_delay(&self, delay: u64) {
+        self.only_timelock();
+        self.check_delay(delay);
+        set_delay(delay);
+        self.emit(&TIMELOCKEvent::NewDelay { delay });
+    }
+
+    fn accept_admin(&self) {
+        let caller: Key = self.get_caller();
+        if caller != get_pending_admin() {
+            runtime::revert(Errors::TimelockNotPendingAdmin);
+        }
+        set_admin(caller);
+        set_pending_admin(zero_address());
+        self.emit(&TIMELOCKEvent::NewAdmin { admin: caller });
+    }
+
+    /// Names the next admin, who takes over with `accept_admin`. The admin set at deployment
+    /// names its successor directly once, for example a governor, later ones only through a
+    /// queued transaction
+    fn set_pending_admin(&self, pending_admin: Key) {
+        if get_admin_initialized() {
+            self.only_timelock();
+        } else {
+            self.only_admin();
+            set_admin_initialized(true);
+        }
+        set_pending_admin(pending_admin);
+        self.emit(&TIMELOCKEvent::NewPendingAdmin { pending_admin });
+    }
+
+    /// Queues a call of `entry_point` of the `target` package with `args`, serialized
+    /// `RuntimeArgs`, to be executed from `eta`, at least `delay` from now
+    fn queue_transaction(&self, target: Key, entry_point: String, args: Bytes, eta: u64) -> Key {
+        self.only_admin();
+        let earliest_eta: u64 = block_timestamp()
+            .checked_add(get_delay())
+            .unwrap_or_revert_with(Errors::TimelockEtaTooEarly);
+        if eta < earliest_eta {
+            runtime::revert(Errors::TimelockEtaTooEarly);
+        }
+        let tx_hash: Key = self.transaction_hash(target, &entry_point, &args, eta);
+        let queued_transactions = QueuedTransactions::instance();
+        if queued_transactions.get(&tx_hash) {
+            runtime::revert(Errors::TimelockAlreadyQueued);
+        }
+        queued_transactions.set(&tx_hash, true);
+        self.emit(&TIMELOCKEvent::QueueTransaction {
+            tx_hash,
+            target,
+            entry_point,
+            eta,
+        });
+        tx_hash
+    }
+
+    fn cancel_transaction(&self, target: Key, entry_point: String, args: Bytes, eta: u64) -> Key {
+        self.only_admin();
+        let tx_hash: Key = self.transaction_hash(target, &entry_point, &args, eta);
+        QueuedTransactions::instance().set(&tx_hash, false);
+        self.emit(&TIMELOCKEvent::CancelTransaction {
+            tx_hash,
+            target,
+            entry_point,
+            eta,
+        });
+        tx_hash
+    }
+
+    /// Executes a queued transaction between its eta and the end of the grace period, returning
+    /// the raw bytes of what the call returned
+    fn execute_transaction(
+        &self,
+        target: Key,
+        entry_point: String,
+        args: Bytes,
+        eta: u64,
+    ) -> Bytes {
+        self.only_admin();
+        let tx_hash: Key = self.transaction_hash(target, &entry_point, &args, eta);
+        let queued_transactions = QueuedTransactions::instance();
+        if !queued_transactions.get(&tx_hash) {
+            runtime::revert(Errors::TimelockNotQueued);
+        }
+        let now: u64 = block_timestamp();
+        if now < eta {
+            runtime::revert(Errors::TimelockNotReady);
+        }
+        if now > eta.saturating_add(GRACE_PERIOD) {
+            runtime::revert(Errors::TimelockStale);
+        }
+        queued_transactions.set(&tx_hash, false);
+        let runtime_args: RuntimeArgs =
+            bytesrepr::deserialize(args.into()).unwrap_or_revert_with(Errors::TimelockInvalidArgs);
+        let ret: RawBytes = runtime::call_versioned_contract(
+            target.into_hash().unwrap_or_revert().into(),
+            None,
+            &entry_point,
+            runtime_args,
+        );
+        self.emit(&TIMELOCKEvent::ExecuteTransaction {
+            tx_hash,
+            target,
+            entry_point,
+            eta,
+        });
+        Bytes::from(ret.0)
+    }
+
+    /// Blake2b hash of the serialized transaction, as a `Key::Hash`
+    fn transaction_hash(&self, target: Key, entry_point: &str, args: &Bytes, eta: u64) -> Key {
+        let mut preimage: Vec<u8> = target.to_bytes().unwrap_or_revert();
+        preimage.append(&mut entry_point.to_string().to_bytes().unwrap_or_revert());
+        preimage.append(&mut args.to_bytes().unwrap_or_revert());
+        preimage.append(&mut eta.to_bytes().unwrap_or_revert());
+        Key::Hash(runtime::blake2b(preimage))
+    }
+
+    fn emit(&self, timelock_event: &TIMELOCKEvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", timelock_event.type_name());
+        match timelock_event {
+            TIMELOCKEvent::NewAdmin { admin } => {
+                event.insert("admin", admin.to_string());
+            }
+            TIMELOCKEvent::NewPendingAdmin { pending_admin } => {
+                event.insert("pending_admin", pending_admin.to_string());
+            }
+            TIMELOCKEvent::NewDelay { delay } => {
+                event.insert("delay", delay.to_string());
+            }
+            TIMELOCKEvent::QueueTransaction {
+                tx_hash,
+                target,
+                entry_point,
+                eta,
+            }
+            | TIMELOCKEvent::CancelTransaction {
+                tx_hash,
+                target,
+                entry_point,
+                eta,
+            }
+            | TIMELOCKEvent::ExecuteTransaction {
+                tx_hash,
+                target,
+                entry_point,
+                eta,
+            } => {
+                event.insert("tx_hash", tx_hash.to_string());
+                event.insert("target", target.to_string());
+                event.insert("entry_point", entry_point.to_string());
+                event.insert("eta", eta.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod timelock_tests;
+
+#[cfg(test)]
+pub mod timelock_instance;
@@ -0,0 +1,164 @@
+use tests_common::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    deploys::deploy_timelock,
+    digest::{Update, VariableOutput},
+    helpers::*,
+    *,
+};
+
+pub struct TIMELOCKInstance(pub TestContract);
+
+impl TIMELOCKInstance {
+    pub fn new(
+        env: &TestEnv,
+        sender: AccountHash,
+        admin: Key,
+        delay: u64,
+        time: u64,
+    ) -> TIMELOCKInstance {
+        TIMELOCKInstance(deploy_timelock(env, sender, admin, delay, time))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn call_transaction(
+        &self,
+        sender: AccountHash,
+        entry_point: &str,
+        target: Key,
+        target_entry_point: &str,
+        args: &Bytes,
+        eta: u64,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            entry_point,
+            runtime_args! {
+                "target" => target,
+                "entry_point" => target_entry_point.to_string(),
+                "args" => args.clone(),
+                "eta" => eta,
+            },
+            time,
+        );
+    }
+
+    pub fn queue_transaction(
+        &self,
+        sender: AccountHash,
+        target: Key,
+        entry_point: &str,
+        args: &Bytes,
+        eta: u64,
+        time: u64,
+    ) {
+        self.call_transaction(
+            sender,
+            "queue_transaction",
+            target,
+            entry_point,
+            args,
+            eta,
+            time,
+        );
+    }
+
+    pub fn cancel_transaction(
+        &self,
+        sender: AccountHash,
+        target: Key,
+        entry_point: &str,
+        args: &Bytes,
+        eta: u64,
+        time: u64,
+    ) {
+        self.call_transaction(
+            sender,
+            "cancel_transaction",
+            target,
+            entry_point,
+            args,
+            eta,
+            time,
+        );
+    }
+
+    pub fn execute_transaction(
+        &self,
+        sender: AccountHash,
+        target: Key,
+        entry_point: &str,
+        args: &Bytes,
+        eta: u64,
+        time: u64,
+    ) {
+        self.call_transaction(
+            sender,
+            "execute_transaction",
+            target,
+            entry_point,
+            args,
+            eta,
+            time,
+        );
+    }
+
+    pub fn set_pending_admin(&self, sender: AccountHash, pending_admin: Key, time: u64) {
+        self.0.call_contract(
+            sender,
+            "set_pending_admin",
+            runtime_args! {
+                "pending_admin" => pending_admin,
+            },
+            time,
+        );
+    }
+
+    pub fn accept_admin(&self, sender: AccountHash, time: u64) {
+        self.0
+            .call_contract(sender, "accept_admin", runtime_args! {}, time);
+    }
+
+    pub fn admin(&self) -> Key {
+        self.0.query_named_key(String::from("admin"))
+    }
+
+    pub fn pending_admin(&self) -> Key {
+        self.0.query_named_key(String::from("pending_admin"))
+    }
+
+    pub fn queued_transactions(&self, tx_hash: [u8; 32]) -> bool {
+        self.0
+            .query_dictionary("queued_transactions", key_to_str(&Key::Hash(tx_hash)))
+            .unwrap_or_default()
+    }
+
+    /// Hash the timelock keeps a transaction under, computed the same way
+    pub fn transaction_hash(
+        &self,
+        target: Key,
+        entry_point: &str,
+        args: &Bytes,
+        eta: u64,
+    ) -> [u8; 32] {
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update(target.to_bytes().unwrap());
+        hasher.update(entry_point.to_string().to_bytes().unwrap());
+        hasher.update(args.to_bytes().unwrap());
+        hasher.update(eta.to_bytes().unwrap());
+
+        let mut ret = [0u8; 32];
+        hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+        ret
+    }
+}
+
+/// Serialized `RuntimeArgs` of a call made through the timelock
+pub fn call_args(args: RuntimeArgs) -> Bytes {
+    Bytes::from(args.to_bytes().unwrap())
+}
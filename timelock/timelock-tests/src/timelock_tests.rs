@@ -0,0 +1,104 @@
+use crate::timelock_instance::*;
+use tests_common::{account::AccountHash, bytesrepr::Bytes, deploys::*, *};
+
+const DELAY: u64 = 172_800_000;
+
+fn deploy() -> (TestEnv, AccountHash, TIMELOCKInstance, TestContract) {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let timelock = TIMELOCKInstance::new(&env, owner, Key::Account(owner), DELAY, now());
+    // the factory takes its fee settings from the timelock only
+    let factory = deploy_factory(&env, owner, timelock.package_hash(), now());
+    (env, owner, timelock, factory)
+}
+
+#[test]
+fn test_timelock_queue_and_execute() {
+    let (env, owner, timelock, factory) = deploy();
+    let fee_to = env.next_user();
+    let target = Key::Hash(factory.package_hash());
+    let args: Bytes = call_args(runtime_args! {
+        "fee_to" => Key::Account(fee_to)
+    });
+    let time = now();
+    let eta = time + DELAY;
+    let tx_hash = timelock.transaction_hash(target, "set_fee_to", &args, eta);
+    timelock.queue_transaction(owner, target, "set_fee_to", &args, eta, time);
+    assert!(timelock.queued_transactions(tx_hash));
+    timelock.execute_transaction(owner, target, "set_fee_to", &args, eta, eta);
+    assert!(!timelock.queued_transactions(tx_hash));
+    let ret: Key = factory.query_named_key(String::from("fee_to"));
+    assert_eq!(ret, Key::Account(fee_to));
+}
+
+#[test]
+fn test_timelock_pause_pair() {
+    let (env, owner, timelock, _) = deploy();
+    let pair = deploy_pair(
+        &env,
+        "PAIR",
+        owner,
+        Key::Account(owner),
+        Key::Account(owner),
+        now(),
+    );
+    // the installing account owns the pair until it names the timelock
+    pair.call_contract(
+        owner,
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => timelock.package_hash()
+        },
+        now(),
+    );
+    assert_eq!(
+        pair.query_named_key::<Key>(String::from("owner")),
+        timelock.package_hash()
+    );
+    let target = Key::Hash(pair.package_hash());
+    let args: Bytes = call_args(runtime_args! {});
+    let time = now();
+    let eta = time + DELAY;
+    timelock.queue_transaction(owner, target, "pause", &args, eta, time);
+    timelock.execute_transaction(owner, target, "pause", &args, eta, eta);
+    assert!(pair.query_named_key::<bool>(String::from("pause")));
+}
+
+#[test]
+#[should_panic]
+fn test_timelock_execute_before_eta() {
+    let (env, owner, timelock, factory) = deploy();
+    let target = Key::Hash(factory.package_hash());
+    let args: Bytes = call_args(runtime_args! {
+        "fee_to" => Key::Account(env.next_user())
+    });
+    let time = now();
+    let eta = time + DELAY;
+    timelock.queue_transaction(owner, target, "set_fee_to", &args, eta, time);
+    timelock.execute_transaction(owner, target, "set_fee_to", &args, eta, eta - 1);
+}
+
+#[test]
+#[should_panic]
+fn test_timelock_execute_cancelled() {
+    let (env, owner, timelock, factory) = deploy();
+    let target = Key::Hash(factory.package_hash());
+    let args: Bytes = call_args(runtime_args! {
+        "fee_to" => Key::Account(env.next_user())
+    });
+    let time = now();
+    let eta = time + DELAY;
+    timelock.queue_transaction(owner, target, "set_fee_to", &args, eta, time);
+    timelock.cancel_transaction(owner, target, "set_fee_to", &args, eta, time);
+    timelock.execute_transaction(owner, target, "set_fee_to", &args, eta, eta);
+}
+
+#[test]
+fn test_timelock_hand_over_admin() {
+    let (env, owner, timelock, _) = deploy();
+    let admin = env.next_user();
+    timelock.set_pending_admin(owner, Key::Account(admin), now());
+    assert_eq!(timelock.pending_admin(), Key::Account(admin));
+    timelock.accept_admin(admin, now());
+    assert_eq!(timelock.admin(), Key::Account(admin));
+}
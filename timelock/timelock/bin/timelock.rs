@@ -0,0 +1,373 @@
+#![no_main]
+
+use std::collections::BTreeSet;
+use timelock_crate::{
+    bytesrepr::Bytes,
+    contract_api::{runtime, storage},
+    data::*,
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+
+#[derive(Default)]
+struct Timelock(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for Timelock {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl TIMELOCK<OnChainContractStorage> for Timelock {}
+
+impl Timelock {
+    fn constructor(
+        &self,
+        admin: Key,
+        delay: u64,
+        contract_hash: ContractHash,
+        package_hash: ContractPackageHash,
+    ) {
+        TIMELOCK::init(self, admin, delay, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let admin: Key = runtime::get_named_arg("admin");
+    let delay: u64 = runtime::get_named_arg("delay");
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Timelock::default().constructor(admin, delay, contract_hash, package_hash);
+}
+
+/// This function is to queue a call to be executed once the delay has passed, only the admin can call it
+///
+/// # Parameters
+///
+/// * `target` - A Key that holds the package hash of the contract called
+///
+/// * `entry_point` - A String that holds the name of the entry point called
+///
+/// * `args` - Bytes that hold the serialized RuntimeArgs of the call
+///
+/// * `eta` - A u64 that holds the block time in milliseconds the call can be executed from, at least the delay from now
+///
+
+#[no_mangle]
+fn queue_transaction() {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+    let eta: u64 = runtime::get_named_arg("eta");
+    let ret: Key = Timelock::default().queue_transaction(target, entry_point, args, eta);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to cancel a queued call, only the admin can call it
+///
+/// # Parameters
+///
+/// * `target` - A Key that holds the package hash of the contract called
+///
+/// * `entry_point` - A String that holds the name of the entry point called
+///
+/// * `args` - Bytes that hold the serialized RuntimeArgs of the call
+///
+/// * `eta` - A u64 that holds the eta the call was queued with
+///
+
+#[no_mangle]
+fn cancel_transaction() {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+    let eta: u64 = runtime::get_named_arg("eta");
+    let ret: Key = Timelock::default().cancel_transaction(target, entry_point, args, eta);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to execute a queued call between its eta and the end of the grace period, only the admin can call it
+///
+/// # Parameters
+///
+/// * `target` - A Key that holds the package hash of the contract called
+///
+/// * `entry_point` - A String that holds the name of the entry point called
+///
+/// * `args` - Bytes that hold the serialized RuntimeArgs of the call
+///
+/// * `eta` - A u64 that holds the eta the call was queued with
+///
+
+#[no_mangle]
+fn execute_transaction() {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+    let eta: u64 = runtime::get_named_arg("eta");
+    let ret: Bytes = Timelock::default().execute_transaction(target, entry_point, args, eta);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to change the delay, only the timelock itself can call it through a queued call
+///
+/// # Parameters
+///
+/// * `delay` - A u64 that holds the delay in milliseconds, between 2 and 30 days
+///
+
+#[no_mangle]
+fn set_delay() {
+    let delay: u64 = runtime::get_named_arg("delay");
+    Timelock::default().set_delay(delay);
+}
+
+/// This function is to name the next admin, directly by the first admin once, later only by the timelock itself through a queued call
+///
+/// # Parameters
+///
+/// * `pending_admin` - A Key that holds the account or package hash of the next admin
+///
+
+#[no_mangle]
+fn set_pending_admin() {
+    let pending_admin: Key = runtime::get_named_arg("pending_admin");
+    Timelock::default().set_pending_admin(pending_admin);
+}
+
+/// This function is to take over as admin, only the pending admin can call it
+///
+
+#[no_mangle]
+fn accept_admin() {
+    Timelock::default().accept_admin();
+}
+
+/// This function is to return whether a call is queued, by its hash
+///
+/// # Parameters
+///
+/// * `tx_hash` - A Key that holds the hash of the call, as returned by queue_transaction
+///
+
+#[no_mangle]
+fn queued_transactions() {
+    let tx_hash: Key = runtime::get_named_arg("tx_hash");
+    runtime::ret(CLValue::from_t(QueuedTransactions::instance().get(&tx_hash)).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin() {
+    runtime::ret(CLValue::from_t(get_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn pending_admin() {
+    runtime::ret(CLValue::from_t(get_pending_admin()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn delay() {
+    runtime::ret(CLValue::from_t(get_delay()).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn grace_period() {
+    runtime::ret(CLValue::from_t(GRACE_PERIOD).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("delay", u64::cl_type()),
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queue_transaction",
+        vec![
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", Bytes::cl_type()),
+            Parameter::new("eta", u64::cl_type()),
+        ],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_transaction",
+        vec![
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", Bytes::cl_type()),
+            Parameter::new("eta", u64::cl_type()),
+        ],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute_transaction",
+        vec![
+            Parameter::new("target", Key::cl_type()),
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", Bytes::cl_type()),
+            Parameter::new("eta", u64::cl_type()),
+        ],
+        Bytes::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_delay",
+        vec![Parameter::new("delay", u64::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_pending_admin",
+        vec![Parameter::new("pending_admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "accept_admin",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "queued_transactions",
+        vec![Parameter::new("tx_hash", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pending_admin",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delay",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grace_period",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        let admin: Key = runtime::get_named_arg("admin");
+        let delay: u64 = runtime::get_named_arg("delay");
+
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "admin" => admin,
+            "delay" => delay,
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}
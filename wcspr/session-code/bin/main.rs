@@ -2,12 +2,18 @@
 
 use common::{
     contract_api::{account, runtime, system},
+    errors::WcsprError,
     keys::*,
+    quote::get_pair_amount_out,
     unwrap_or_revert::UnwrapOrRevert,
     *,
 };
 
 fn purse(amount: U512) -> URef {
+    // fail the same way the contract does instead of on the empty purse transfer
+    if amount.is_zero() {
+        runtime::revert(WcsprError::ZeroAmount);
+    }
     let main_purse: URef = account::get_main_purse();
     let secondary_purse: URef = system::create_purse();
     system::transfer_from_purse_to_purse(main_purse, secondary_purse, amount, None)
@@ -15,6 +21,20 @@ fn purse(amount: U512) -> URef {
     secondary_purse
 }
 
+/// Wraps `amount` cspr straight into `pair`, so the pair holds the wcspr without a separate transfer
+fn wrap_into_pair(wcspr: Key, pair: Key, amount: U512) {
+    let () = runtime::call_versioned_contract(
+        wcspr.into_hash().unwrap_or_revert().into(),
+        None,
+        DEPOSIT_TO,
+        runtime_args! {
+            "recipient" => pair,
+            "purse" => purse(amount),
+            "amount" => amount
+        },
+    );
+}
+
 #[no_mangle]
 fn call() {
     let entrypoint: String = runtime::get_named_arg(ENTRYPOINT);
@@ -33,17 +53,138 @@ fn call() {
             );
         }
         WITHDRAW => {
-          let amount: U512 = runtime::get_named_arg("amount");
-          let () = runtime::call_versioned_contract(
-              package_hash.into_hash().unwrap_or_revert().into(),
-              None,
-              WITHDRAW,
-              runtime_args! {
-                  "purse" => account::get_main_purse().into_add(),
-                  "amount" => amount
-              },
-          );
-      }
-      _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+            let amount: U512 = runtime::get_named_arg("amount");
+            let () = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                WITHDRAW,
+                runtime_args! {
+                    "purse" => account::get_main_purse().into_add(),
+                    "amount" => amount
+                },
+            );
+        }
+        DEPOSIT_TO => {
+            let recipient: Key = runtime::get_named_arg("recipient");
+            let amount: U512 = runtime::get_named_arg("amount");
+            let () = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                DEPOSIT_TO,
+                runtime_args! {
+                    "recipient" => recipient,
+                    "purse" => purse(amount),
+                    "amount" => amount
+                },
+            );
+        }
+        WITHDRAW_TO => {
+            let recipient: Key = runtime::get_named_arg("recipient");
+            let amount: U512 = runtime::get_named_arg("amount");
+            let () = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                WITHDRAW_TO,
+                runtime_args! {
+                    "recipient" => recipient,
+                    "amount" => amount
+                },
+            );
+        }
+        WITHDRAW_FROM => {
+            let owner: Key = runtime::get_named_arg("owner");
+            let amount: U512 = runtime::get_named_arg("amount");
+            let () = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                WITHDRAW_FROM,
+                runtime_args! {
+                    "owner" => owner,
+                    "recipient_purse" => account::get_main_purse().into_add(),
+                    "amount" => amount
+                },
+            );
+        }
+        WRAP_AND_ADD_LIQUIDITY => {
+            let pair: Key = runtime::get_named_arg("pair");
+            let token: Key = runtime::get_named_arg("token");
+            let amount: U512 = runtime::get_named_arg("amount");
+            let token_amount: U256 = runtime::get_named_arg("token_amount");
+            let min_liquidity: U256 = runtime::get_named_arg("min_liquidity");
+            let to: Key = runtime::get_named_arg("to");
+            wrap_into_pair(package_hash, pair, amount);
+            let () = runtime::call_versioned_contract(
+                token.into_hash().unwrap_or_revert().into(),
+                None,
+                "transfer",
+                runtime_args! {
+                    "recipient" => Address::from(pair),
+                    "amount" => token_amount
+                },
+            );
+            let liquidity: U256 = runtime::call_versioned_contract(
+                pair.into_hash().unwrap_or_revert().into(),
+                None,
+                "mint",
+                runtime_args! {
+                    "to" => to
+                },
+            );
+            if liquidity < min_liquidity {
+                runtime::revert(WcsprError::InsufficientLiquidityMinted);
+            }
+        }
+        WRAP_AND_SWAP => {
+            let pair: Key = runtime::get_named_arg("pair");
+            let amount: U512 = runtime::get_named_arg("amount");
+            let amount_out_min: U256 = runtime::get_named_arg("amount_out_min");
+            let to: Key = runtime::get_named_arg("to");
+            let pair_package_hash: ContractPackageHash = pair.into_hash().unwrap_or_revert().into();
+            let (reserve0, reserve1, _): (U128, U128, u64) = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "get_reserves",
+                runtime_args! {},
+            );
+            let token0: Key = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "token0",
+                runtime_args! {},
+            );
+            let (reserve_in, reserve_out) = if token0 == package_hash {
+                (reserve0, reserve1)
+            } else {
+                (reserve1, reserve0)
+            };
+            let amount_out: U256 = get_pair_amount_out(
+                pair,
+                token0 == package_hash,
+                U256::from(amount.as_u128()),
+                U256::from(reserve_in.as_u128()),
+                U256::from(reserve_out.as_u128()),
+            );
+            if amount_out < amount_out_min {
+                runtime::revert(WcsprError::InsufficientOutputAmount);
+            }
+            let (amount0_out, amount1_out): (U256, U256) = if token0 == package_hash {
+                (0.into(), amount_out)
+            } else {
+                (amount_out, 0.into())
+            };
+            wrap_into_pair(package_hash, pair, amount);
+            let () = runtime::call_versioned_contract(
+                pair_package_hash,
+                None,
+                "swap",
+                runtime_args! {
+                    "amount0_out" => amount0_out,
+                    "amount1_out" => amount1_out,
+                    "to" => to,
+                    "data" => ""
+                },
+            );
+        }
+        _ => runtime::revert(WcsprError::InvalidEntryPoint),
     };
 }
@@ -1,8 +1,29 @@
-use common::*;
+use common::{functions::account_zero_address, keys::*, *};
 
 pub enum WcsprEvents {
-    Deposit { purse: URef, amount: U512 },
-    Withdraw { purse: URef, amount: U512 },
+    Deposit {
+        purse: URef,
+        recipient: Key,
+        amount: U512,
+    },
+    Withdraw {
+        purse: URef,
+        owner: Key,
+        amount: U512,
+    },
+    WithdrawTo {
+        recipient: Key,
+        owner: Key,
+        amount: U512,
+    },
+    ExcessSwept {
+        recipient: Key,
+        amount: U512,
+    },
+    BackingMismatch {
+        purse_balance: U512,
+        total_supply: U256,
+    },
 }
 
 impl WcsprEvents {
@@ -10,13 +31,44 @@ impl WcsprEvents {
         match self {
             WcsprEvents::Deposit {
                 purse: _,
+                recipient: _,
                 amount: _,
             } => "deposit",
             WcsprEvents::Withdraw {
                 purse: _,
+                owner: _,
                 amount: _,
             } => "withdraw",
+            WcsprEvents::WithdrawTo {
+                recipient: _,
+                owner: _,
+                amount: _,
+            } => "withdraw_to",
+            WcsprEvents::ExcessSwept {
+                recipient: _,
+                amount: _,
+            } => "excess_swept",
+            WcsprEvents::BackingMismatch {
+                purse_balance: _,
+                total_supply: _,
+            } => "backing_mismatch",
         }
         .to_string()
     }
 }
+
+pub fn set_owner(owner: Key) {
+    set_key(OWNER, owner);
+}
+
+pub fn get_owner() -> Key {
+    get_key(OWNER).unwrap_or_else(account_zero_address)
+}
+
+pub fn set_excess_recipient(excess_recipient: Key) {
+    set_key(EXCESS_RECIPIENT, excess_recipient);
+}
+
+pub fn get_excess_recipient() -> Key {
+    get_key(EXCESS_RECIPIENT).unwrap_or_else(get_owner)
+}
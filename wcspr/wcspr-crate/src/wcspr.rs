@@ -1,10 +1,12 @@
 use std::collections::BTreeMap;
 
-use crate::data::WcsprEvents;
+use crate::data::*;
 use common::{
+    account::AccountHash,
     contract_api::{runtime, storage, system},
-    errors::Errors,
+    errors::WcsprError,
     functions::{get_purse, set_purse, u256_to_u512, u512_to_u256},
+    keys::OWNER,
     unwrap_or_revert::UnwrapOrRevert,
     *,
 };
@@ -13,84 +15,254 @@ use uniswap_erc20::{data::get_package_hash, Address, ERC20};
 pub trait WCSPR<Storage: ContractStorage>: ContractContext<Storage> + ERC20<Storage> {
     fn init(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
         ERC20::init(self, contract_hash, package_hash);
-        
+
         let purse: URef = system::create_purse();
 
         set_purse(purse);
+        set_owner(self.get_caller());
+        set_excess_recipient(self.get_caller());
     }
 
     fn migrate(&self) {
-      let old_purse = get_purse();
-      let balance = system::get_purse_balance(old_purse).unwrap_or_revert();
+        let old_purse = get_purse();
+        let balance = system::get_purse_balance(old_purse).unwrap_or_revert();
 
-      if balance > u256_to_u512(U256::MAX) {
-        runtime::revert(Errors::UniswapV2CoreWCSPROverFlow2);
-      }
-      
-      let purse: URef = system::create_purse();
+        if balance > u256_to_u512(U256::MAX) {
+            runtime::revert(WcsprError::PurseOverFlow);
+        }
 
-      if balance > 0.into() {
-        system::transfer_from_purse_to_purse(old_purse, purse.into_add(), balance, None).unwrap_or_revert();
-      }
+        let purse: URef = system::create_purse();
 
-      set_purse(purse);
+        if balance > 0.into() {
+            system::transfer_from_purse_to_purse(old_purse, purse.into_add(), balance, None)
+                .unwrap_or_revert_with(WcsprError::MigrateFailed);
+        }
+
+        set_purse(purse);
+        // versions deployed before ownership existed are adopted by whoever upgrades them
+        if get_key::<Key>(OWNER).is_none() {
+            set_owner(self.get_caller());
+        }
     }
 
+    fn deposit(&self, amount: U512, purse: URef) -> Result<(), WcsprError> {
+        self.deposit_to(self.get_caller(), amount, purse)
+    }
 
-    fn deposit(&self, amount: U512, purse: URef) -> Result<(), u32> {
+    /// Wraps cspr from `purse` and mints the wcspr to `recipient` instead of the caller
+    fn deposit_to(&self, recipient: Key, amount: U512, purse: URef) -> Result<(), WcsprError> {
         if amount.is_zero() {
-            return Err(5); // Amount to transfer is 0
+            return Err(WcsprError::ZeroAmount);
         }
         if amount > u256_to_u512(U256::MAX) {
-            runtime::revert(Errors::UniswapV2CoreWCSPROverFlow1);
+            return Err(WcsprError::AmountOverFlow);
         }
         if system::get_purse_balance(purse).unwrap_or_revert() > u256_to_u512(U256::MAX) {
-            runtime::revert(Errors::UniswapV2CoreWCSPROverFlow2);
+            return Err(WcsprError::PurseOverFlow);
         }
         // transfers native cspr from source purse to destination purse
-        system::transfer_from_purse_to_purse(purse, get_purse().into_add(), amount, None).unwrap_or_revert();
-        // mint wcspr for the caller
-        self.mint(Address::from(self.get_caller()), u512_to_u256(amount))
+        system::transfer_from_purse_to_purse(purse, get_purse().into_add(), amount, None)
+            .map_err(|_| WcsprError::PurseTransferFailed)?;
+        // mint wcspr for the recipient
+        self.mint(Address::from(recipient), u512_to_u256(amount))
             .unwrap_or_revert();
-        self.emit(&WcsprEvents::Deposit { purse, amount });
+        self.emit(&WcsprEvents::Deposit {
+            purse,
+            recipient,
+            amount,
+        });
         Ok(())
     }
 
-    fn withdraw(&self, amount: U512, purse: URef) -> Result<(), u32> {
+    fn withdraw(&self, amount: U512, purse: URef) -> Result<(), WcsprError> {
+        self._withdraw(self.get_caller(), amount, purse)
+    }
+
+    /// Unwraps the caller's wcspr into the main purse of the `recipient` account, whose purse the
+    /// caller does not need to know
+    fn withdraw_to(&self, recipient: Key, amount: U512) -> Result<(), WcsprError> {
+        let recipient_account: AccountHash = recipient
+            .into_account()
+            .ok_or(WcsprError::InvalidRecipient)?;
+        let owner: Key = self.get_caller();
+        self._check_withdraw(owner, amount)?;
+        // transfer native cspr from purse to the recipient's main purse
+        system::transfer_from_purse_to_account(get_purse(), recipient_account, amount, None)
+            .map_err(|_| WcsprError::PurseTransferFailed)?;
+        // burn wcspr of the owner
+        self.burn(Address::from(owner), u512_to_u256(amount))
+            .unwrap_or_revert();
+        self.emit(&WcsprEvents::WithdrawTo {
+            recipient,
+            owner,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Unwraps wcspr of `owner` into `recipient_purse`, spending the caller's allowance
+    fn withdraw_from(
+        &self,
+        owner: Key,
+        amount: U512,
+        recipient_purse: URef,
+    ) -> Result<(), WcsprError> {
+        let spender: Address = Address::from(self.get_caller());
+        let allowance: U256 = self.allowance(Address::from(owner), spender);
+        if allowance < u512_to_u256(amount) {
+            return Err(WcsprError::InsufficientAllowance);
+        }
+        self._approve(
+            Address::from(owner),
+            spender,
+            allowance - u512_to_u256(amount),
+        )
+        .unwrap_or_revert();
+        self._withdraw(owner, amount, recipient_purse)
+    }
+
+    fn _check_withdraw(&self, owner: Key, amount: U512) -> Result<(), WcsprError> {
         if amount.is_zero() {
-            return Err(5); // Amount to transfer is 0
+            return Err(WcsprError::ZeroAmount);
         }
         if amount > u256_to_u512(U256::MAX) {
-            runtime::revert(Errors::UniswapV2CoreWCSPROverFlow3);
+            return Err(WcsprError::AmountOverFlow);
+        }
+        if self.balance_of(Address::from(owner)) < u512_to_u256(amount) {
+            return Err(WcsprError::InsufficientBalance);
         }
+        Ok(())
+    }
+
+    fn _withdraw(&self, owner: Key, amount: U512, purse: URef) -> Result<(), WcsprError> {
+        self._check_withdraw(owner, amount)?;
         // transfer native cspr from purse to account
-        system::transfer_from_purse_to_purse(get_purse(), purse, amount, None).unwrap_or_revert();
-        // burn wcspr for the caller
-        self.burn(Address::from(self.get_caller()), u512_to_u256(amount))
+        system::transfer_from_purse_to_purse(get_purse(), purse, amount, None)
+            .map_err(|_| WcsprError::PurseTransferFailed)?;
+        // burn wcspr of the owner
+        self.burn(Address::from(owner), u512_to_u256(amount))
             .unwrap_or_revert();
-        self.emit(&WcsprEvents::Withdraw { purse, amount });
+        self.emit(&WcsprEvents::Withdraw {
+            purse,
+            owner,
+            amount,
+        });
         Ok(())
     }
 
+    fn set_excess_recipient(&self, excess_recipient: Key) {
+        if self.get_caller() != get_owner() {
+            runtime::revert(WcsprError::NotOwner);
+        }
+        if excess_recipient.into_account().is_none() {
+            runtime::revert(WcsprError::InvalidRecipient);
+        }
+        set_excess_recipient(excess_recipient);
+    }
+
+    /// Returns the purse balance and the total supply that it has to back
+    fn backing(&self) -> (U512, U256) {
+        (
+            system::get_purse_balance(get_purse()).unwrap_or_revert(),
+            self.total_supply(),
+        )
+    }
+
+    /// Reverts unless every wcspr in circulation is backed by cspr in the purse
+    fn assert_backing(&self) {
+        let (purse_balance, total_supply) = self.backing();
+        if purse_balance < u256_to_u512(total_supply) {
+            runtime::revert(WcsprError::BackingDeficit);
+        }
+    }
+
+    /// Sends cspr that was put in the purse without minting (direct top-ups) to the excess recipient,
+    /// a deficit is recorded with a `BackingMismatch` event instead of reverting
+    fn reconcile(&self) {
+        let (purse_balance, total_supply) = self.backing();
+        let total_supply_u512: U512 = u256_to_u512(total_supply);
+        if purse_balance < total_supply_u512 {
+            self.emit(&WcsprEvents::BackingMismatch {
+                purse_balance,
+                total_supply,
+            });
+        } else if purse_balance > total_supply_u512 {
+            let recipient: Key = get_excess_recipient();
+            let amount: U512 = purse_balance - total_supply_u512;
+            system::transfer_from_purse_to_account(
+                get_purse(),
+                recipient
+                    .into_account()
+                    .unwrap_or_revert_with(WcsprError::InvalidRecipient),
+                amount,
+                None,
+            )
+            .unwrap_or_revert_with(WcsprError::PurseTransferFailed);
+            self.emit(&WcsprEvents::ExcessSwept { recipient, amount });
+        }
+    }
+
     // Events
     fn emit(&self, wcspr_event: &WcsprEvents) {
         match wcspr_event {
-            WcsprEvents::Deposit { purse, amount } => {
+            WcsprEvents::Deposit {
+                purse,
+                recipient,
+                amount,
+            } => {
                 let mut event = BTreeMap::new();
                 event.insert("contract_package_hash", get_package_hash().to_string());
                 event.insert("event_type", wcspr_event.type_name());
                 event.insert("purse", purse.to_string());
+                event.insert("recipient", recipient.to_string());
                 event.insert("amount", amount.to_string());
                 storage::new_uref(event);
             }
-            WcsprEvents::Withdraw { purse, amount } => {
+            WcsprEvents::Withdraw {
+                purse,
+                owner,
+                amount,
+            } => {
                 let mut event = BTreeMap::new();
                 event.insert("contract_package_hash", get_package_hash().to_string());
                 event.insert("event_type", wcspr_event.type_name());
                 event.insert("purse", purse.to_string());
+                event.insert("owner", owner.to_string());
                 event.insert("amount", amount.to_string());
                 storage::new_uref(event);
             }
+            WcsprEvents::WithdrawTo {
+                recipient,
+                owner,
+                amount,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", get_package_hash().to_string());
+                event.insert("event_type", wcspr_event.type_name());
+                event.insert("recipient", recipient.to_string());
+                event.insert("owner", owner.to_string());
+                event.insert("amount", amount.to_string());
+                storage::new_uref(event);
+            }
+            WcsprEvents::ExcessSwept { recipient, amount } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", get_package_hash().to_string());
+                event.insert("event_type", wcspr_event.type_name());
+                event.insert("recipient", recipient.to_string());
+                event.insert("amount", amount.to_string());
+                storage::new_uref(event);
+            }
+            WcsprEvents::BackingMismatch {
+                purse_balance,
+                total_supply,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", get_package_hash().to_string());
+                event.insert("event_type", wcspr_event.type_name());
+                event.insert("purse_balance", purse_balance.to_string());
+                event.insert("total_supply", total_supply.to_string());
+                storage::new_uref(event);
+            }
         };
     }
 }
@@ -1,4 +1,4 @@
-use tests_common::{account::AccountHash, runtime_args, *};
+use tests_common::{account::AccountHash, keys::*, runtime_args, *};
 
 pub fn call(
     env: &TestEnv,
@@ -21,3 +21,129 @@ pub fn call(
         time,
     )
 }
+
+pub fn deposit_to(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: Key,
+    recipient: Key,
+    amount: U512,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-wcspr.wasm",
+        "session-code-wcspr",
+        sender,
+        runtime_args! {
+            "entrypoint" => DEPOSIT_TO,
+            "package_hash" => package_hash,
+            "recipient" => recipient,
+            "amount" => amount,
+        },
+        time,
+    )
+}
+
+pub fn withdraw_to(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: Key,
+    recipient: Key,
+    amount: U512,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-wcspr.wasm",
+        "session-code-wcspr",
+        sender,
+        runtime_args! {
+            "entrypoint" => WITHDRAW_TO,
+            "package_hash" => package_hash,
+            "recipient" => recipient,
+            "amount" => amount,
+        },
+        time,
+    )
+}
+
+pub fn withdraw_from(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: Key,
+    owner: Key,
+    amount: U512,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-wcspr.wasm",
+        "session-code-wcspr",
+        sender,
+        runtime_args! {
+            "entrypoint" => WITHDRAW_FROM,
+            "package_hash" => package_hash,
+            "owner" => owner,
+            "amount" => amount,
+        },
+        time,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn wrap_and_add_liquidity(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: Key,
+    pair: Key,
+    token: Key,
+    amount: U512,
+    token_amount: U256,
+    min_liquidity: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-wcspr.wasm",
+        "session-code-wcspr",
+        sender,
+        runtime_args! {
+            "entrypoint" => WRAP_AND_ADD_LIQUIDITY,
+            "package_hash" => package_hash,
+            "pair" => pair,
+            "token" => token,
+            "amount" => amount,
+            "token_amount" => token_amount,
+            "min_liquidity" => min_liquidity,
+            "to" => Key::Account(sender),
+        },
+        time,
+    )
+}
+
+pub fn wrap_and_swap(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: Key,
+    pair: Key,
+    amount: U512,
+    amount_out_min: U256,
+    time: u64,
+) -> TestContract {
+    TestContract::new(
+        env,
+        "session-code-wcspr.wasm",
+        "session-code-wcspr",
+        sender,
+        runtime_args! {
+            "entrypoint" => WRAP_AND_SWAP,
+            "package_hash" => package_hash,
+            "pair" => pair,
+            "amount" => amount,
+            "amount_out_min" => amount_out_min,
+            "to" => Key::Account(sender),
+        },
+        time,
+    )
+}
@@ -100,3 +100,449 @@ fn test_wcspr_withdraw_with_no_deposit() {
         now(),
     );
 }
+
+#[test]
+fn test_wcspr_backing_after_deposit_and_withdraw() {
+    let (env, owner, token) = deploy();
+    let user = env.next_user();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    call(
+        &env,
+        user,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+    call(
+        &env,
+        owner,
+        WITHDRAW,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512 / 2,
+        now(),
+    );
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+    assert_eq!(
+        token.query_named_key::<U256>("total_supply".into()),
+        AMOUNT + AMOUNT / 2
+    );
+    call(
+        &env,
+        user,
+        WITHDRAW,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+}
+
+#[test]
+fn test_wcspr_backing_after_migrate() {
+    let (env, owner, token) = deploy();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    // installing under the same name upgrades the package and moves the purse
+    let token = deploy_wcspr(
+        &env,
+        WRAPPED_CSPR,
+        owner,
+        NAME.into(),
+        SYMBOL.into(),
+        DECIMALS,
+        INIT_TOTAL_SUPPLY,
+        now(),
+    );
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        AMOUNT
+    );
+    call(
+        &env,
+        owner,
+        WITHDRAW,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+    assert_eq!(
+        token.query_named_key::<U256>("total_supply".into()),
+        0.into()
+    );
+}
+
+#[test]
+fn test_wcspr_reconcile_without_excess() {
+    let (env, owner, token) = deploy();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    token.call_contract(owner, "reconcile", runtime_args! {}, now());
+    token.call_contract(owner, "assert_backing", runtime_args! {}, now());
+    assert_eq!(token.query_named_key::<U256>("total_supply".into()), AMOUNT);
+}
+
+#[test]
+fn test_wcspr_set_excess_recipient() {
+    let (env, owner, token) = deploy();
+    assert_eq!(
+        token.query_named_key::<Key>(EXCESS_RECIPIENT.into()),
+        Key::Account(owner)
+    );
+    let recipient = env.next_user();
+    token.call_contract(
+        owner,
+        "set_excess_recipient",
+        runtime_args! {
+            "excess_recipient" => Key::Account(recipient)
+        },
+        now(),
+    );
+    assert_eq!(
+        token.query_named_key::<Key>(EXCESS_RECIPIENT.into()),
+        Key::Account(recipient)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_wcspr_set_excess_recipient_with_non_owner() {
+    let (env, _, token) = deploy();
+    let user = env.next_user();
+    token.call_contract(
+        user,
+        "set_excess_recipient",
+        runtime_args! {
+            "excess_recipient" => Key::Account(user)
+        },
+        now(),
+    );
+}
+
+#[test]
+fn test_wcspr_deposit_to() {
+    let (env, owner, token) = deploy();
+    let recipient = env.next_user();
+    deposit_to(
+        &env,
+        owner,
+        Key::Hash(token.package_hash()),
+        Key::Account(recipient),
+        AMOUNT_U512,
+        now(),
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        0.into()
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(recipient))),
+        AMOUNT
+    );
+}
+
+#[test]
+fn test_wcspr_withdraw_to() {
+    let (env, owner, token) = deploy();
+    let recipient = env.next_user();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    withdraw_to(
+        &env,
+        owner,
+        Key::Hash(token.package_hash()),
+        Key::Account(recipient),
+        AMOUNT_U512,
+        now(),
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        0.into()
+    );
+    assert_eq!(
+        token.query_named_key::<U256>("total_supply".into()),
+        0.into()
+    );
+}
+
+#[test]
+#[should_panic] // As the cspr can only be sent to the main purse of an account
+fn test_wcspr_withdraw_to_contract() {
+    let (env, owner, token) = deploy();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    withdraw_to(
+        &env,
+        owner,
+        Key::Hash(token.package_hash()),
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+}
+
+#[test]
+fn test_wcspr_withdraw_from() {
+    let (env, owner, token) = deploy();
+    let spender = env.next_user();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    token.call_contract(
+        owner,
+        "approve",
+        runtime_args! {
+            "spender" => Address::Account(spender),
+            "amount" => AMOUNT
+        },
+        now(),
+    );
+    withdraw_from(
+        &env,
+        spender,
+        Key::Hash(token.package_hash()),
+        Key::Account(owner),
+        AMOUNT_U512 / 2,
+        now(),
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        AMOUNT / 2
+    );
+    assert_eq!(
+        token.query::<U256>(
+            ALLOWANCES,
+            addresses_to_str(Address::Account(owner), Address::Account(spender))
+        ),
+        AMOUNT / 2
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_wcspr_withdraw_from_without_allowance() {
+    let (env, owner, token) = deploy();
+    let spender = env.next_user();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        now(),
+    );
+    withdraw_from(
+        &env,
+        spender,
+        Key::Hash(token.package_hash()),
+        Key::Account(owner),
+        AMOUNT_U512,
+        now(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_wcspr_deposit_zero_amount() {
+    let (env, owner, token) = deploy();
+    call(
+        &env,
+        owner,
+        DEPOSIT,
+        Key::Hash(token.package_hash()),
+        0.into(),
+        now(),
+    );
+}
+
+fn deploy_pair_with_wcspr() -> (
+    TestEnv,
+    AccountHash,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let (env, owner, wcspr) = deploy();
+    let factory = deploy_factory(&env, owner, Key::Account(owner), now());
+    let token = deploy_erc20(&env, "ERC20-1", owner, "Token", "TK", 9, 0.into(), now());
+    let flash_swapper = deploy_flashswapper(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        vec![],
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    let pair = deploy_pair(
+        &env,
+        "PAIR",
+        owner,
+        Key::Hash(flash_swapper.package_hash()),
+        Key::Hash(factory.package_hash()),
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "set_white_list",
+        runtime_args! {
+            "white_list" => Key::Account(owner)
+        },
+        now(),
+    );
+    factory.call_contract(
+        owner,
+        "create_pair",
+        runtime_args! {
+            "token_a" => Key::Hash(wcspr.package_hash()),
+            "token_b" => Key::Hash(token.package_hash()),
+            "pair_hash" => Key::Hash(pair.package_hash())
+        },
+        now(),
+    );
+    token.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(owner),
+            "amount" => AMOUNT * 2
+        },
+        now(),
+    );
+    (env, owner, wcspr, token, pair)
+}
+
+#[test]
+fn test_wcspr_wrap_and_add_liquidity() {
+    let (env, owner, wcspr, token, pair) = deploy_pair_with_wcspr();
+    wrap_and_add_liquidity(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        AMOUNT,
+        1.into(),
+        now(),
+    );
+    assert_eq!(
+        wcspr.query::<U256>(
+            BALANCES,
+            address_to_str(&Address::Contract(pair.package_hash().into()))
+        ),
+        AMOUNT
+    );
+    assert_eq!(
+        token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))),
+        AMOUNT
+    );
+    assert!(pair.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))) > 0.into());
+}
+
+#[test]
+#[should_panic]
+fn test_wcspr_wrap_and_add_liquidity_below_min_liquidity() {
+    let (env, owner, wcspr, token, pair) = deploy_pair_with_wcspr();
+    wrap_and_add_liquidity(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        AMOUNT,
+        AMOUNT,
+        now(),
+    );
+}
+
+#[test]
+fn test_wcspr_wrap_and_swap() {
+    let (env, owner, wcspr, token, pair) = deploy_pair_with_wcspr();
+    wrap_and_add_liquidity(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        AMOUNT,
+        1.into(),
+        now(),
+    );
+    wrap_and_swap(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        AMOUNT_U512 / 10,
+        1.into(),
+        now(),
+    );
+    assert!(token.query::<U256>(BALANCES, address_to_str(&Address::Account(owner))) > AMOUNT);
+}
+
+#[test]
+#[should_panic]
+fn test_wcspr_wrap_and_swap_below_amount_out_min() {
+    let (env, owner, wcspr, token, pair) = deploy_pair_with_wcspr();
+    wrap_and_add_liquidity(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token.package_hash()),
+        AMOUNT_U512,
+        AMOUNT,
+        1.into(),
+        now(),
+    );
+    wrap_and_swap(
+        &env,
+        owner,
+        Key::Hash(wcspr.package_hash()),
+        Key::Hash(pair.package_hash()),
+        AMOUNT_U512 / 10,
+        AMOUNT / 10,
+        now(),
+    );
+}
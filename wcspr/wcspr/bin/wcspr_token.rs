@@ -184,6 +184,70 @@ fn withdraw() {
     Token::default().withdraw(amount, purse).unwrap_or_revert();
 }
 
+/// This function is to set the account that receives cspr sent to the purse without a deposit
+/// # Parameters
+/// * `excess_recipient` - A Key that holds the account address of the recipient
+#[no_mangle]
+fn set_excess_recipient() {
+    let excess_recipient: Key = runtime::get_named_arg("excess_recipient");
+    Token::default().set_excess_recipient(excess_recipient);
+}
+
+/// This function is to revert if the purse balance is less than the total supply
+#[no_mangle]
+fn assert_backing() {
+    Token::default().assert_backing();
+}
+
+/// This function is to send any purse balance above the total supply to the excess recipient
+#[no_mangle]
+fn reconcile() {
+    Token::default().reconcile();
+}
+
+/// This function is to deposit cspr and mint the wcspr to a recipient other than the caller
+/// # Parameters
+/// * `recipient` - A Key that holds the account address or package hash that receives the wcspr
+/// * `purse` - A URef that holds the purse of the user
+/// * `amount` - A U512 that holds the amount for deposit
+#[no_mangle]
+fn deposit_to() {
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let purse: URef = runtime::get_named_arg("purse");
+    let amount: U512 = runtime::get_named_arg("amount");
+    Token::default()
+        .deposit_to(recipient, amount, purse)
+        .unwrap_or_revert();
+}
+
+/// This function is to withdraw the caller's wcspr into the main purse of the recipient
+/// # Parameters
+/// * `recipient` - A Key that holds the account address of the recipient
+/// * `amount` - A U512 that holds the amount for withdraw
+#[no_mangle]
+fn withdraw_to() {
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let amount: U512 = runtime::get_named_arg("amount");
+    Token::default()
+        .withdraw_to(recipient, amount)
+        .unwrap_or_revert();
+}
+
+/// This function is to withdraw wcspr of an owner who approved the caller
+/// # Parameters
+/// * `owner` - A Key that holds the account address or package hash of the owner
+/// * `recipient_purse` - A URef that holds the purse receiving the cspr
+/// * `amount` - A U512 that holds the amount for withdraw
+#[no_mangle]
+fn withdraw_from() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let recipient_purse: URef = runtime::get_named_arg("recipient_purse");
+    let amount: U512 = runtime::get_named_arg("amount");
+    Token::default()
+        .withdraw_from(owner, amount, recipient_purse)
+        .unwrap_or_revert();
+}
+
 fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(EntryPoint::new(
@@ -319,6 +383,59 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "deposit_to",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_to",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_from",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("recipient_purse", URef::cl_type()),
+            Parameter::new("amount", U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_excess_recipient",
+        vec![Parameter::new("excess_recipient", Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "assert_backing",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reconcile",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
 
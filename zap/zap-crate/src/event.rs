@@ -0,0 +1,42 @@
+use common::*;
+
+pub enum ZAPEvent {
+    ZapIn {
+        user: Key,
+        pair: Key,
+        token_in: Key,
+        amount_in: U256,
+        swap_amount: U256,
+        liquidity: U256,
+    },
+    ZapOut {
+        user: Key,
+        pair: Key,
+        liquidity: U256,
+        token_out: Key,
+        amount_out: U256,
+    },
+}
+
+impl ZAPEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            ZAPEvent::ZapIn {
+                user: _,
+                pair: _,
+                token_in: _,
+                amount_in: _,
+                swap_amount: _,
+                liquidity: _,
+            } => "zap_in",
+            ZAPEvent::ZapOut {
+                user: _,
+                pair: _,
+                liquidity: _,
+                token_out: _,
+                amount_out: _,
+            } => "zap_out",
+        }
+        .to_string()
+    }
+}
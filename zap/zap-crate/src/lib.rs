@@ -0,0 +1,5 @@
+pub mod event;
+mod zap;
+
+pub use common::*;
+pub use zap::ZAP;
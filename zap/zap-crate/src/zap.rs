@@ -0,0 +1,193 @@
+use crate::event::ZAPEvent;
+use common::{
+    calls::{reserves, tokens, transfer, transfer_from},
+    contract_api::{runtime, storage},
+    errors::Errors,
+    math::{get_amount_out, get_zap_swap_amount},
+    quote::{get_pair_amount_out, require_constant_product},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+use std::collections::BTreeMap;
+
+pub trait ZAP<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        set_contract_hash(contract_hash);
+        set_package_hash(package_hash);
+    }
+
+    /// Pulls `amount_in` of `token_in` from the caller, swaps the optimal part of it through `pair`
+    /// and adds both sides as liquidity, minting the LP tokens to the caller. The optimal part is
+    /// solved on `x * y = k`, so only constant product pairs can be zapped into
+    fn zap_in(&self, pair: Key, token_in: Key, amount_in: U256, min_liquidity: U256) -> U256 {
+        require_constant_product(pair);
+        let user: Key = self.get_caller();
+        let (token0, token1) = tokens(pair);
+        let token_out: Key = self.other_token(token_in, token0, token1);
+        transfer_from(token_in, user, Key::from(get_package_hash()), amount_in);
+        let (reserve_in, reserve_out) = reserves(pair, token_in == token0);
+        let swap_amount: U256 = get_zap_swap_amount(amount_in, reserve_in);
+        let amount_out: U256 = self.swap(
+            pair,
+            token_in,
+            token_in == token0,
+            swap_amount,
+            get_amount_out(swap_amount, reserve_in, reserve_out),
+            Key::from(get_package_hash()),
+        );
+        transfer(
+            token_in,
+            pair,
+            amount_in
+                .checked_sub(swap_amount)
+                .unwrap_or_revert_with(Errors::ZapUnderFlow),
+        );
+        transfer(token_out, pair, amount_out);
+        let liquidity: U256 = runtime::call_versioned_contract(
+            pair.into_hash().unwrap_or_revert().into(),
+            None,
+            "mint",
+            runtime_args! {
+                "to" => user
+            },
+        );
+        if liquidity < min_liquidity {
+            runtime::revert(Errors::ZapInsufficientLiquidityMinted);
+        }
+        self.emit(&ZAPEvent::ZapIn {
+            user,
+            pair,
+            token_in,
+            amount_in,
+            swap_amount,
+            liquidity,
+        });
+        liquidity
+    }
+
+    /// Pulls `liquidity` LP tokens of `pair` from the caller, burns them and swaps the other token
+    /// into `token_out`, sending all of it to the caller
+    fn zap_out(&self, pair: Key, liquidity: U256, token_out: Key, min_amount_out: U256) -> U256 {
+        let user: Key = self.get_caller();
+        let (token0, token1) = tokens(pair);
+        let token_in: Key = self.other_token(token_out, token0, token1);
+        transfer_from(pair, user, pair, liquidity);
+        let (amount0, amount1): (U256, U256) = runtime::call_versioned_contract(
+            pair.into_hash().unwrap_or_revert().into(),
+            None,
+            "burn",
+            runtime_args! {
+                "to" => Key::from(get_package_hash())
+            },
+        );
+        let (amount_in, amount_kept) = if token_in == token0 {
+            (amount0, amount1)
+        } else {
+            (amount1, amount0)
+        };
+        let mut amount_out: U256 = amount_kept;
+        if !amount_in.is_zero() {
+            let (reserve_in, reserve_out) = reserves(pair, token_in == token0);
+            let amount_swapped: U256 = self.swap(
+                pair,
+                token_in,
+                token_in == token0,
+                amount_in,
+                get_pair_amount_out(pair, token_in == token0, amount_in, reserve_in, reserve_out),
+                user,
+            );
+            amount_out = amount_out
+                .checked_add(amount_swapped)
+                .unwrap_or_revert_with(Errors::ZapOverFlow);
+        }
+        if amount_out < min_amount_out {
+            runtime::revert(Errors::ZapInsufficientOutputAmount);
+        }
+        transfer(token_out, user, amount_kept);
+        self.emit(&ZAPEvent::ZapOut {
+            user,
+            pair,
+            liquidity,
+            token_out,
+            amount_out,
+        });
+        amount_out
+    }
+
+    fn other_token(&self, token: Key, token0: Key, token1: Key) -> Key {
+        if token == token0 {
+            token1
+        } else if token == token1 {
+            token0
+        } else {
+            runtime::revert(Errors::ZapInvalidToken);
+        }
+    }
+
+    /// Sends `amount_in` of `token_in` to `pair` and swaps it for `amount_out` of the other token
+    fn swap(
+        &self,
+        pair: Key,
+        token_in: Key,
+        zero_for_one: bool,
+        amount_in: U256,
+        amount_out: U256,
+        to: Key,
+    ) -> U256 {
+        let (amount0_out, amount1_out): (U256, U256) = if zero_for_one {
+            (0.into(), amount_out)
+        } else {
+            (amount_out, 0.into())
+        };
+        transfer(token_in, pair, amount_in);
+        let () = runtime::call_versioned_contract(
+            pair.into_hash().unwrap_or_revert().into(),
+            None,
+            "swap",
+            runtime_args! {
+                "amount0_out" => amount0_out,
+                "amount1_out" => amount1_out,
+                "to" => to,
+                "data" => ""
+            },
+        );
+        amount_out
+    }
+
+    fn emit(&self, zap_event: &ZAPEvent) {
+        let mut event = BTreeMap::new();
+        event.insert("contract_package_hash", get_package_hash().to_string());
+        event.insert("event_type", zap_event.type_name());
+        match zap_event {
+            ZAPEvent::ZapIn {
+                user,
+                pair,
+                token_in,
+                amount_in,
+                swap_amount,
+                liquidity,
+            } => {
+                event.insert("user", user.to_string());
+                event.insert("pair", pair.to_string());
+                event.insert("token_in", token_in.to_string());
+                event.insert("amount_in", amount_in.to_string());
+                event.insert("swap_amount", swap_amount.to_string());
+                event.insert("liquidity", liquidity.to_string());
+            }
+            ZAPEvent::ZapOut {
+                user,
+                pair,
+                liquidity,
+                token_out,
+                amount_out,
+            } => {
+                event.insert("user", user.to_string());
+                event.insert("pair", pair.to_string());
+                event.insert("liquidity", liquidity.to_string());
+                event.insert("token_out", token_out.to_string());
+                event.insert("amount_out", amount_out.to_string());
+            }
+        }
+        storage::new_uref(event);
+    }
+}
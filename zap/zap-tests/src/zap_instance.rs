@@ -0,0 +1,57 @@
+use tests_common::{account::AccountHash, deploys::deploy_zap, *};
+
+pub struct ZAPInstance(pub TestContract);
+
+impl ZAPInstance {
+    pub fn new(env: &TestEnv, sender: AccountHash, time: u64) -> ZAPInstance {
+        ZAPInstance(deploy_zap(env, sender, time))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    pub fn zap_in(
+        &self,
+        sender: AccountHash,
+        pair: Key,
+        token_in: Key,
+        amount_in: U256,
+        min_liquidity: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "zap_in",
+            runtime_args! {
+                "pair" => pair,
+                "token_in" => token_in,
+                "amount_in" => amount_in,
+                "min_liquidity" => min_liquidity,
+            },
+            time,
+        );
+    }
+
+    pub fn zap_out(
+        &self,
+        sender: AccountHash,
+        pair: Key,
+        liquidity: U256,
+        token_out: Key,
+        min_amount_out: U256,
+        time: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "zap_out",
+            runtime_args! {
+                "pair" => pair,
+                "liquidity" => liquidity,
+                "token_out" => token_out,
+                "min_amount_out" => min_amount_out,
+            },
+            time,
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::zap_instance::ZAPInstance;
+use tests_common::{account::AccountHash, deploys::*, helpers::*, *};
+
+const ZAP_AMOUNT: u128 = 1_000_000_000;
+
+fn deploy() -> (
+    TestEnv,
+    AccountHash,
+    ZAPInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    deploy_with(|env, owner, callee_package_hash, factory_hash| {
+        deploy_pair(env, "PAIR", owner, callee_package_hash, factory_hash, now())
+    })
+}
+
+/// Creates the pair `deploy_pair` deploys with liquidity and gives a user ZAP_AMOUNT of its token0,
+/// approved to the zap
+fn deploy_with(
+    deploy_pair: impl FnOnce(&TestEnv, AccountHash, Key, Key) -> TestContract,
+) -> (
+    TestEnv,
+    AccountHash,
+    ZAPInstance,
+    TestContract,
+    TestContract,
+    TestContract,
+) {
+    let (env, owner, _, pair, token_a, token_b) = deploy_pair_with_liquidity(deploy_pair);
+    let user = env.next_user();
+    let zap = ZAPInstance::new(&env, owner, now());
+    token_a.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Address::Account(user),
+            "amount" => U256::from(ZAP_AMOUNT)
+        },
+        now(),
+    );
+    approve(&token_a, user, &zap, ZAP_AMOUNT.into());
+    (env, user, zap, token_a, token_b, pair)
+}
+
+fn approve(token: &TestContract, owner: AccountHash, zap: &ZAPInstance, amount: U256) {
+    token.call_contract(
+        owner,
+        "approve",
+        runtime_args! {
+            "spender" => Address::Contract(zap.0.package_hash().into()),
+            "amount" => amount
+        },
+        now(),
+    );
+}
+
+fn balance(token: &TestContract, owner: AccountHash) -> U256 {
+    token.query(BALANCES, address_to_str(&Address::Account(owner)))
+}
+
+#[test]
+fn test_zap_in() {
+    let (_, user, zap, token_a, token_b, pair) = deploy();
+    zap.zap_in(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token_a.package_hash()),
+        ZAP_AMOUNT.into(),
+        498_000_000.into(),
+        now(),
+    );
+    // about half the liquidity of the tokens provided, less the swap fee
+    assert!(balance(&pair, user) >= 498_000_000.into());
+    assert_eq!(balance(&token_a, user), 0.into());
+    assert_eq!(balance(&token_b, user), 0.into());
+    let zap_address = address_to_str(&Address::Contract(zap.0.package_hash().into()));
+    assert_eq!(
+        token_a.query::<U256>(BALANCES, zap_address.clone()),
+        0.into()
+    );
+    assert_eq!(token_b.query::<U256>(BALANCES, zap_address), 0.into());
+}
+
+#[test]
+fn test_zap_out() {
+    let (_, user, zap, token_a, token_b, pair) = deploy();
+    zap.zap_in(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token_a.package_hash()),
+        ZAP_AMOUNT.into(),
+        0.into(),
+        now(),
+    );
+    let liquidity: U256 = balance(&pair, user);
+    approve(&pair, user, &zap, liquidity);
+    zap.zap_out(
+        user,
+        Key::Hash(pair.package_hash()),
+        liquidity,
+        Key::Hash(token_a.package_hash()),
+        997_000_000.into(),
+        now(),
+    );
+    // a round trip costs the swap fee twice
+    assert!(balance(&token_a, user) >= 997_000_000.into());
+    assert_eq!(balance(&token_b, user), 0.into());
+    assert_eq!(balance(&pair, user), 0.into());
+}
+
+#[test]
+#[should_panic]
+fn test_zap_in_below_min_liquidity() {
+    let (_, user, zap, token_a, _, pair) = deploy();
+    zap.zap_in(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token_a.package_hash()),
+        ZAP_AMOUNT.into(),
+        500_000_000.into(),
+        now(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_zap_in_with_token_not_in_pair() {
+    let (_, user, zap, _, _, pair) = deploy();
+    zap.zap_in(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(zap.0.package_hash()),
+        ZAP_AMOUNT.into(),
+        0.into(),
+        now(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "User(191)")] // As the part swapped is only solved on the constant product
+fn test_zap_in_stable_pair() {
+    let (_, user, zap, token_a, _, pair) =
+        deploy_with(|env, owner, callee_package_hash, factory_hash| {
+            deploy_stable_pair(
+                env,
+                "PAIR",
+                owner,
+                callee_package_hash,
+                factory_hash,
+                100.into(),
+                now(),
+            )
+        });
+    zap.zap_in(
+        user,
+        Key::Hash(pair.package_hash()),
+        Key::Hash(token_a.package_hash()),
+        ZAP_AMOUNT.into(),
+        0.into(),
+        now(),
+    );
+}
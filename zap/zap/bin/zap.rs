@@ -0,0 +1,198 @@
+#![no_main]
+
+use std::collections::BTreeSet;
+use zap_crate::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+    *,
+};
+
+#[derive(Default)]
+struct Zap(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for Zap {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+
+impl ZAP<OnChainContractStorage> for Zap {}
+
+impl Zap {
+    fn constructor(&self, contract_hash: ContractHash, package_hash: ContractPackageHash) {
+        ZAP::init(self, contract_hash, package_hash);
+    }
+}
+
+#[no_mangle]
+fn constructor() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
+    Zap::default().constructor(contract_hash, package_hash);
+}
+
+/// This function is to add single-sided liquidity, swapping the optimal part of token_in through the pair first
+///
+/// # Parameters
+///
+/// * `pair` - A Key that holds the Hash of the pair
+///
+/// * `token_in` - A Key that holds the Hash of the token provided, one of the pair's tokens
+///
+/// * `amount_in` - A U256 that holds the amount of token_in pulled from the caller, it has to be approved to the zap first
+///
+/// * `min_liquidity` - A U256 that holds the least amount of LP tokens to mint to the caller
+///
+
+#[no_mangle]
+fn zap_in() {
+    let pair: Key = runtime::get_named_arg("pair");
+    let token_in: Key = runtime::get_named_arg("token_in");
+    let amount_in: U256 = runtime::get_named_arg("amount_in");
+    let min_liquidity: U256 = runtime::get_named_arg("min_liquidity");
+    let ret: U256 = Zap::default().zap_in(pair, token_in, amount_in, min_liquidity);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+/// This function is to remove liquidity into a single token, swapping the other token of the pair into it
+///
+/// # Parameters
+///
+/// * `pair` - A Key that holds the Hash of the pair
+///
+/// * `liquidity` - A U256 that holds the amount of LP tokens pulled from the caller, it has to be approved to the zap first
+///
+/// * `token_out` - A Key that holds the Hash of the token received, one of the pair's tokens
+///
+/// * `min_amount_out` - A U256 that holds the least amount of token_out to send to the caller
+///
+
+#[no_mangle]
+fn zap_out() {
+    let pair: Key = runtime::get_named_arg("pair");
+    let liquidity: U256 = runtime::get_named_arg("liquidity");
+    let token_out: Key = runtime::get_named_arg("token_out");
+    let min_amount_out: U256 = runtime::get_named_arg("min_amount_out");
+    let ret: U256 = Zap::default().zap_out(pair, liquidity, token_out, min_amount_out);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("contract_hash", ContractHash::cl_type()),
+            Parameter::new("package_hash", ContractPackageHash::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "zap_in",
+        vec![
+            Parameter::new("pair", Key::cl_type()),
+            Parameter::new("token_in", Key::cl_type()),
+            Parameter::new("amount_in", U256::cl_type()),
+            Parameter::new("min_liquidity", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "zap_out",
+        vec![
+            Parameter::new("pair", Key::cl_type()),
+            Parameter::new("liquidity", U256::cl_type()),
+            Parameter::new("token_out", Key::cl_type()),
+            Parameter::new("min_amount_out", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "contract_hash" => contract_hash,
+            "package_hash"=> package_hash
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+
+        // Store contract in the account's named keys.
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}